@@ -88,4 +88,202 @@ pub enum InsuranceError {
     
     #[msg("Payout calculation error")]
     PayoutCalculationError,
+
+    #[msg("Coverage has pending claims")]
+    CoverageHasPendingClaims,
+
+    #[msg("Coverage is too close to expiry to trade")]
+    CoverageNearExpiry,
+
+    #[msg("Invalid listing price")]
+    InvalidListingPrice,
+
+    #[msg("Invalid fee")]
+    InvalidFee,
+
+    #[msg("Invalid pricing curve")]
+    InvalidPricingCurve,
+
+    #[msg("Premium exceeds the maximum accepted by the insured")]
+    PremiumExceedsMaximum,
+
+    #[msg("Premium quote has expired")]
+    QuoteExpired,
+
+    #[msg("Invalid coverage period")]
+    InvalidCoveragePeriod,
+
+    #[msg("Pool vault not initialized for this asset")]
+    VaultNotInitialized,
+
+    #[msg("Required token account missing")]
+    MissingTokenAccount,
+
+    #[msg("Invalid LIVES discount schedule")]
+    InvalidDiscountSchedule,
+
+    #[msg("Invalid withdrawal configuration")]
+    InvalidWithdrawalConfig,
+
+    #[msg("Withdrawal cooldown has not elapsed")]
+    WithdrawalCooldownActive,
+
+    #[msg("Epoch withdrawal cap reached")]
+    EpochWithdrawalCapReached,
+
+    #[msg("Tranche not found")]
+    TrancheNotFound,
+
+    #[msg("Invalid tranche configuration")]
+    InvalidTrancheConfig,
+
+    #[msg("Reward calculation overflow")]
+    RewardCalculationOverflow,
+
+    #[msg("Insufficient staked balance")]
+    InsufficientStake,
+
+    #[msg("No rewards to claim")]
+    NoRewardsToClaim,
+
+    #[msg("Invalid staking configuration")]
+    InvalidStakingConfig,
+
+    #[msg("Stake is still locked")]
+    StakeLocked,
+
+    #[msg("Invalid reinsurance treaty terms")]
+    InvalidTreatyTerms,
+
+    #[msg("Reinsurance accounts do not match the pool's treaty")]
+    ReinsuranceAccountsMismatch,
+
+    #[msg("Invalid coverage types")]
+    InvalidCoverageTypes,
+
+    #[msg("Coverage type not offered by this pool")]
+    CoverageTypeNotOffered,
+
+    #[msg("Pool registry page is full")]
+    RegistryFull,
+
+    #[msg("Pool not registered")]
+    PoolNotRegistered,
+
+    #[msg("Invalid governance configuration")]
+    InvalidGovernanceConfig,
+
+    #[msg("Invalid proposal")]
+    InvalidProposal,
+
+    #[msg("Voting period has ended")]
+    VotingClosed,
+
+    #[msg("Voting period has not ended")]
+    VotingStillOpen,
+
+    #[msg("Insufficient voting weight")]
+    InsufficientVotingWeight,
+
+    #[msg("Invalid timelock configuration")]
+    InvalidTimelockConfig,
+
+    #[msg("Timelock delay has not elapsed")]
+    TimelockNotReady,
+
+    #[msg("Signer does not hold the required role")]
+    MissingRole,
+
+    #[msg("Role membership list is full")]
+    RoleMembersFull,
+
+    #[msg("Invalid role assignment")]
+    InvalidRoleAssignment,
+
+    #[msg("Invalid pool authority")]
+    InvalidAuthority,
+
+    #[msg("Account is already at the current version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Metadata URI is too long")]
+    MetadataUriTooLong,
+
+    #[msg("Custom coverage category is too long")]
+    CoverageCategoryTooLong,
+
+    #[msg("Too many custom trigger conditions")]
+    TooManyCustomConditions,
+
+    #[msg("Custom trigger condition type is too long")]
+    ConditionTypeTooLong,
+
+    #[msg("Rejection reason is too long")]
+    RejectionReasonTooLong,
+
+    #[msg("Token account is not owned by the expected wallet")]
+    InvalidTokenOwner,
+
+    #[msg("Instruction is not allowed in the pool's current status")]
+    OperationNotAllowedInPoolStatus,
+
+    #[msg("Pool is already winding down")]
+    PoolAlreadyWindingDown,
+
+    #[msg("Policies, claims or unearned premiums are still outstanding")]
+    WindDownIncomplete,
+
+    #[msg("Coverage has not reached its end time")]
+    CoverageNotEnded,
+
+    #[msg("Listing price exceeds the maximum accepted by the buyer")]
+    ListingPriceExceedsMaximum,
+
+    #[msg("Quote was made for different coverage parameters or payment asset")]
+    QuoteMismatch,
+
+    #[msg("Price update is not fully verified")]
+    PriceUpdateNotVerified,
+
+    #[msg("Withdrawal requests are processed in queue order")]
+    WithdrawalOutOfOrder,
+
+    #[msg("Withdrawal request was already cancelled")]
+    WithdrawalAlreadyCancelled,
+
+    #[msg("Stake has not been held for the minimum staking period")]
+    StakeWarmingUp,
+
+    #[msg("Pool is already listed on this registry page")]
+    PoolAlreadyRegistered,
+
+    #[msg("Pool ID is reserved for migrated pools")]
+    InvalidPoolId,
+
+    #[msg("Proposal did not pass")]
+    ProposalNotPassed,
+
+    #[msg("Proposal passed and must be queued")]
+    ProposalPassed,
+
+    #[msg("Timelock operation expired without being executed")]
+    TimelockExpired,
+
+    #[msg("Coverage belongs to another pool")]
+    CoverageNotInPool,
+
+    #[msg("Claim was made against another coverage")]
+    ClaimCoverageMismatch,
+
+    #[msg("Reinsurer pool is not taking on new exposure")]
+    ReinsurerNotActive,
+
+    #[msg("Pool has no treaty ceding new policies")]
+    NoActiveTreaty,
+
+    #[msg("Account must be migrated to the current layout first")]
+    AccountNotMigrated,
+
+    #[msg("Listed coverage is expired together with its listing")]
+    ListingRequired,
 }
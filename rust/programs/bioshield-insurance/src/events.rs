@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
 use crate::state::coverage_account::CoverageType;
+use crate::state::tranche::TrancheKind;
+use crate::state::reinsurance::TreatyKind;
+use crate::state::registry::PoolStatus;
+use crate::state::governance::ParameterChange;
+use crate::state::roles::Role;
 
 #[event]
 pub struct PoolInitialized {
@@ -65,13 +70,6 @@ pub struct PoolPaused {
     pub timestamp: i64,
 }
 
-#[event]
-pub struct PoolUnpaused {
-    pub pool: Pubkey,
-    pub authority: Pubkey,
-    pub timestamp: i64,
-}
-
 #[event]
 pub struct OracleUpdated {
     pub pool: Pubkey,
@@ -98,3 +96,443 @@ pub struct PayoutProcessed {
     pub token: Pubkey,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct CoverageListed {
+    pub listing: Pubkey,
+    pub coverage: Pubkey,
+    pub seller: Pubkey,
+    pub ask_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoverageDelisted {
+    pub listing: Pubkey,
+    pub coverage: Pubkey,
+    pub seller: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoverageSold {
+    pub coverage: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub marketplace_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultInitialized {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LegacyLiquiditySwept {
+    pub pool: Pubkey,
+    pub from: Pubkey,
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SolPremiumsSwept {
+    pub pool: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LivesPremiumBurned {
+    pub pool: Pubkey,
+    pub coverage: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalRequested {
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub request: Pubkey,
+    pub shield_amount: u64,
+    pub sequence: u64,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalCancelled {
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub request: Pubkey,
+    pub shield_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrancheInitialized {
+    pub pool: Pubkey,
+    pub share_mint: Pubkey,
+    pub kind: TrancheKind,
+    pub premium_share_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimLossAllocated {
+    pub pool: Pubkey,
+    pub claim: Pubkey,
+    pub junior_loss: u64,
+    pub senior_loss: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PremiumEarned {
+    pub pool: Pubkey,
+    pub coverage: Pubkey,
+    pub amount: u64,
+    pub premium_earned: u64,
+    pub unearned_premiums: u64,
+    pub staker_rewards: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ShieldStaked {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub lock_end: i64,
+    pub boost_bps: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LivesStaked {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub staked_since: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LivesUnstaked {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ShieldUnstaked {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LivesRewardsFunded {
+    pub pool: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub funded_remaining: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LivesRewardsClaimed {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeBoostExpired {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub boosted_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReinsuranceTreatyRegistered {
+    pub treaty: Pubkey,
+    pub cedent: Pubkey,
+    pub reinsurer: Pubkey,
+    pub kind: TreatyKind,
+    pub cession_bps: u16,
+    pub attachment_point: u64,
+    pub limit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReinsuranceTreatyTerminated {
+    pub pool: Pubkey,
+    pub treaty: Pubkey,
+    pub ceded_coverage: u64, // still reinsured, and run off under the treaty
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReinsuranceTreatyClosed {
+    pub pool: Pubkey,
+    pub treaty: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoverageCeded {
+    pub treaty: Pubkey,
+    pub coverage: Pubkey,
+    pub ceded_coverage: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PremiumCeded {
+    pub treaty: Pubkey,
+    pub coverage: Pubkey,
+    pub amount: u64,
+    pub staker_rewards: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReinsuranceRecovered {
+    pub treaty: Pubkey,
+    pub claim: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolRegistered {
+    pub pool: Pubkey,
+    pub authority: Pubkey,
+    pub pool_id: u64,
+    pub page: u32,
+    pub coverage_types: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RegistryEntrySynced {
+    pub pool: Pubkey,
+    pub coverage_types: u8,
+    pub status: PoolStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceInitialized {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub voting_period: i64,
+    pub quorum_votes: u64,
+    pub threshold_bps: u16,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VotingWeightChanged {
+    pub governance: Pubkey,
+    pub voter: Pubkey,
+    pub shield_deposited: u64,
+    pub lives_deposited: u64,
+    pub weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub governance: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub id: u64,
+    pub action_count: u8,
+    pub voting_ends_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalFinalized {
+    pub proposal: Pubkey,
+    pub passed: bool,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterChangeQueued {
+    pub pool: Pubkey,
+    pub operation: Pubkey,
+    pub id: u64,
+    pub actions: Vec<ParameterChange>,
+    pub proposer: Pubkey,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterChangeExecuted {
+    pub pool: Pubkey,
+    pub operation: Pubkey,
+    pub id: u64,
+    pub actions: Vec<ParameterChange>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterChangeCancelled {
+    pub pool: Pubkey,
+    pub operation: Pubkey,
+    pub id: u64,
+    pub guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub pool: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub pool: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub pool: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub pool: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WindDownStarted {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub open_coverages: Option<u64>,
+    pub coverage_ends_by: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoverageCancelled {
+    pub pool: Pubkey,
+    pub coverage: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoverageExpired {
+    pub pool: Pubkey,
+    pub coverage: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionOpened {
+    pub pool: Pubkey,
+    pub vault_balance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SharesRedeemed {
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub share_mint: Pubkey,
+    pub shares_burned: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionAssetPaid {
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimShortfallPaid {
+    pub claim: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub settlement_value: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LivesRewardVaultInitialized {
+    pub pool: Pubkey,
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, PoolRoles};
+
+/// Signed by the proposed authority, proving the new key (or multisig/governance PDA) can act
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump,
+        constraint = insurance_pool.pending_authority == Some(new_authority.key()) @ InsuranceError::InvalidAuthority
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    pub new_authority: Signer<'info>,
+}
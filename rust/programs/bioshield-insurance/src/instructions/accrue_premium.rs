@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, CoverageAccount, ReinsuranceTreaty};
+
+/// Permissionless crank that releases a policy's earned premium
+#[derive(Accounts)]
+pub struct AccruePremium<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        constraint = coverage_account.pool == insurance_pool.key()
+    )]
+    pub coverage_account: Account<'info, CoverageAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.settlement_mint.as_ref()],
+        bump
+    )]
+    pub settlement_vault: Account<'info, TokenAccount>,
+    
+    /// SHIELD supply splits earned premium between stakers and share holders
+    #[account(
+        constraint = shield_token_mint.key() == insurance_pool.shield_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub shield_token_mint: Account<'info, Mint>,
+    
+    /// Pool's reinsurance treaty, required when it has one
+    #[account(
+        mut,
+        constraint = reinsurance_treaty.cedent == insurance_pool.key()
+    )]
+    pub reinsurance_treaty: Option<Account<'info, ReinsuranceTreaty>>,
+    
+    /// Reinsurer pool named by the treaty
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", reinsurer_pool.creator.as_ref(), &reinsurer_pool.pool_id_seed()[..]],
+        bump = reinsurer_pool.bump
+    )]
+    pub reinsurer_pool: Option<Account<'info, InsurancePool>>,
+    
+    /// Reinsurer's settlement vault, checked against its ledger
+    #[account(mut)]
+    pub reinsurer_settlement_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Reinsurer's SHIELD mint, checked against the reinsurer pool
+    pub reinsurer_shield_token_mint: Option<Account<'info, Mint>>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -1,12 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount, Mint, MintTo};
+use crate::errors::InsuranceError;
 use crate::state::InsurancePool;
 
 #[derive(Accounts)]
 pub struct AddLiquidity<'info> {
     #[account(
         mut,
-        seeds = [b"insurance_pool", insurance_pool.authority.as_ref()],
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
         bump = insurance_pool.bump
     )]
     pub insurance_pool: Account<'info, InsurancePool>,
@@ -16,25 +17,28 @@ pub struct AddLiquidity<'info> {
     
     #[account(
         mut,
-        constraint = liquidity_provider_token.owner == liquidity_provider.key()
+        constraint = liquidity_provider_token.owner == liquidity_provider.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = liquidity_provider_token.mint == insurance_pool.settlement_mint @ InsuranceError::InvalidTokenMint
     )]
     pub liquidity_provider_token: Account<'info, TokenAccount>,
     
     #[account(
         mut,
-        constraint = pool_token_account.owner == insurance_pool.key()
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.settlement_mint.as_ref()],
+        bump
     )]
-    pub pool_token_account: Account<'info, TokenAccount>,
+    pub settlement_vault: Account<'info, TokenAccount>,
     
     #[account(
         mut,
-        constraint = shield_token_mint.key() == insurance_pool.shield_token_mint
+        constraint = shield_token_mint.key() == insurance_pool.shield_token_mint @ InsuranceError::InvalidTokenMint
     )]
     pub shield_token_mint: Account<'info, Mint>,
     
     #[account(
         mut,
-        constraint = provider_shield_account.owner == liquidity_provider.key()
+        constraint = provider_shield_account.owner == liquidity_provider.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = provider_shield_account.mint == insurance_pool.shield_token_mint @ InsuranceError::InvalidTokenMint
     )]
     pub provider_shield_account: Account<'info, TokenAccount>,
     
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use crate::errors::InsuranceError;
+use crate::state::InsurancePool;
+
+#[derive(Accounts)]
+pub struct AddTrancheLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(mut)]
+    pub liquidity_provider: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = liquidity_provider_token.owner == liquidity_provider.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = liquidity_provider_token.mint == insurance_pool.settlement_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub liquidity_provider_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.settlement_mint.as_ref()],
+        bump
+    )]
+    pub settlement_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = insurance_pool.tranches.iter().any(|tranche| tranche.share_mint == share_mint.key()) @ InsuranceError::InvalidTokenMint
+    )]
+    pub share_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = provider_share_account.owner == liquidity_provider.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = provider_share_account.mint == share_mint.key() @ InsuranceError::InvalidTokenMint
+    )]
+    pub provider_share_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
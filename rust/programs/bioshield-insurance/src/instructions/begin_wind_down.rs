@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, PoolRoles, Role};
+
+/// Retiring a pool is irreversible, so it takes an admin rather than the guardian
+#[derive(Accounts)]
+pub struct BeginWindDown<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(&admin.key(), Role::Admin) @ InsuranceError::MissingRole
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, CoverageAccount, CoverageListing};
+
+#[derive(Accounts)]
+pub struct BuyCoverage<'info> {
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"listing", coverage_account.key().as_ref()],
+        bump = listing.bump,
+        constraint = listing.seller == seller.key()
+    )]
+    pub listing: Account<'info, CoverageListing>,
+
+    #[account(
+        mut,
+        constraint = coverage_account.key() == listing.coverage,
+        constraint = coverage_account.pool == insurance_pool.key()
+    )]
+    pub coverage_account: Account<'info, CoverageAccount>,
+
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = buyer_settlement_account.owner == buyer.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = buyer_settlement_account.mint == insurance_pool.settlement_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub buyer_settlement_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Seller recovering the listing rent, checked against the listing
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = seller_settlement_account.owner == listing.seller @ InsuranceError::InvalidTokenOwner,
+        constraint = seller_settlement_account.mint == insurance_pool.settlement_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub seller_settlement_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = treasury_settlement_account.owner == insurance_pool.treasury @ InsuranceError::InvalidTokenOwner,
+        constraint = treasury_settlement_account.mint == insurance_pool.settlement_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub treasury_settlement_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, CoverageAccount, PoolRoles, ReinsuranceTreaty, Role};
+
+/// The insured may give up a policy for a refund while the pool winds down; an admin may
+/// cancel on their behalf to close out the book
+#[derive(Accounts)]
+pub struct CancelCoverage<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    #[account(
+        mut,
+        constraint = coverage_account.pool == insurance_pool.key()
+    )]
+    pub coverage_account: Account<'info, CoverageAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.settlement_mint.as_ref()],
+        bump
+    )]
+    pub settlement_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = insured_settlement_account.owner == coverage_account.insured @ InsuranceError::InvalidTokenOwner,
+        constraint = insured_settlement_account.mint == insurance_pool.settlement_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub insured_settlement_account: Account<'info, TokenAccount>,
+    
+    /// Pool's reinsurance treaty, required when the policy ceded exposure to it
+    #[account(
+        mut,
+        constraint = reinsurance_treaty.cedent == insurance_pool.key()
+    )]
+    pub reinsurance_treaty: Option<Account<'info, ReinsuranceTreaty>>,
+    
+    /// Reinsurer pool named by the treaty
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", reinsurer_pool.creator.as_ref(), &reinsurer_pool.pool_id_seed()[..]],
+        bump = reinsurer_pool.bump
+    )]
+    pub reinsurer_pool: Option<Account<'info, InsurancePool>>,
+    
+    #[account(
+        constraint = authority.key() == coverage_account.insured ||
+            roles.has_role(&authority.key(), Role::Admin) @ InsuranceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
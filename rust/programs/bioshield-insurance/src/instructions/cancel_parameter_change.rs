@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, TimelockOperation, PoolRoles, Role};
+
+#[derive(Accounts)]
+pub struct CancelParameterChange<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(&guardian.key(), Role::Guardian) @ InsuranceError::MissingRole
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"timelock", insurance_pool.key().as_ref(), &operation.id.to_le_bytes()],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+    
+    /// CHECK: Paid the operation's rent and receives it back; checked against the operation
+    #[account(
+        mut,
+        constraint = payer.key() == operation.payer
+    )]
+    pub payer: UncheckedAccount<'info>,
+    
+    pub guardian: Signer<'info>,
+}
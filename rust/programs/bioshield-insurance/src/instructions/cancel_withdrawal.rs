@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, WithdrawalRequest};
+
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal",
+            insurance_pool.key().as_ref(),
+            &withdrawal_request.sequence.to_le_bytes()
+        ],
+        bump = withdrawal_request.bump,
+        constraint = withdrawal_request.provider == liquidity_provider.key() @ InsuranceError::Unauthorized
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+    
+    #[account(
+        mut,
+        seeds = [b"withdrawal_escrow", withdrawal_request.key().as_ref()],
+        bump
+    )]
+    pub shield_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_shield_account.owner == liquidity_provider.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = provider_shield_account.mint == withdrawal_request.share_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub provider_shield_account: Account<'info, TokenAccount>,
+    
+    pub liquidity_provider: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, StakePosition};
+
+#[derive(Accounts)]
+pub struct ClaimLivesRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_position", insurance_pool.key().as_ref(), owner.key().as_ref()],
+        bump = stake_position.bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(
+        mut,
+        seeds = [b"lives_rewards", insurance_pool.key().as_ref()],
+        bump
+    )]
+    pub lives_reward_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = owner_lives_account.owner == owner.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = owner_lives_account.mint == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub owner_lives_account: Account<'info, TokenAccount>,
+    
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
-use crate::state::{InsurancePool, CoverageAccount, CoverageParams};
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use crate::errors::InsuranceError;
+use crate::state::{
+    InsurancePool, CoverageAccount, CoverageParams, LivesStake, PoolRoles, ReinsuranceTreaty, Role,
+};
 
 #[derive(Accounts)]
 #[instruction(coverage_params: CoverageParams)]
@@ -9,45 +13,105 @@ pub struct CreateCoverage<'info> {
         init,
         payer = insured,
         space = CoverageAccount::SIZE,
-        seeds = [b"coverage", insured.key().as_ref(), &coverage_params.coverage_amount.to_le_bytes()],
+        seeds = [
+            b"coverage",
+            insurance_pool.key().as_ref(),
+            insured.key().as_ref(),
+            &coverage_params.nonce.to_le_bytes()
+        ],
         bump
     )]
     pub coverage_account: Account<'info, CoverageAccount>,
     
     #[account(
         mut,
-        seeds = [b"insurance_pool", insurance_pool.authority.as_ref()],
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
         bump = insurance_pool.bump
     )]
     pub insurance_pool: Account<'info, InsurancePool>,
     
+    #[account(
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
     #[account(mut)]
     pub insured: Signer<'info>,
     
-    /// CHECK: Optional LIVES token account for premium payment
+    /// Oracle operator co-signing the risk assessment, required when one is supplied
     #[account(
-        constraint = lives_token_account.is_some() == coverage_params.pay_with_lives
+        constraint = roles.has_role(&oracle_authority.key(), Role::OracleOperator) @ InsuranceError::MissingRole
+    )]
+    pub oracle_authority: Option<Signer<'info>>,
+    
+    /// Insured's LIVES account, required when paying with LIVES
+    #[account(
+        mut,
+        constraint = lives_token_account.owner == insured.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = lives_token_account.mint == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
     )]
     pub lives_token_account: Option<Account<'info, TokenAccount>>,
     
-    /// CHECK: Pool's LIVES token account
+    /// Insured's LIVES stake, which picks the discount tier when paying with LIVES
+    #[account(
+        seeds = [b"lives_stake", insurance_pool.key().as_ref(), insured.key().as_ref()],
+        bump = lives_stake.bump
+    )]
+    pub lives_stake: Option<Account<'info, LivesStake>>,
+    
+    /// Pool's LIVES vault, required when paying with LIVES
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.lives_token_mint.as_ref()],
+        bump
+    )]
+    pub lives_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// LIVES mint, required when the pool burns a share of LIVES premiums
+    #[account(
+        mut,
+        constraint = lives_token_mint.key() == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub lives_token_mint: Option<Account<'info, Mint>>,
+    
+    /// Insured's settlement token account; omitted to pay native SOL into a wrapped SOL vault
+    #[account(
+        mut,
+        constraint = insured_settlement_account.owner == insured.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = insured_settlement_account.mint == insurance_pool.settlement_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub insured_settlement_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Pool's settlement vault
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.settlement_mint.as_ref()],
+        bump
+    )]
+    pub settlement_vault: Account<'info, TokenAccount>,
+    
+    /// Pyth LIVES/USD price update, required when paying with LIVES
+    pub lives_price_update: Option<Account<'info, PriceUpdateV2>>,
+    
+    /// Pyth price update for a settlement asset that is not a USD stablecoin
+    pub settlement_price_update: Option<Account<'info, PriceUpdateV2>>,
+    
+    /// Pool's reinsurance treaty, required when it has one
     #[account(
         mut,
-        constraint = pool_lives_account.owner == insurance_pool.key()
+        constraint = reinsurance_treaty.cedent == insurance_pool.key()
     )]
-    pub pool_lives_account: Account<'info, TokenAccount>,
+    pub reinsurance_treaty: Option<Account<'info, ReinsuranceTreaty>>,
+    
+    /// Reinsurer pool named by the treaty
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", reinsurer_pool.creator.as_ref(), &reinsurer_pool.pool_id_seed()[..]],
+        bump = reinsurer_pool.bump
+    )]
+    pub reinsurer_pool: Option<Account<'info, InsurancePool>>,
     
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct CoverageParams {
-    pub coverage_amount: u64,
-    pub coverage_period: u32,
-    pub coverage_type: crate::state::coverage_account::CoverageType,
-    pub trigger_conditions: crate::state::oracle_data::TriggerConditions,
-    pub risk_category: crate::utils::calculations::RiskCategory,
-    pub metadata_uri: String,
-    pub pay_with_lives: bool,
-}
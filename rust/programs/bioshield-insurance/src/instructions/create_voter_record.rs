@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, Governance, VoterRecord};
+
+#[derive(Accounts)]
+pub struct CreateVoterRecord<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"governance", insurance_pool.key().as_ref()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = VoterRecord::SIZE,
+        seeds = [b"voter", governance.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"voter_shield", voter_record.key().as_ref()],
+        bump,
+        token::mint = shield_token_mint,
+        token::authority = governance
+    )]
+    pub shield_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"voter_lives", voter_record.key().as_ref()],
+        bump,
+        token::mint = lives_token_mint,
+        token::authority = governance
+    )]
+    pub lives_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        constraint = shield_token_mint.key() == insurance_pool.shield_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub shield_token_mint: Account<'info, Mint>,
+    
+    #[account(
+        constraint = lives_token_mint.key() == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub lives_token_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::state::{CoverageAccount, CoverageListing};
+
+#[derive(Accounts)]
+pub struct DelistCoverage<'info> {
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"listing", coverage_account.key().as_ref()],
+        bump = listing.bump,
+        constraint = listing.seller == seller.key()
+    )]
+    pub listing: Account<'info, CoverageListing>,
+
+    #[account(
+        mut,
+        constraint = coverage_account.key() == listing.coverage
+    )]
+    pub coverage_account: Account<'info, CoverageAccount>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+}
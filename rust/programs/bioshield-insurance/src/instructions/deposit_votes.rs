@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, Governance, VoterRecord};
+
+/// Shared by `deposit_votes` and `withdraw_votes`
+#[derive(Accounts)]
+pub struct DepositVotes<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"governance", insurance_pool.key().as_ref()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    #[account(
+        mut,
+        seeds = [b"voter", governance.key().as_ref(), owner.key().as_ref()],
+        bump = voter_record.bump
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    
+    #[account(
+        mut,
+        seeds = [b"voter_shield", voter_record.key().as_ref()],
+        bump
+    )]
+    pub shield_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"voter_lives", voter_record.key().as_ref()],
+        bump
+    )]
+    pub lives_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = owner_shield_account.owner == owner.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = owner_shield_account.mint == insurance_pool.shield_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub owner_shield_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = owner_lives_account.owner == owner.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = owner_lives_account.mint == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub owner_lives_account: Account<'info, TokenAccount>,
+    
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
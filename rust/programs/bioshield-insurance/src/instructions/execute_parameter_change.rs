@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, ReinsuranceTreaty, TimelockOperation, PoolRoles, Role};
+
+/// Permissionless once the operation's ETA has passed
+#[derive(Accounts)]
+pub struct ExecuteParameterChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    /// Whoever queued the operation must still be an admin
+    #[account(
+        mut,
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(&operation.proposer, Role::Admin) @ InsuranceError::MissingRole
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"timelock", insurance_pool.key().as_ref(), &operation.id.to_le_bytes()],
+        bump = operation.bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+    
+    /// CHECK: Paid the operation's rent and receives it back; checked against the operation
+    #[account(
+        mut,
+        constraint = payer.key() == operation.payer
+    )]
+    pub payer: UncheckedAccount<'info>,
+    
+    /// Pool's treaty, required to execute its termination
+    #[account(
+        constraint = reinsurance_treaty.cedent == insurance_pool.key()
+    )]
+    pub reinsurance_treaty: Option<Account<'info, ReinsuranceTreaty>>,
+}
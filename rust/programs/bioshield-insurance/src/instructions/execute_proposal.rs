@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsurancePool, Governance, Proposal, TimelockOperation};
+
+/// Permissionless once voting has ended; the pool only accepts changes from its own governance
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"governance", insurance_pool.key().as_ref()],
+        bump = governance.bump,
+        constraint = insurance_pool.governance == Some(governance.key())
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", governance.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = TimelockOperation::SIZE,
+        seeds = [b"timelock", insurance_pool.key().as_ref(), &insurance_pool.timelock_operation_count.to_le_bytes()],
+        bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsurancePool, CoverageAccount, CoverageListing, ReinsuranceTreaty};
+
+/// Permissionless crank that retires a policy past its end time
+#[derive(Accounts)]
+pub struct ExpireCoverage<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        constraint = coverage_account.pool == insurance_pool.key()
+    )]
+    pub coverage_account: Account<'info, CoverageAccount>,
+    
+    /// Pool's reinsurance treaty, required when the policy ceded exposure to it
+    #[account(
+        mut,
+        constraint = reinsurance_treaty.cedent == insurance_pool.key()
+    )]
+    pub reinsurance_treaty: Option<Account<'info, ReinsuranceTreaty>>,
+    
+    /// Reinsurer pool named by the treaty
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", reinsurer_pool.creator.as_ref(), &reinsurer_pool.pool_id_seed()[..]],
+        bump = reinsurer_pool.bump
+    )]
+    pub reinsurer_pool: Option<Account<'info, InsurancePool>>,
+    
+    /// Listing the policy was left on past its end time, required when it is listed.
+    /// Closing it hands the rent back to its seller.
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"listing", coverage_account.key().as_ref()],
+        bump = listing.bump,
+        constraint = listing.seller == seller.key()
+    )]
+    pub listing: Option<Account<'info, CoverageListing>>,
+    
+    /// CHECK: Seller named by the listing; only receives its rent
+    #[account(mut)]
+    pub seller: Option<UncheckedAccount<'info>>,
+}
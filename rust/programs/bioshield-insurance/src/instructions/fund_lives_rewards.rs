@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::InsuranceError;
+use crate::state::InsurancePool;
+
+#[derive(Accounts)]
+pub struct FundLivesRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        seeds = [b"lives_rewards", insurance_pool.key().as_ref()],
+        bump
+    )]
+    pub lives_reward_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = funder_lives_account.owner == funder.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = funder_lives_account.mint == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub funder_lives_account: Account<'info, TokenAccount>,
+    
+    pub funder: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{TokenAccount, Mint};
+use crate::errors::InsuranceError;
+use crate::state::InsurancePool;
+
+#[derive(Accounts)]
+pub struct GetSharePrice<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.settlement_mint.as_ref()],
+        bump
+    )]
+    pub settlement_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        constraint = shield_token_mint.key() == insurance_pool.shield_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub shield_token_mint: Account<'info, Mint>,
+}
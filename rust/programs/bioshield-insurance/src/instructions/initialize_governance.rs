@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, Governance, PoolRoles, Role};
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump,
+        constraint = insurance_pool.governance.is_none()
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(&authority.key(), Role::Admin) @ InsuranceError::MissingRole
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Governance::SIZE,
+        seeds = [b"governance", insurance_pool.key().as_ref()],
+        bump
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
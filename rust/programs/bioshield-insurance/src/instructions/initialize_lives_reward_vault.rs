@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, PoolRoles, Role};
+
+#[derive(Accounts)]
+pub struct InitializeLivesRewardVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(&authority.key(), Role::Admin) @ InsuranceError::MissingRole
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    #[account(
+        constraint = lives_token_mint.key() == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub lives_token_mint: Account<'info, Mint>,
+    
+    /// Holds LIVES set aside for staking emissions, separate from LIVES premiums
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"lives_rewards", insurance_pool.key().as_ref()],
+        bump,
+        token::mint = lives_token_mint,
+        token::authority = insurance_pool
+    )]
+    pub lives_reward_vault: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{InsurancePool, PoolParams};
+use crate::state::{InsurancePool, PoolRoles, PoolParams};
 
 #[derive(Accounts)]
 #[instruction(pool_params: PoolParams)]
@@ -8,24 +8,26 @@ pub struct InitializePool<'info> {
         init,
         payer = authority,
         space = InsurancePool::SIZE,
-        seeds = [b"insurance_pool", authority.key().as_ref()],
+        seeds = [b"insurance_pool", authority.key().as_ref(), &pool_params.pool_id.to_le_bytes()],
         bump
     )]
     pub insurance_pool: Account<'info, InsurancePool>,
     
+    #[account(
+        init,
+        payer = authority,
+        space = PoolRoles::SIZE,
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
     pub lives_token_mint: Account<'info, anchor_spl::token::Mint>,
     pub shield_token_mint: Account<'info, anchor_spl::token::Mint>,
+    pub settlement_mint: Account<'info, anchor_spl::token::Mint>,
     
     pub system_program: Program<'info, System>,
 }
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct PoolParams {
-    pub fee_basis_points: u16,
-    pub min_coverage_amount: u64,
-    pub max_coverage_amount: u64,
-    pub oracle_address: Pubkey,
-}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use crate::state::PoolRegistry;
+
+#[derive(Accounts)]
+#[instruction(page: u32)]
+pub struct InitializeRegistry<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = PoolRegistry::SIZE,
+        seeds = [b"pool_registry", &page.to_le_bytes()],
+        bump
+    )]
+    pub pool_registry: Account<'info, PoolRegistry>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, PoolRoles};
+
+/// Backfill for pools created before roles existed; pools created since get theirs in initialize_pool
+#[derive(Accounts)]
+pub struct InitializeRoles<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump,
+        constraint = insurance_pool.authority == authority.key() @ InsuranceError::Unauthorized
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = PoolRoles::SIZE,
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, PoolRoles, Role};
+
+#[derive(Accounts)]
+pub struct InitializeTranche<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(&authority.key(), Role::Admin) @ InsuranceError::MissingRole
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    /// Tranche share mint; the pool must be its mint authority
+    #[account(
+        constraint = share_mint.mint_authority == anchor_lang::solana_program::program_option::COption::Some(insurance_pool.key()),
+        constraint = share_mint.supply == 0
+    )]
+    pub share_mint: Account<'info, Mint>,
+    
+    pub authority: Signer<'info>,
+}
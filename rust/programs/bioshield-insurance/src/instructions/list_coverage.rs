@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsurancePool, CoverageAccount, CoverageListing};
+
+#[derive(Accounts)]
+pub struct ListCoverage<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = CoverageListing::SIZE,
+        seeds = [b"listing", coverage_account.key().as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, CoverageListing>,
+
+    #[account(
+        mut,
+        constraint = coverage_account.insured == seller.key(),
+        constraint = coverage_account.pool == insurance_pool.key()
+    )]
+    pub coverage_account: Account<'info, CoverageAccount>,
+
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// Shared by migrate_pool and migrate_coverage; permissionless since
+/// migration only rewrites the account into its current layout
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: Program-owned account in an older layout; its discriminator is checked on deserialization
+    #[account(
+        mut,
+        owner = crate::ID
+    )]
+    pub account: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
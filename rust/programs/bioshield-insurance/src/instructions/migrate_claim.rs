@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{CoverageAccount, COVERAGE_ACCOUNT_VERSION};
+
+/// Baseline policies did not count their open claims, so each open claim is counted
+/// against its policy as it is migrated. The policy has to be migrated first.
+#[derive(Accounts)]
+pub struct MigrateClaim<'info> {
+    /// CHECK: Program-owned account in an older layout; its discriminator is checked on deserialization
+    #[account(
+        mut,
+        owner = crate::ID
+    )]
+    pub account: UncheckedAccount<'info>,
+    
+    /// Policy the claim was made against, checked against the migrated claim
+    #[account(
+        mut,
+        constraint = coverage_account.version == COVERAGE_ACCOUNT_VERSION @ InsuranceError::AccountNotMigrated
+    )]
+    pub coverage_account: Account<'info, CoverageAccount>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
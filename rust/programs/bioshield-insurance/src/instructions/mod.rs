@@ -3,13 +3,1566 @@ pub mod create_coverage;
 pub mod submit_claim;
 pub mod process_payout;
 pub mod add_liquidity;
-pub mod remove_liquidity;
 pub mod update_oracle;
+pub mod list_coverage;
+pub mod buy_coverage;
+pub mod delist_coverage;
+pub mod quote_premium;
+pub mod initialize_vault;
+pub mod sweep_sol_premiums;
+pub mod request_withdrawal;
+pub mod process_withdrawal;
+pub mod cancel_withdrawal;
+pub mod get_share_price;
+pub mod initialize_tranche;
+pub mod add_tranche_liquidity;
+pub mod accrue_premium;
+pub mod open_stake_position;
+pub mod stake_shield;
+pub mod open_lives_stake;
+pub mod stake_lives;
+pub mod claim_rewards;
+pub mod initialize_lives_reward_vault;
+pub mod fund_lives_rewards;
+pub mod claim_lives_rewards;
+pub mod refresh_stake_boost;
+pub mod register_reinsurance_treaty;
+pub mod initialize_registry;
+pub mod sync_registry_entry;
+pub mod register_pool;
+pub mod initialize_governance;
+pub mod create_voter_record;
+pub mod deposit_votes;
+pub mod create_proposal;
+pub mod cast_vote;
+pub mod execute_proposal;
+pub mod reject_proposal;
+pub mod queue_parameter_change;
+pub mod execute_parameter_change;
+pub mod cancel_parameter_change;
+pub mod pause_pool;
+pub mod initialize_roles;
+pub mod accept_authority;
+pub mod migrate_account;
+pub mod begin_wind_down;
+pub mod cancel_coverage;
+pub mod expire_coverage;
+pub mod open_redemption;
+pub mod redeem_shares;
+pub mod sweep_legacy_liquidity;
+pub mod migrate_claim;
 
 pub use initialize_pool::*;
 pub use create_coverage::*;
 pub use submit_claim::*;
 pub use process_payout::*;
 pub use add_liquidity::*;
-pub use remove_liquidity::*;
 pub use update_oracle::*;
+pub use list_coverage::*;
+pub use buy_coverage::*;
+pub use delist_coverage::*;
+pub use quote_premium::*;
+pub use initialize_vault::*;
+pub use sweep_sol_premiums::*;
+pub use request_withdrawal::*;
+pub use process_withdrawal::*;
+pub use cancel_withdrawal::*;
+pub use get_share_price::*;
+pub use initialize_tranche::*;
+pub use add_tranche_liquidity::*;
+pub use accrue_premium::*;
+pub use open_stake_position::*;
+pub use stake_shield::*;
+pub use open_lives_stake::*;
+pub use stake_lives::*;
+pub use claim_rewards::*;
+pub use initialize_lives_reward_vault::*;
+pub use fund_lives_rewards::*;
+pub use claim_lives_rewards::*;
+pub use refresh_stake_boost::*;
+pub use register_reinsurance_treaty::*;
+pub use initialize_registry::*;
+pub use sync_registry_entry::*;
+pub use register_pool::*;
+pub use initialize_governance::*;
+pub use create_voter_record::*;
+pub use deposit_votes::*;
+pub use create_proposal::*;
+pub use cast_vote::*;
+pub use execute_proposal::*;
+pub use reject_proposal::*;
+pub use queue_parameter_change::*;
+pub use execute_parameter_change::*;
+pub use cancel_parameter_change::*;
+pub use pause_pool::*;
+pub use initialize_roles::*;
+pub use accept_authority::*;
+pub use migrate_account::*;
+pub use begin_wind_down::*;
+pub use cancel_coverage::*;
+pub use expire_coverage::*;
+pub use open_redemption::*;
+pub use redeem_shares::*;
+pub use sweep_legacy_liquidity::*;
+pub use migrate_claim::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+    use anchor_lang::prelude::*;
+    use anchor_lang::solana_program::program_option::COption;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token;
+    use crate::errors::InsuranceError;
+    use crate::state::{
+        ClaimAccount, CoverageAccount, CoverageListing, CoverageParams, CoverageType,
+        InsurancePool, LivesStake, ParameterChange, PoolRegistry, PoolRoles, RiskCategory, Role,
+        TimelockOperation, TriggerConditions, WithdrawalRequest, COVERAGE_ACCOUNT_VERSION,
+        LEGACY_POOL_ID,
+    };
+
+    // Account infos borrow their key, lamports, data and owner; leaking them gives
+    // 'static infos that `try_accounts` can hold on to for the rest of the test.
+    fn account_info(key: Pubkey, owner: Pubkey, data: Vec<u8>, is_signer: bool) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            is_signer,
+            true,
+            Box::leak(Box::new(1_000_000_000)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )
+    }
+
+    fn wallet(key: Pubkey) -> AccountInfo<'static> {
+        account_info(key, anchor_lang::system_program::ID, Vec::new(), true)
+    }
+
+    fn program_account<T: AccountSerialize>(key: Pubkey, account: &T) -> AccountInfo<'static> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        account_info(key, crate::ID, data, false)
+    }
+
+    /// State account with every field zeroed, to be filled in by the test
+    fn blank<T: AccountDeserialize>(size: usize) -> T {
+        T::try_deserialize_unchecked(&mut &vec![0u8; size][..]).unwrap()
+    }
+
+    fn token_account(key: Pubkey, mint: Pubkey, owner: Pubkey) -> AccountInfo<'static> {
+        let account = spl_token::state::Account {
+            mint,
+            owner,
+            amount: 1_000_000,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+        let mut data = vec![0u8; spl_token::state::Account::LEN];
+        spl_token::state::Account::pack(account, &mut data).unwrap();
+        account_info(key, spl_token::ID, data, false)
+    }
+
+    fn mint_account(key: Pubkey, authority: Pubkey) -> AccountInfo<'static> {
+        let mint = spl_token::state::Mint {
+            mint_authority: COption::Some(authority),
+            supply: 1_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        let mut data = vec![0u8; spl_token::state::Mint::LEN];
+        spl_token::state::Mint::pack(mint, &mut data).unwrap();
+        account_info(key, spl_token::ID, data, false)
+    }
+
+    /// Account about to be created by an `init` constraint. It already belongs to the program,
+    /// since the system program CPIs that would hand it over are no-ops off-chain.
+    fn uninitialized(key: Pubkey, size: usize) -> AccountInfo<'static> {
+        account_info(key, crate::ID, vec![0; size], false)
+    }
+
+    struct TestRuntime;
+
+    impl anchor_lang::solana_program::program_stubs::SyscallStubs for TestRuntime {
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            // SAFETY: the sysvar getter hands over a pointer to an uninitialized Rent
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            anchor_lang::solana_program::entrypoint::SUCCESS
+        }
+    }
+
+    /// `init` constraints read the rent sysvar, which has no off-chain default
+    fn with_runtime() {
+        anchor_lang::solana_program::program_stubs::set_syscall_stubs(Box::new(TestRuntime));
+    }
+
+    fn system_program() -> AccountInfo<'static> {
+        let mut info = account_info(anchor_lang::system_program::ID, Pubkey::default(), Vec::new(), false);
+        info.executable = true;
+        info
+    }
+
+    fn token_program() -> AccountInfo<'static> {
+        let mut info = account_info(spl_token::ID, Pubkey::default(), Vec::new(), false);
+        info.executable = true;
+        info
+    }
+
+    /// Stands in for an omitted optional account
+    fn absent() -> AccountInfo<'static> {
+        account_info(crate::ID, Pubkey::default(), Vec::new(), false)
+    }
+
+    fn leak(infos: Vec<AccountInfo<'static>>) -> &'static [AccountInfo<'static>] {
+        Box::leak(infos.into_boxed_slice())
+    }
+
+    struct Fixture {
+        pool_key: Pubkey,
+        pool: InsurancePool,
+        settlement_vault: Pubkey,
+        wallet: Pubkey,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let mut pool: InsurancePool = blank(InsurancePool::SIZE);
+            pool.creator = Pubkey::new_unique();
+            pool.pool_id = 1;
+            pool.settlement_mint = Pubkey::new_unique();
+            pool.lives_token_mint = Pubkey::new_unique();
+            pool.shield_token_mint = Pubkey::new_unique();
+
+            let (pool_key, bump) = Pubkey::find_program_address(
+                &[b"insurance_pool", pool.creator.as_ref(), &pool.pool_id.to_le_bytes()],
+                &crate::ID,
+            );
+            pool.bump = bump;
+            let (settlement_vault, _) = Pubkey::find_program_address(
+                &[b"vault", pool_key.as_ref(), pool.settlement_mint.as_ref()],
+                &crate::ID,
+            );
+
+            Self { pool_key, pool, settlement_vault, wallet: Pubkey::new_unique() }
+        }
+
+        /// A pool migrated from the single-pool layout, still at its authority-seeded address
+        fn legacy() -> Self {
+            let mut fixture = Self::new();
+            fixture.pool.pool_id = LEGACY_POOL_ID;
+            let (pool_key, bump) = Pubkey::find_program_address(
+                &[b"insurance_pool", fixture.pool.creator.as_ref()],
+                &crate::ID,
+            );
+            fixture.pool.bump = bump;
+            fixture.pool_key = pool_key;
+            (fixture.settlement_vault, _) = Pubkey::find_program_address(
+                &[b"vault", pool_key.as_ref(), fixture.pool.settlement_mint.as_ref()],
+                &crate::ID,
+            );
+            fixture
+        }
+
+        fn pool(&self) -> AccountInfo<'static> {
+            program_account(self.pool_key, &self.pool)
+        }
+
+        fn roles(&self, member: Pubkey, role: Role) -> AccountInfo<'static> {
+            let mut roles: PoolRoles = blank(PoolRoles::SIZE);
+            let (key, bump) = Pubkey::find_program_address(&[b"roles", self.pool_key.as_ref()], &crate::ID);
+            roles.pool = self.pool_key;
+            roles.bump = bump;
+            roles.grant(member, role).unwrap();
+            program_account(key, &roles)
+        }
+
+        fn settlement_vault(&self) -> AccountInfo<'static> {
+            token_account(self.settlement_vault, self.pool.settlement_mint, self.pool_key)
+        }
+
+        /// The pool's vault PDA for one of its accepted assets
+        fn vault(&self, mint: Pubkey) -> AccountInfo<'static> {
+            let (key, _) = Pubkey::find_program_address(
+                &[b"vault", self.pool_key.as_ref(), mint.as_ref()],
+                &crate::ID,
+            );
+            token_account(key, mint, self.pool_key)
+        }
+
+        /// Pool-owned settlement account that is not the pool's vault PDA
+        fn stray_vault(&self) -> AccountInfo<'static> {
+            token_account(Pubkey::new_unique(), self.pool.settlement_mint, self.pool_key)
+        }
+
+        fn wallet_token_account(&self, mint: Pubkey) -> AccountInfo<'static> {
+            token_account(Pubkey::new_unique(), mint, self.wallet)
+        }
+    }
+
+    struct AddLiquidityAccounts {
+        provider_token: AccountInfo<'static>,
+        settlement_vault: AccountInfo<'static>,
+        shield_mint: AccountInfo<'static>,
+        provider_shield: AccountInfo<'static>,
+    }
+
+    impl AddLiquidityAccounts {
+        fn honest(fixture: &Fixture) -> Self {
+            Self {
+                provider_token: fixture.wallet_token_account(fixture.pool.settlement_mint),
+                settlement_vault: fixture.settlement_vault(),
+                shield_mint: mint_account(fixture.pool.shield_token_mint, fixture.pool_key),
+                provider_shield: fixture.wallet_token_account(fixture.pool.shield_token_mint),
+            }
+        }
+
+        fn validate(self, fixture: &Fixture) -> Result<()> {
+            let mut infos = leak(vec![
+                fixture.pool(),
+                wallet(fixture.wallet),
+                self.provider_token,
+                self.settlement_vault,
+                self.shield_mint,
+                self.provider_shield,
+                token_program(),
+            ]);
+            AddLiquidity::try_accounts(
+                &crate::ID,
+                &mut infos,
+                &[],
+                &mut AddLiquidityBumps::default(),
+                &mut BTreeSet::new(),
+            )
+            .map(|_| ())
+        }
+    }
+
+    #[test]
+    fn test_add_liquidity_accepts_pool_accounts() {
+        let fixture = Fixture::new();
+        assert!(AddLiquidityAccounts::honest(&fixture).validate(&fixture).is_ok());
+    }
+
+    #[test]
+    fn test_add_liquidity_rejects_deposit_of_worthless_mint() {
+        let fixture = Fixture::new();
+        let mut accounts = AddLiquidityAccounts::honest(&fixture);
+        accounts.provider_token = fixture.wallet_token_account(Pubkey::new_unique());
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    #[test]
+    fn test_add_liquidity_rejects_foreign_shield_mint() {
+        let fixture = Fixture::new();
+        let mut accounts = AddLiquidityAccounts::honest(&fixture);
+        let fake_mint = Pubkey::new_unique();
+        accounts.shield_mint = mint_account(fake_mint, fixture.pool_key);
+        accounts.provider_shield = fixture.wallet_token_account(fake_mint);
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    #[test]
+    fn test_add_liquidity_rejects_shares_to_another_mint() {
+        let fixture = Fixture::new();
+        let mut accounts = AddLiquidityAccounts::honest(&fixture);
+        accounts.provider_shield = fixture.wallet_token_account(fixture.pool.lives_token_mint);
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    #[test]
+    fn test_add_liquidity_rejects_pool_owned_account_as_vault() {
+        let fixture = Fixture::new();
+        let mut accounts = AddLiquidityAccounts::honest(&fixture);
+        accounts.settlement_vault = fixture.stray_vault();
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintSeeds.into())
+        );
+    }
+
+    struct ProcessClaimAccounts {
+        coverage_pool: Pubkey,
+        claimed_coverage: Option<Pubkey>, // the coverage the claim names, if not the one passed
+        claimant_token: AccountInfo<'static>,
+        settlement_vault: AccountInfo<'static>,
+        lives_vault: AccountInfo<'static>,
+        claimant_lives: AccountInfo<'static>,
+        sol_vault: AccountInfo<'static>,
+        claimant_sol: AccountInfo<'static>,
+    }
+
+    impl ProcessClaimAccounts {
+        fn honest(fixture: &Fixture) -> Self {
+            Self {
+                coverage_pool: fixture.pool_key,
+                claimed_coverage: None,
+                claimant_token: fixture.wallet_token_account(fixture.pool.settlement_mint),
+                settlement_vault: fixture.settlement_vault(),
+                lives_vault: absent(),
+                claimant_lives: absent(),
+                sol_vault: absent(),
+                claimant_sol: absent(),
+            }
+        }
+
+        /// Honest accounts that can also pay a shortfall out of the LIVES and wrapped SOL vaults
+        fn with_other_vaults(fixture: &Fixture) -> Self {
+            let native_mint = spl_token::native_mint::ID;
+            Self {
+                lives_vault: fixture.vault(fixture.pool.lives_token_mint),
+                claimant_lives: fixture.wallet_token_account(fixture.pool.lives_token_mint),
+                sol_vault: fixture.vault(native_mint),
+                claimant_sol: fixture.wallet_token_account(native_mint),
+                ..Self::honest(fixture)
+            }
+        }
+
+        fn validate(self, fixture: &Fixture) -> Result<()> {
+            let processor = Pubkey::new_unique();
+            let coverage_key = Pubkey::new_unique();
+
+            let mut coverage: CoverageAccount = blank(CoverageAccount::SIZE);
+            coverage.pool = self.coverage_pool;
+            let mut claim: ClaimAccount = blank(ClaimAccount::SIZE);
+            claim.coverage = self.claimed_coverage.unwrap_or(coverage_key);
+            claim.claimant = fixture.wallet;
+
+            let mut infos = leak(vec![
+                program_account(Pubkey::new_unique(), &claim),
+                program_account(coverage_key, &coverage),
+                fixture.pool(),
+                fixture.roles(processor, Role::ClaimsProcessor),
+                wallet(processor),
+                self.settlement_vault,
+                self.claimant_token,
+                self.lives_vault,
+                self.claimant_lives,
+                self.sol_vault,
+                self.claimant_sol,
+                absent(),
+                absent(),
+                absent(),
+                absent(),
+                absent(),
+                absent(),
+                token_program(),
+            ]);
+            ProcessClaimWithOracle::try_accounts(
+                &crate::ID,
+                &mut infos,
+                &[],
+                &mut ProcessClaimWithOracleBumps::default(),
+                &mut BTreeSet::new(),
+            )
+            .map(|_| ())
+        }
+    }
+
+    #[test]
+    fn test_process_claim_accepts_pool_accounts() {
+        let fixture = Fixture::new();
+        assert!(ProcessClaimAccounts::honest(&fixture).validate(&fixture).is_ok());
+    }
+
+    #[test]
+    fn test_process_claim_rejects_payout_to_another_mint() {
+        let fixture = Fixture::new();
+        let mut accounts = ProcessClaimAccounts::honest(&fixture);
+        accounts.claimant_token = fixture.wallet_token_account(Pubkey::new_unique());
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    #[test]
+    fn test_process_claim_rejects_payout_to_another_wallet() {
+        let fixture = Fixture::new();
+        let mut accounts = ProcessClaimAccounts::honest(&fixture);
+        accounts.claimant_token = token_account(
+            Pubkey::new_unique(),
+            fixture.pool.settlement_mint,
+            Pubkey::new_unique(),
+        );
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenOwner.into())
+        );
+    }
+
+    #[test]
+    fn test_process_claim_rejects_pool_owned_account_as_vault() {
+        let fixture = Fixture::new();
+        let mut accounts = ProcessClaimAccounts::honest(&fixture);
+        accounts.settlement_vault = fixture.stray_vault();
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintSeeds.into())
+        );
+    }
+
+    #[test]
+    fn test_process_claim_rejects_coverage_of_another_pool() {
+        let fixture = Fixture::new();
+        let mut accounts = ProcessClaimAccounts::honest(&fixture);
+        accounts.coverage_pool = Pubkey::new_unique();
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::CoverageNotInPool.into())
+        );
+    }
+
+    #[test]
+    fn test_process_claim_rejects_coverage_the_claim_was_not_made_against() {
+        let fixture = Fixture::new();
+        let mut accounts = ProcessClaimAccounts::honest(&fixture);
+        accounts.claimed_coverage = Some(Pubkey::new_unique());
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::ClaimCoverageMismatch.into())
+        );
+    }
+
+    #[test]
+    fn test_process_claim_accepts_every_pool_vault() {
+        let fixture = Fixture::new();
+        let accounts = ProcessClaimAccounts::with_other_vaults(&fixture);
+
+        assert!(accounts.validate(&fixture).is_ok());
+    }
+
+    #[test]
+    fn test_process_claim_rejects_pool_owned_account_as_lives_vault() {
+        let fixture = Fixture::new();
+        let mut accounts = ProcessClaimAccounts::with_other_vaults(&fixture);
+        accounts.lives_vault = token_account(
+            Pubkey::new_unique(),
+            fixture.pool.lives_token_mint,
+            fixture.pool_key,
+        );
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintSeeds.into())
+        );
+    }
+
+    #[test]
+    fn test_process_claim_rejects_lives_payout_to_another_wallet() {
+        let fixture = Fixture::new();
+        let mut accounts = ProcessClaimAccounts::with_other_vaults(&fixture);
+        accounts.claimant_lives = token_account(
+            Pubkey::new_unique(),
+            fixture.pool.lives_token_mint,
+            Pubkey::new_unique(),
+        );
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenOwner.into())
+        );
+    }
+
+    #[test]
+    fn test_process_claim_rejects_sol_vault_of_a_wrapped_sol_pool() {
+        // The wrapped SOL vault is already the settlement vault, so it cannot pay twice
+        let mut fixture = Fixture::new();
+        fixture.pool.settlement_mint = spl_token::native_mint::ID;
+        fixture.settlement_vault = Pubkey::find_program_address(
+            &[b"vault", fixture.pool_key.as_ref(), spl_token::native_mint::ID.as_ref()],
+            &crate::ID,
+        )
+        .0;
+        let mut accounts = ProcessClaimAccounts::with_other_vaults(&fixture);
+        accounts.claimant_token = fixture.wallet_token_account(spl_token::native_mint::ID);
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    struct RedeemSharesAccounts {
+        settlement_vault: AccountInfo<'static>,
+        provider_settlement: AccountInfo<'static>,
+        lives_vault: AccountInfo<'static>,
+        provider_lives: AccountInfo<'static>,
+        sol_vault: AccountInfo<'static>,
+        provider_sol: AccountInfo<'static>,
+    }
+
+    impl RedeemSharesAccounts {
+        fn honest(fixture: &Fixture) -> Self {
+            let native_mint = spl_token::native_mint::ID;
+            Self {
+                settlement_vault: fixture.settlement_vault(),
+                provider_settlement: fixture.wallet_token_account(fixture.pool.settlement_mint),
+                lives_vault: fixture.vault(fixture.pool.lives_token_mint),
+                provider_lives: fixture.wallet_token_account(fixture.pool.lives_token_mint),
+                sol_vault: fixture.vault(native_mint),
+                provider_sol: fixture.wallet_token_account(native_mint),
+            }
+        }
+
+        fn validate(self, fixture: &Fixture) -> Result<()> {
+            let share_mint = fixture.pool.shield_token_mint;
+            let mut infos = leak(vec![
+                fixture.pool(),
+                mint_account(share_mint, fixture.pool_key),
+                fixture.wallet_token_account(share_mint),
+                self.settlement_vault,
+                self.provider_settlement,
+                self.lives_vault,
+                self.provider_lives,
+                self.sol_vault,
+                self.provider_sol,
+                wallet(fixture.wallet),
+                token_program(),
+            ]);
+            RedeemShares::try_accounts(
+                &crate::ID,
+                &mut infos,
+                &[],
+                &mut RedeemSharesBumps::default(),
+                &mut BTreeSet::new(),
+            )
+            .map(|_| ())
+        }
+    }
+
+    #[test]
+    fn test_redeem_shares_accepts_every_pool_vault() {
+        let fixture = Fixture::new();
+        assert!(RedeemSharesAccounts::honest(&fixture).validate(&fixture).is_ok());
+    }
+
+    #[test]
+    fn test_redeem_shares_rejects_pool_owned_account_as_lives_vault() {
+        let fixture = Fixture::new();
+        let mut accounts = RedeemSharesAccounts::honest(&fixture);
+        accounts.lives_vault = token_account(
+            Pubkey::new_unique(),
+            fixture.pool.lives_token_mint,
+            fixture.pool_key,
+        );
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintSeeds.into())
+        );
+    }
+
+    #[test]
+    fn test_redeem_shares_rejects_lives_slice_to_another_wallet() {
+        let fixture = Fixture::new();
+        let mut accounts = RedeemSharesAccounts::honest(&fixture);
+        accounts.provider_lives = token_account(
+            Pubkey::new_unique(),
+            fixture.pool.lives_token_mint,
+            Pubkey::new_unique(),
+        );
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenOwner.into())
+        );
+    }
+
+    #[test]
+    fn test_redeem_shares_rejects_sol_slice_in_another_mint() {
+        let fixture = Fixture::new();
+        let mut accounts = RedeemSharesAccounts::honest(&fixture);
+        accounts.provider_sol = fixture.wallet_token_account(fixture.pool.settlement_mint);
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    #[test]
+    fn test_redeem_shares_rejects_sol_vault_of_a_wrapped_sol_pool() {
+        // The wrapped SOL vault is already the settlement vault, so it cannot pay twice
+        let mut fixture = Fixture::new();
+        fixture.pool.settlement_mint = spl_token::native_mint::ID;
+        fixture.settlement_vault = Pubkey::find_program_address(
+            &[b"vault", fixture.pool_key.as_ref(), spl_token::native_mint::ID.as_ref()],
+            &crate::ID,
+        )
+        .0;
+        let accounts = RedeemSharesAccounts::honest(&fixture);
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    fn withdrawal_request(fixture: &Fixture, sequence: u64) -> (Pubkey, AccountInfo<'static>) {
+        let (key, bump) = Pubkey::find_program_address(
+            &[b"withdrawal", fixture.pool_key.as_ref(), &sequence.to_le_bytes()],
+            &crate::ID,
+        );
+        let mut request: WithdrawalRequest = blank(WithdrawalRequest::SIZE);
+        request.pool = fixture.pool_key;
+        request.provider = fixture.wallet;
+        request.share_mint = fixture.pool.shield_token_mint;
+        request.shield_amount = 1_000;
+        request.sequence = sequence;
+        request.bump = bump;
+        (key, program_account(key, &request))
+    }
+
+    fn withdrawal_escrow(fixture: &Fixture, request: Pubkey) -> AccountInfo<'static> {
+        let (key, _) = Pubkey::find_program_address(&[b"withdrawal_escrow", request.as_ref()], &crate::ID);
+        token_account(key, fixture.pool.shield_token_mint, fixture.pool_key)
+    }
+
+    fn validate_process_withdrawal(fixture: &Fixture, sequence: u64) -> Result<()> {
+        let (request_key, request) = withdrawal_request(fixture, sequence);
+        let mut provider = wallet(fixture.wallet);
+        provider.is_signer = false;
+
+        let mut infos = leak(vec![
+            fixture.pool(),
+            request,
+            withdrawal_escrow(fixture, request_key),
+            mint_account(fixture.pool.shield_token_mint, fixture.pool_key),
+            fixture.settlement_vault(),
+            fixture.wallet_token_account(fixture.pool.settlement_mint),
+            provider,
+            token_program(),
+        ]);
+        ProcessWithdrawal::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut ProcessWithdrawalBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_process_withdrawal_takes_the_head_of_the_queue() {
+        let mut fixture = Fixture::new();
+        fixture.pool.withdrawal_queue.head = 3;
+        fixture.pool.withdrawal_queue.tail = 5;
+
+        assert!(validate_process_withdrawal(&fixture, 3).is_ok());
+    }
+
+    #[test]
+    fn test_process_withdrawal_refuses_to_jump_the_queue() {
+        let mut fixture = Fixture::new();
+        fixture.pool.withdrawal_queue.head = 3;
+        fixture.pool.withdrawal_queue.tail = 5;
+
+        assert_eq!(
+            validate_process_withdrawal(&fixture, 4).err(),
+            Some(InsuranceError::WithdrawalOutOfOrder.into())
+        );
+    }
+
+    fn validate_cancel_withdrawal(fixture: &Fixture, signer: Pubkey) -> Result<()> {
+        let (request_key, request) = withdrawal_request(fixture, 0);
+
+        let mut infos = leak(vec![
+            fixture.pool(),
+            request,
+            withdrawal_escrow(fixture, request_key),
+            token_account(Pubkey::new_unique(), fixture.pool.shield_token_mint, signer),
+            wallet(signer),
+            token_program(),
+        ]);
+        CancelWithdrawal::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut CancelWithdrawalBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_provider_can_cancel_their_withdrawal() {
+        let fixture = Fixture::new();
+        assert!(validate_cancel_withdrawal(&fixture, fixture.wallet).is_ok());
+    }
+
+    #[test]
+    fn test_only_the_provider_can_cancel_a_withdrawal() {
+        let fixture = Fixture::new();
+
+        assert_eq!(
+            validate_cancel_withdrawal(&fixture, Pubkey::new_unique()).err(),
+            Some(InsuranceError::Unauthorized.into())
+        );
+    }
+
+    fn registry_page(page: u32) -> AccountInfo<'static> {
+        let (key, bump) = Pubkey::find_program_address(&[b"pool_registry", &page.to_le_bytes()], &crate::ID);
+        program_account(key, &PoolRegistry { page, pools: Vec::new(), bump })
+    }
+
+    fn validate_register_pool(fixture: &Fixture, registry: AccountInfo<'static>) -> Result<()> {
+        let mut infos = leak(vec![fixture.pool(), registry]);
+        RegisterPool::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut RegisterPoolBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_pool_can_be_listed_on_any_registry_page() {
+        let fixture = Fixture::new();
+
+        assert!(validate_register_pool(&fixture, registry_page(0)).is_ok());
+        assert!(validate_register_pool(&fixture, registry_page(7)).is_ok());
+    }
+
+    #[test]
+    fn test_legacy_pool_is_found_at_its_authority_seeded_address() {
+        let fixture = Fixture::legacy();
+        assert!(validate_register_pool(&fixture, registry_page(0)).is_ok());
+
+        // Seeded with its ID it would be a different account
+        let (id_seeded, _) = Pubkey::find_program_address(
+            &[b"insurance_pool", fixture.pool.creator.as_ref(), &LEGACY_POOL_ID.to_le_bytes()],
+            &crate::ID,
+        );
+        let mut moved = Fixture::legacy();
+        moved.pool_key = id_seeded;
+        assert_eq!(
+            validate_register_pool(&moved, registry_page(0)).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintSeeds.into())
+        );
+    }
+
+    fn validate_execute_parameter_change(fixture: &Fixture, proposer_is_admin: bool) -> Result<()> {
+        let proposer = Pubkey::new_unique();
+        let (key, bump) = Pubkey::find_program_address(
+            &[b"timelock", fixture.pool_key.as_ref(), &0u64.to_le_bytes()],
+            &crate::ID,
+        );
+        let mut operation: TimelockOperation = blank(TimelockOperation::SIZE);
+        operation.pool = fixture.pool_key;
+        operation.proposer = proposer;
+        operation.payer = fixture.wallet;
+        operation.actions = vec![ParameterChange::Pause { paused: true }];
+        operation.bump = bump;
+
+        let admin = if proposer_is_admin { proposer } else { Pubkey::new_unique() };
+        let mut payer = wallet(fixture.wallet);
+        payer.is_signer = false;
+
+        let mut infos = leak(vec![
+            fixture.pool(),
+            fixture.roles(admin, Role::Admin),
+            program_account(key, &operation),
+            payer,
+            absent(),
+        ]);
+        ExecuteParameterChange::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut ExecuteParameterChangeBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_parameter_change_runs_while_its_proposer_is_admin() {
+        let fixture = Fixture::new();
+        assert!(validate_execute_parameter_change(&fixture, true).is_ok());
+    }
+
+    #[test]
+    fn test_parameter_change_lapses_once_its_proposer_loses_admin() {
+        let fixture = Fixture::new();
+
+        assert_eq!(
+            validate_execute_parameter_change(&fixture, false).err(),
+            Some(InsuranceError::MissingRole.into())
+        );
+    }
+
+    fn validate_queue_parameter_change(fixture: &Fixture, signer: Pubkey, admin: Pubkey) -> Result<()> {
+        let (operation, _) = Pubkey::find_program_address(
+            &[b"timelock", fixture.pool_key.as_ref(), &fixture.pool.timelock_operation_count.to_le_bytes()],
+            &crate::ID,
+        );
+
+        let mut infos = leak(vec![
+            fixture.pool(),
+            fixture.roles(admin, Role::Admin),
+            uninitialized(operation, TimelockOperation::SIZE),
+            wallet(signer),
+            system_program(),
+        ]);
+        QueueParameterChange::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut QueueParameterChangeBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_admin_can_queue_a_parameter_change() {
+        with_runtime();
+        let fixture = Fixture::new();
+        assert!(validate_queue_parameter_change(&fixture, fixture.wallet, fixture.wallet).is_ok());
+    }
+
+    #[test]
+    fn test_only_an_admin_can_queue_a_parameter_change() {
+        with_runtime();
+        let fixture = Fixture::new();
+
+        assert_eq!(
+            validate_queue_parameter_change(&fixture, Pubkey::new_unique(), fixture.wallet).err(),
+            Some(InsuranceError::MissingRole.into())
+        );
+    }
+
+    fn validate_initialize_roles(fixture: &Fixture, signer: Pubkey) -> Result<()> {
+        let (roles, _) = Pubkey::find_program_address(&[b"roles", fixture.pool_key.as_ref()], &crate::ID);
+
+        let mut infos = leak(vec![
+            fixture.pool(),
+            uninitialized(roles, PoolRoles::SIZE),
+            wallet(signer),
+            system_program(),
+        ]);
+        InitializeRoles::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut InitializeRolesBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_migrated_pool_authority_can_backfill_roles() {
+        with_runtime();
+        let mut fixture = Fixture::legacy();
+        fixture.pool.authority = fixture.wallet;
+
+        assert!(validate_initialize_roles(&fixture, fixture.wallet).is_ok());
+        assert_eq!(
+            validate_initialize_roles(&fixture, Pubkey::new_unique()).err(),
+            Some(InsuranceError::Unauthorized.into())
+        );
+    }
+
+    fn validate_accept_authority(fixture: &Fixture, signer: Pubkey) -> Result<()> {
+        let mut infos = leak(vec![
+            fixture.pool(),
+            fixture.roles(fixture.pool.authority, Role::Admin),
+            wallet(signer),
+        ]);
+        AcceptAuthority::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut AcceptAuthorityBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_only_the_nominee_can_accept_authority() {
+        let mut fixture = Fixture::new();
+        fixture.pool.authority = Pubkey::new_unique();
+        fixture.pool.pending_authority = Some(fixture.wallet);
+
+        assert!(validate_accept_authority(&fixture, fixture.wallet).is_ok());
+        assert_eq!(
+            validate_accept_authority(&fixture, Pubkey::new_unique()).err(),
+            Some(InsuranceError::InvalidAuthority.into())
+        );
+    }
+
+    fn validate_cancel_parameter_change(fixture: &Fixture, signer: Pubkey, guardian: Pubkey) -> Result<()> {
+        let (key, bump) = Pubkey::find_program_address(
+            &[b"timelock", fixture.pool_key.as_ref(), &0u64.to_le_bytes()],
+            &crate::ID,
+        );
+        let mut operation: TimelockOperation = blank(TimelockOperation::SIZE);
+        operation.pool = fixture.pool_key;
+        operation.payer = fixture.wallet;
+        operation.actions = vec![ParameterChange::Pause { paused: false }];
+        operation.bump = bump;
+
+        let mut payer = wallet(fixture.wallet);
+        payer.is_signer = false;
+
+        let mut infos = leak(vec![
+            fixture.pool(),
+            fixture.roles(guardian, Role::Guardian),
+            program_account(key, &operation),
+            payer,
+            wallet(signer),
+        ]);
+        CancelParameterChange::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut CancelParameterChangeBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_guardian_can_cancel_a_queued_change() {
+        let fixture = Fixture::new();
+        let guardian = Pubkey::new_unique();
+        assert!(validate_cancel_parameter_change(&fixture, guardian, guardian).is_ok());
+    }
+
+    #[test]
+    fn test_only_a_guardian_can_cancel_a_queued_change() {
+        let fixture = Fixture::new();
+
+        assert_eq!(
+            validate_cancel_parameter_change(&fixture, fixture.wallet, Pubkey::new_unique()).err(),
+            Some(InsuranceError::MissingRole.into())
+        );
+    }
+
+    fn validate_sweep_legacy_liquidity(
+        fixture: &Fixture,
+        legacy_token_account: AccountInfo<'static>,
+        vault: AccountInfo<'static>,
+    ) -> Result<()> {
+        let mut infos = leak(vec![fixture.pool(), legacy_token_account, vault, token_program()]);
+        SweepLegacyLiquidity::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut SweepLegacyLiquidityBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_legacy_liquidity_is_swept_into_the_vault_for_its_mint() {
+        let fixture = Fixture::legacy();
+        let legacy_token_account = fixture.stray_vault();
+
+        assert!(
+            validate_sweep_legacy_liquidity(&fixture, legacy_token_account, fixture.settlement_vault()).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_legacy_liquidity_sweep_rejects_another_mints_vault() {
+        let fixture = Fixture::legacy();
+        let lives_vault = fixture.vault(fixture.pool.lives_token_mint);
+
+        assert_eq!(
+            validate_sweep_legacy_liquidity(&fixture, fixture.stray_vault(), lives_vault).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintSeeds.into())
+        );
+    }
+
+    #[test]
+    fn test_legacy_liquidity_sweep_only_takes_pool_owned_accounts() {
+        let fixture = Fixture::legacy();
+        let someone_elses = fixture.wallet_token_account(fixture.pool.settlement_mint);
+
+        assert_eq!(
+            validate_sweep_legacy_liquidity(&fixture, someone_elses, fixture.settlement_vault()).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintTokenOwner.into())
+        );
+    }
+
+    #[test]
+    fn test_legacy_liquidity_sweep_is_only_for_migrated_pools() {
+        let fixture = Fixture::new();
+
+        assert_eq!(
+            validate_sweep_legacy_liquidity(&fixture, fixture.stray_vault(), fixture.settlement_vault()).err(),
+            Some(InsuranceError::InvalidPoolId.into())
+        );
+    }
+
+    fn validate_migrate_claim(coverage_version: u8) -> Result<()> {
+        let mut coverage: CoverageAccount = blank(CoverageAccount::SIZE);
+        coverage.version = coverage_version;
+
+        let mut infos = leak(vec![
+            program_account(Pubkey::new_unique(), &blank::<ClaimAccount>(ClaimAccount::SIZE)),
+            program_account(Pubkey::new_unique(), &coverage),
+            wallet(Pubkey::new_unique()),
+            system_program(),
+        ]);
+        MigrateClaim::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut MigrateClaimBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_claims_migrate_only_after_their_coverage() {
+        assert!(validate_migrate_claim(COVERAGE_ACCOUNT_VERSION).is_ok());
+        assert_eq!(
+            validate_migrate_claim(0).err(),
+            Some(InsuranceError::AccountNotMigrated.into())
+        );
+    }
+
+    fn validate_quote(
+        fixture: &Fixture,
+        lives_account: AccountInfo<'static>,
+        lives_stake: AccountInfo<'static>,
+    ) -> Result<()> {
+        let mut infos = leak(vec![
+            fixture.pool(),
+            fixture.roles(Pubkey::new_unique(), Role::OracleOperator),
+            wallet(fixture.wallet),
+            absent(),
+            lives_account,
+            lives_stake,
+            absent(),
+            absent(),
+        ]);
+        QuotePremium::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut QuotePremiumBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_quote_accepts_lives_account() {
+        let fixture = Fixture::new();
+        let lives_account = fixture.wallet_token_account(fixture.pool.lives_token_mint);
+
+        assert!(validate_quote(&fixture, lives_account, absent()).is_ok());
+    }
+
+    #[test]
+    fn test_quote_rejects_discount_from_someone_elses_lives() {
+        let fixture = Fixture::new();
+        let borrowed_lives = token_account(
+            Pubkey::new_unique(),
+            fixture.pool.lives_token_mint,
+            Pubkey::new_unique(),
+        );
+
+        assert_eq!(
+            validate_quote(&fixture, borrowed_lives, absent()).err(),
+            Some(InsuranceError::InvalidTokenOwner.into())
+        );
+    }
+
+    #[test]
+    fn test_quote_rejects_discount_from_non_lives_balance() {
+        let fixture = Fixture::new();
+        let fake_lives = fixture.wallet_token_account(Pubkey::new_unique());
+
+        assert_eq!(
+            validate_quote(&fixture, fake_lives, absent()).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    fn lives_stake(fixture: &Fixture, owner: Pubkey) -> AccountInfo<'static> {
+        let (key, bump) = Pubkey::find_program_address(
+            &[b"lives_stake", fixture.pool_key.as_ref(), owner.as_ref()],
+            &crate::ID,
+        );
+        let mut stake: LivesStake = blank(LivesStake::SIZE);
+        stake.pool = fixture.pool_key;
+        stake.owner = owner;
+        stake.amount = 1_000_000;
+        stake.bump = bump;
+        program_account(key, &stake)
+    }
+
+    #[test]
+    fn test_quote_accepts_the_insureds_lives_stake() {
+        let fixture = Fixture::new();
+        let lives_account = fixture.wallet_token_account(fixture.pool.lives_token_mint);
+
+        assert!(validate_quote(&fixture, lives_account, lives_stake(&fixture, fixture.wallet)).is_ok());
+    }
+
+    #[test]
+    fn test_quote_rejects_discount_from_someone_elses_lives_stake() {
+        let fixture = Fixture::new();
+        let lives_account = fixture.wallet_token_account(fixture.pool.lives_token_mint);
+        let borrowed_stake = lives_stake(&fixture, Pubkey::new_unique());
+
+        assert_eq!(
+            validate_quote(&fixture, lives_account, borrowed_stake).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintSeeds.into())
+        );
+    }
+
+    struct CreateCoverageAccounts {
+        lives_account: AccountInfo<'static>,
+        lives_vault: AccountInfo<'static>,
+        settlement_account: AccountInfo<'static>,
+        settlement_vault: AccountInfo<'static>,
+    }
+
+    impl CreateCoverageAccounts {
+        /// Paying in LIVES from the insured's own accounts
+        fn honest(fixture: &Fixture) -> Self {
+            Self {
+                lives_account: fixture.wallet_token_account(fixture.pool.lives_token_mint),
+                lives_vault: fixture.vault(fixture.pool.lives_token_mint),
+                settlement_account: fixture.wallet_token_account(fixture.pool.settlement_mint),
+                settlement_vault: fixture.settlement_vault(),
+            }
+        }
+
+        fn validate(self, fixture: &Fixture) -> Result<()> {
+            with_runtime();
+            let params = CoverageParams {
+                coverage_amount: 1_000_000,
+                coverage_period: 86_400,
+                coverage_type: CoverageType::ClinicalTrialFailure,
+                trigger_conditions: TriggerConditions {
+                    clinical_trial_failure: true,
+                    regulatory_rejection: false,
+                    ip_invalidation: false,
+                    minimum_threshold: 0,
+                    custom_conditions: Vec::new(),
+                },
+                risk_category: RiskCategory::Medium,
+                metadata_uri: String::new(),
+                max_premium: 0,
+                nonce: 3,
+            };
+            let (coverage_key, _) = Pubkey::find_program_address(
+                &[
+                    b"coverage",
+                    fixture.pool_key.as_ref(),
+                    fixture.wallet.as_ref(),
+                    &params.nonce.to_le_bytes(),
+                ],
+                &crate::ID,
+            );
+
+            let mut infos = leak(vec![
+                uninitialized(coverage_key, CoverageAccount::SIZE),
+                fixture.pool(),
+                fixture.roles(Pubkey::new_unique(), Role::OracleOperator),
+                wallet(fixture.wallet),
+                absent(),
+                self.lives_account,
+                absent(),
+                self.lives_vault,
+                absent(),
+                self.settlement_account,
+                self.settlement_vault,
+                absent(),
+                absent(),
+                absent(),
+                absent(),
+                token_program(),
+                system_program(),
+            ]);
+            CreateCoverage::try_accounts(
+                &crate::ID,
+                &mut infos,
+                &params.try_to_vec().unwrap(),
+                &mut CreateCoverageBumps::default(),
+                &mut BTreeSet::new(),
+            )
+            .map(|_| ())
+        }
+    }
+
+    #[test]
+    fn test_create_coverage_accepts_the_insureds_accounts() {
+        let fixture = Fixture::new();
+        assert!(CreateCoverageAccounts::honest(&fixture).validate(&fixture).is_ok());
+    }
+
+    #[test]
+    fn test_create_coverage_rejects_premium_from_someone_elses_lives() {
+        let fixture = Fixture::new();
+        let mut accounts = CreateCoverageAccounts::honest(&fixture);
+        accounts.lives_account = token_account(
+            Pubkey::new_unique(),
+            fixture.pool.lives_token_mint,
+            Pubkey::new_unique(),
+        );
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenOwner.into())
+        );
+    }
+
+    #[test]
+    fn test_create_coverage_rejects_lives_premium_in_another_mint() {
+        let fixture = Fixture::new();
+        let mut accounts = CreateCoverageAccounts::honest(&fixture);
+        accounts.lives_account = fixture.wallet_token_account(Pubkey::new_unique());
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    #[test]
+    fn test_create_coverage_rejects_premium_from_someone_elses_settlement_account() {
+        let fixture = Fixture::new();
+        let mut accounts = CreateCoverageAccounts::honest(&fixture);
+        accounts.settlement_account = token_account(
+            Pubkey::new_unique(),
+            fixture.pool.settlement_mint,
+            Pubkey::new_unique(),
+        );
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenOwner.into())
+        );
+    }
+
+    #[test]
+    fn test_create_coverage_rejects_settlement_premium_in_another_mint() {
+        let fixture = Fixture::new();
+        let mut accounts = CreateCoverageAccounts::honest(&fixture);
+        accounts.settlement_account = fixture.wallet_token_account(Pubkey::new_unique());
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    #[test]
+    fn test_create_coverage_rejects_pool_owned_account_as_lives_vault() {
+        let fixture = Fixture::new();
+        let mut accounts = CreateCoverageAccounts::honest(&fixture);
+        accounts.lives_vault = token_account(
+            Pubkey::new_unique(),
+            fixture.pool.lives_token_mint,
+            fixture.pool_key,
+        );
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintSeeds.into())
+        );
+    }
+
+    #[test]
+    fn test_create_coverage_rejects_pool_owned_account_as_settlement_vault() {
+        let fixture = Fixture::new();
+        let mut accounts = CreateCoverageAccounts::honest(&fixture);
+        accounts.settlement_vault = fixture.stray_vault();
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintSeeds.into())
+        );
+    }
+
+    struct BuyCoverageAccounts {
+        seller: Pubkey,
+        buyer_settlement: AccountInfo<'static>,
+        seller_settlement: AccountInfo<'static>,
+        treasury_settlement: AccountInfo<'static>,
+    }
+
+    impl BuyCoverageAccounts {
+        fn honest(fixture: &Fixture) -> Self {
+            let seller = Pubkey::new_unique();
+            Self {
+                seller,
+                buyer_settlement: fixture.wallet_token_account(fixture.pool.settlement_mint),
+                seller_settlement: token_account(Pubkey::new_unique(), fixture.pool.settlement_mint, seller),
+                treasury_settlement: token_account(
+                    Pubkey::new_unique(),
+                    fixture.pool.settlement_mint,
+                    fixture.pool.treasury,
+                ),
+            }
+        }
+
+        fn validate(self, fixture: &Fixture) -> Result<()> {
+            let coverage_key = Pubkey::new_unique();
+            let mut coverage: CoverageAccount = blank(CoverageAccount::SIZE);
+            coverage.pool = fixture.pool_key;
+            coverage.insured = self.seller;
+
+            let (listing_key, bump) =
+                Pubkey::find_program_address(&[b"listing", coverage_key.as_ref()], &crate::ID);
+            let mut listing: CoverageListing = blank(CoverageListing::SIZE);
+            listing.seller = self.seller;
+            listing.coverage = coverage_key;
+            listing.pool = fixture.pool_key;
+            listing.bump = bump;
+
+            let mut seller = wallet(self.seller);
+            seller.is_signer = false;
+
+            let mut infos = leak(vec![
+                program_account(listing_key, &listing),
+                program_account(coverage_key, &coverage),
+                fixture.pool(),
+                wallet(fixture.wallet),
+                self.buyer_settlement,
+                seller,
+                self.seller_settlement,
+                self.treasury_settlement,
+                token_program(),
+            ]);
+            BuyCoverage::try_accounts(
+                &crate::ID,
+                &mut infos,
+                &[],
+                &mut BuyCoverageBumps::default(),
+                &mut BTreeSet::new(),
+            )
+            .map(|_| ())
+        }
+    }
+
+    fn validate_expire_listed_coverage(fixture: &Fixture, seller: Pubkey) -> Result<()> {
+        let coverage_key = Pubkey::new_unique();
+        let mut coverage: CoverageAccount = blank(CoverageAccount::SIZE);
+        coverage.pool = fixture.pool_key;
+
+        let (listing_key, bump) =
+            Pubkey::find_program_address(&[b"listing", coverage_key.as_ref()], &crate::ID);
+        let mut listing: CoverageListing = blank(CoverageListing::SIZE);
+        listing.seller = fixture.wallet;
+        listing.coverage = coverage_key;
+        listing.bump = bump;
+
+        let mut seller = wallet(seller);
+        seller.is_signer = false;
+
+        let mut infos = leak(vec![
+            fixture.pool(),
+            program_account(coverage_key, &coverage),
+            absent(),
+            absent(),
+            program_account(listing_key, &listing),
+            seller,
+        ]);
+        ExpireCoverage::try_accounts(
+            &crate::ID,
+            &mut infos,
+            &[],
+            &mut ExpireCoverageBumps::default(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_expiry_closes_an_abandoned_listing_back_to_its_seller() {
+        let fixture = Fixture::new();
+
+        assert!(validate_expire_listed_coverage(&fixture, fixture.wallet).is_ok());
+        assert_eq!(
+            validate_expire_listed_coverage(&fixture, Pubkey::new_unique()).err(),
+            Some(anchor_lang::error::ErrorCode::ConstraintRaw.into())
+        );
+    }
+
+    fn marketplace_fixture() -> Fixture {
+        let mut fixture = Fixture::new();
+        fixture.pool.treasury = Pubkey::new_unique();
+        fixture
+    }
+
+    #[test]
+    fn test_buy_coverage_accepts_settlement_accounts() {
+        let fixture = marketplace_fixture();
+        assert!(BuyCoverageAccounts::honest(&fixture).validate(&fixture).is_ok());
+    }
+
+    #[test]
+    fn test_buy_coverage_rejects_payment_in_another_mint() {
+        let fixture = marketplace_fixture();
+        let mut accounts = BuyCoverageAccounts::honest(&fixture);
+        accounts.buyer_settlement = fixture.wallet_token_account(fixture.pool.lives_token_mint);
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    #[test]
+    fn test_buy_coverage_rejects_proceeds_to_someone_other_than_the_seller() {
+        let fixture = marketplace_fixture();
+        let mut accounts = BuyCoverageAccounts::honest(&fixture);
+        accounts.seller_settlement = fixture.wallet_token_account(fixture.pool.settlement_mint);
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenOwner.into())
+        );
+    }
+
+    #[test]
+    fn test_buy_coverage_rejects_fee_diverted_from_the_treasury() {
+        let fixture = marketplace_fixture();
+        let mut accounts = BuyCoverageAccounts::honest(&fixture);
+        accounts.treasury_settlement = fixture.wallet_token_account(fixture.pool.settlement_mint);
+
+        assert_eq!(
+            accounts.validate(&fixture).err(),
+            Some(InsuranceError::InvalidTokenOwner.into())
+        );
+    }
+}
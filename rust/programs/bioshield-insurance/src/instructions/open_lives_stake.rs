@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, LivesStake};
+
+#[derive(Accounts)]
+pub struct OpenLivesStake<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = LivesStake::SIZE,
+        seeds = [b"lives_stake", insurance_pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub lives_stake: Account<'info, LivesStake>,
+    
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"lives_stake_escrow", lives_stake.key().as_ref()],
+        bump,
+        token::mint = lives_token_mint,
+        token::authority = insurance_pool
+    )]
+    pub lives_stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        constraint = lives_token_mint.key() == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub lives_token_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, StakePosition};
+
+#[derive(Accounts)]
+pub struct OpenStakePosition<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = StakePosition::SIZE,
+        seeds = [b"stake_position", insurance_pool.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"stake_escrow", stake_position.key().as_ref()],
+        bump,
+        token::mint = shield_token_mint,
+        token::authority = insurance_pool
+    )]
+    pub stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        constraint = shield_token_mint.key() == insurance_pool.shield_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub shield_token_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
-use crate::state::{InsurancePool, CoverageAccount, ClaimAccount, OracleData};
+use anchor_spl::token::spl_token::native_mint;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, CoverageAccount, ClaimAccount, ReinsuranceTreaty, PoolRoles, Role};
 
 #[derive(Accounts)]
 pub struct ProcessClaimWithOracle<'info> {
@@ -12,33 +15,100 @@ pub struct ProcessClaimWithOracle<'info> {
     
     #[account(
         mut,
-        constraint = coverage_account.key() == claim_account.coverage
+        constraint = coverage_account.key() == claim_account.coverage @ InsuranceError::ClaimCoverageMismatch,
+        constraint = coverage_account.pool == insurance_pool.key() @ InsuranceError::CoverageNotInPool
     )]
     pub coverage_account: Account<'info, CoverageAccount>,
     
     #[account(
         mut,
-        seeds = [b"insurance_pool", insurance_pool.authority.as_ref()],
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
         bump = insurance_pool.bump
     )]
     pub insurance_pool: Account<'info, InsurancePool>,
     
-    /// CHECK: Oracle processor account
+    #[account(
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(&processor.key(), Role::ClaimsProcessor) @ InsuranceError::MissingRole
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    /// Claims processor, checked against the pool's roles
     pub processor: Signer<'info>,
     
-    /// CHECK: Pool's token account for payouts
+    /// Pool's settlement vault for payouts
     #[account(
         mut,
-        constraint = pool_token_account.owner == insurance_pool.key()
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.settlement_mint.as_ref()],
+        bump
     )]
-    pub pool_token_account: Account<'info, TokenAccount>,
+    pub settlement_vault: Account<'info, TokenAccount>,
     
-    /// CHECK: Claimant's token account
+    /// Claimant's settlement token account receiving the payout
     #[account(
         mut,
-        constraint = claimant_token_account.owner == claim_account.claimant
+        constraint = claimant_token_account.owner == claim_account.claimant @ InsuranceError::InvalidTokenOwner,
+        constraint = claimant_token_account.mint == insurance_pool.settlement_mint @ InsuranceError::InvalidTokenMint
     )]
     pub claimant_token_account: Account<'info, TokenAccount>,
     
+    /// Pool's LIVES vault, drawn on when the settlement vault cannot pay the whole claim
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.lives_token_mint.as_ref()],
+        bump
+    )]
+    pub lives_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Claimant's LIVES account receiving any part of the payout made in LIVES
+    #[account(
+        mut,
+        constraint = claimant_lives_account.owner == claim_account.claimant @ InsuranceError::InvalidTokenOwner,
+        constraint = claimant_lives_account.mint == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub claimant_lives_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Pool's wrapped SOL vault, when it is not already the settlement vault
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), native_mint::ID.as_ref()],
+        bump,
+        constraint = insurance_pool.settlement_mint != native_mint::ID @ InsuranceError::InvalidTokenMint
+    )]
+    pub sol_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Claimant's wrapped SOL account receiving any part of the payout made in SOL
+    #[account(
+        mut,
+        constraint = claimant_sol_account.owner == claim_account.claimant @ InsuranceError::InvalidTokenOwner,
+        constraint = claimant_sol_account.mint == native_mint::ID @ InsuranceError::InvalidTokenMint
+    )]
+    pub claimant_sol_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Prices for paying a shortfall out of the other vaults, checked against the pool's feeds
+    pub settlement_price_update: Option<Account<'info, PriceUpdateV2>>,
+    pub lives_price_update: Option<Account<'info, PriceUpdateV2>>,
+    pub sol_price_update: Option<Account<'info, PriceUpdateV2>>,
+    
+    /// Pool's reinsurance treaty, required when it has one
+    #[account(
+        mut,
+        constraint = reinsurance_treaty.cedent == insurance_pool.key()
+    )]
+    pub reinsurance_treaty: Option<Account<'info, ReinsuranceTreaty>>,
+    
+    /// Reinsurer pool named by the treaty
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", reinsurer_pool.creator.as_ref(), &reinsurer_pool.pool_id_seed()[..]],
+        bump = reinsurer_pool.bump
+    )]
+    pub reinsurer_pool: Option<Account<'info, InsurancePool>>,
+    
+    /// Reinsurer's settlement vault, checked against its ledger
+    #[account(mut)]
+    pub reinsurer_settlement_vault: Option<Account<'info, TokenAccount>>,
+    
     pub token_program: Program<'info, Token>,
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, WithdrawalRequest};
+
+#[derive(Accounts)]
+pub struct ProcessWithdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"withdrawal",
+            insurance_pool.key().as_ref(),
+            &withdrawal_request.sequence.to_le_bytes()
+        ],
+        bump = withdrawal_request.bump,
+        constraint = withdrawal_request.sequence == insurance_pool.withdrawal_queue.head @ InsuranceError::WithdrawalOutOfOrder
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+    
+    #[account(
+        mut,
+        seeds = [b"withdrawal_escrow", withdrawal_request.key().as_ref()],
+        bump
+    )]
+    pub shield_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = shield_token_mint.key() == withdrawal_request.share_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub shield_token_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.settlement_mint.as_ref()],
+        bump
+    )]
+    pub settlement_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_settlement_account.owner == withdrawal_request.provider @ InsuranceError::InvalidTokenOwner,
+        constraint = provider_settlement_account.mint == insurance_pool.settlement_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub provider_settlement_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Provider receiving the request and escrow rent, checked against the request
+    #[account(
+        mut,
+        constraint = liquidity_provider.key() == withdrawal_request.provider
+    )]
+    pub liquidity_provider: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
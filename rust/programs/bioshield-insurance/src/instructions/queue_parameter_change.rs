@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, TimelockOperation, PoolRoles, Role};
+
+#[derive(Accounts)]
+pub struct QueueParameterChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(&authority.key(), Role::Admin) @ InsuranceError::MissingRole
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = TimelockOperation::SIZE,
+        seeds = [b"timelock", insurance_pool.key().as_ref(), &insurance_pool.timelock_operation_count.to_le_bytes()],
+        bump
+    )]
+    pub operation: Account<'info, TimelockOperation>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
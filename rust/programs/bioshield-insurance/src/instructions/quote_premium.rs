@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, LivesStake, PoolRoles, Role};
+
+/// Read-only: meant to be simulated, with the breakdown read back from return data
+#[derive(Accounts)]
+pub struct QuotePremium<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    pub insured: Signer<'info>,
+    
+    /// Oracle operator co-signing the risk assessment, required when one is supplied
+    #[account(
+        constraint = roles.has_role(&oracle_authority.key(), Role::OracleOperator) @ InsuranceError::MissingRole
+    )]
+    pub oracle_authority: Option<Signer<'info>>,
+    
+    /// LIVES account the premium will be paid from; supplying it quotes the premium in LIVES
+    #[account(
+        constraint = lives_token_account.owner == insured.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = lives_token_account.mint == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub lives_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Insured's LIVES stake, which picks the discount tier when paying with LIVES
+    #[account(
+        seeds = [b"lives_stake", insurance_pool.key().as_ref(), insured.key().as_ref()],
+        bump = lives_stake.bump
+    )]
+    pub lives_stake: Option<Account<'info, LivesStake>>,
+    
+    /// Pyth LIVES/USD price update, required when paying with LIVES
+    pub lives_price_update: Option<Account<'info, PriceUpdateV2>>,
+    
+    /// Pyth price update for a settlement asset that is not a USD stablecoin
+    pub settlement_price_update: Option<Account<'info, PriceUpdateV2>>,
+}
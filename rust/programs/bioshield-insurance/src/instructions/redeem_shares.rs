@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use anchor_spl::token::spl_token::native_mint;
+use crate::errors::InsuranceError;
+use crate::state::InsurancePool;
+
+/// Burn SHIELD or tranche shares for their pro-rata slice of a redeeming pool's vaults
+#[derive(Accounts)]
+pub struct RedeemShares<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        constraint = insurance_pool.is_share_mint(&share_mint.key()) @ InsuranceError::InvalidTokenMint
+    )]
+    pub share_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = provider_share_account.owner == liquidity_provider.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = provider_share_account.mint == share_mint.key() @ InsuranceError::InvalidTokenMint
+    )]
+    pub provider_share_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.settlement_mint.as_ref()],
+        bump
+    )]
+    pub settlement_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = provider_settlement_account.owner == liquidity_provider.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = provider_settlement_account.mint == insurance_pool.settlement_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub provider_settlement_account: Account<'info, TokenAccount>,
+    
+    /// Pool's LIVES vault, required once it holds anything
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), insurance_pool.lives_token_mint.as_ref()],
+        bump
+    )]
+    pub lives_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Provider's LIVES account receiving their slice of the LIVES vault
+    #[account(
+        mut,
+        constraint = provider_lives_account.owner == liquidity_provider.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = provider_lives_account.mint == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub provider_lives_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Pool's wrapped SOL vault, when it is not already the settlement vault
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), native_mint::ID.as_ref()],
+        bump,
+        constraint = insurance_pool.settlement_mint != native_mint::ID @ InsuranceError::InvalidTokenMint
+    )]
+    pub sol_vault: Option<Account<'info, TokenAccount>>,
+    
+    /// Provider's wrapped SOL account receiving their slice of the SOL vault
+    #[account(
+        mut,
+        constraint = provider_sol_account.owner == liquidity_provider.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = provider_sol_account.mint == native_mint::ID @ InsuranceError::InvalidTokenMint
+    )]
+    pub provider_sol_account: Option<Account<'info, TokenAccount>>,
+    
+    pub liquidity_provider: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsurancePool, StakePosition};
+
+/// Permissionless crank that drops a position back to 1x once its lock has ended
+#[derive(Accounts)]
+pub struct RefreshStakeBoost<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        seeds = [b"stake_position", insurance_pool.key().as_ref(), stake_position.owner.as_ref()],
+        bump = stake_position.bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsurancePool, PoolRegistry};
+
+/// Permissionless: any pool may be listed on any registry page with room
+#[derive(Accounts)]
+pub struct RegisterPool<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        seeds = [b"pool_registry", &pool_registry.page.to_le_bytes()],
+        bump = pool_registry.bump
+    )]
+    pub pool_registry: Account<'info, PoolRegistry>,
+}
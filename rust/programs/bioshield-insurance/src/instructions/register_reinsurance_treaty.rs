@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, PoolStatus, ReinsuranceTreaty, PoolRoles, Role};
+
+/// Admins of both pools sign: the cedent to give up premium, the reinsurer to take on losses
+#[derive(Accounts)]
+pub struct RegisterReinsuranceTreaty<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump,
+        constraint = insurance_pool.reinsurance_treaty.is_none()
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"roles", insurance_pool.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.has_role(&authority.key(), Role::Admin) @ InsuranceError::MissingRole
+    )]
+    pub roles: Account<'info, PoolRoles>,
+    
+    #[account(
+        seeds = [b"insurance_pool", reinsurer_pool.creator.as_ref(), &reinsurer_pool.pool_id_seed()[..]],
+        bump = reinsurer_pool.bump,
+        constraint = reinsurer_pool.key() != insurance_pool.key(),
+        constraint = reinsurer_pool.settlement_mint == insurance_pool.settlement_mint,
+        constraint = reinsurer_pool.status() == PoolStatus::Active @ InsuranceError::ReinsurerNotActive
+    )]
+    pub reinsurer_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"roles", reinsurer_pool.key().as_ref()],
+        bump = reinsurer_roles.bump,
+        constraint = reinsurer_roles.has_role(&reinsurer_authority.key(), Role::Admin) @ InsuranceError::MissingRole
+    )]
+    pub reinsurer_roles: Account<'info, PoolRoles>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = ReinsuranceTreaty::SIZE,
+        seeds = [b"treaty", insurance_pool.key().as_ref(), reinsurer_pool.key().as_ref()],
+        bump
+    )]
+    pub reinsurance_treaty: Account<'info, ReinsuranceTreaty>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub reinsurer_authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsurancePool, Governance, Proposal};
+
+/// Permissionless once voting has ended
+#[derive(Accounts)]
+pub struct RejectProposal<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        seeds = [b"governance", insurance_pool.key().as_ref()],
+        bump = governance.bump,
+        constraint = insurance_pool.governance == Some(governance.key())
+    )]
+    pub governance: Account<'info, Governance>,
+    
+    #[account(
+        mut,
+        seeds = [b"proposal", governance.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Mint};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, WithdrawalRequest};
+
+#[derive(Accounts)]
+pub struct RequestWithdrawal<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        init,
+        payer = liquidity_provider,
+        space = WithdrawalRequest::SIZE,
+        seeds = [
+            b"withdrawal",
+            insurance_pool.key().as_ref(),
+            &insurance_pool.withdrawal_queue.tail.to_le_bytes()
+        ],
+        bump
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+    
+    #[account(
+        init,
+        payer = liquidity_provider,
+        seeds = [b"withdrawal_escrow", withdrawal_request.key().as_ref()],
+        bump,
+        token::mint = shield_token_mint,
+        token::authority = insurance_pool
+    )]
+    pub shield_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        constraint = insurance_pool.is_share_mint(&shield_token_mint.key()) @ InsuranceError::InvalidTokenMint
+    )]
+    pub shield_token_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = provider_shield_account.owner == liquidity_provider.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = provider_shield_account.mint == shield_token_mint.key() @ InsuranceError::InvalidTokenMint
+    )]
+    pub provider_shield_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub liquidity_provider: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
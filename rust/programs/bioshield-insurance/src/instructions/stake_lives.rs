@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, LivesStake};
+
+/// Shared by `stake_lives` and `unstake_lives`
+#[derive(Accounts)]
+pub struct StakeLives<'info> {
+    #[account(
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        mut,
+        seeds = [b"lives_stake", insurance_pool.key().as_ref(), owner.key().as_ref()],
+        bump = lives_stake.bump
+    )]
+    pub lives_stake: Account<'info, LivesStake>,
+    
+    #[account(
+        mut,
+        seeds = [b"lives_stake_escrow", lives_stake.key().as_ref()],
+        bump
+    )]
+    pub lives_stake_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = owner_lives_account.owner == owner.key() @ InsuranceError::InvalidTokenOwner,
+        constraint = owner_lives_account.mint == insurance_pool.lives_token_mint @ InsuranceError::InvalidTokenMint
+    )]
+    pub owner_lives_account: Account<'info, TokenAccount>,
+    
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
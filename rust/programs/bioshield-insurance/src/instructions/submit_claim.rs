@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{CoverageAccount, ClaimAccount, ClaimData};
+use crate::state::{InsurancePool, CoverageAccount, ClaimAccount, ClaimData};
 
 #[derive(Accounts)]
 #[instruction(claim_data: ClaimData)]
@@ -19,15 +19,16 @@ pub struct SubmitClaim<'info> {
     )]
     pub coverage_account: Account<'info, CoverageAccount>,
     
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump,
+        constraint = coverage_account.pool == insurance_pool.key()
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
     #[account(mut)]
     pub claimant: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct ClaimData {
-    pub amount: u64,
-    pub claim_type: crate::state::claim::ClaimType,
-    pub evidence_hash: [u8; 32],
-}
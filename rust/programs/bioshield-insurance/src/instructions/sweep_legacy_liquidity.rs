@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, LEGACY_POOL_ID};
+
+/// Permissionless: moves liquidity a migrated pool still holds in its single-pool era
+/// token account into the vault PDA for that mint
+#[derive(Accounts)]
+pub struct SweepLegacyLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump,
+        constraint = insurance_pool.pool_id == LEGACY_POOL_ID @ InsuranceError::InvalidPoolId
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    /// Pool-owned account LPs deposited into before vaults existed
+    #[account(
+        mut,
+        token::authority = insurance_pool,
+        constraint = legacy_token_account.key() != vault.key()
+    )]
+    pub legacy_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), legacy_token_account.mint.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
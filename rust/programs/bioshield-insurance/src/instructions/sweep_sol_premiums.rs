@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token::spl_token::native_mint;
+use crate::state::InsurancePool;
+
+#[derive(Accounts)]
+pub struct SweepSolPremiums<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    /// Pool's wrapped SOL vault, which is its settlement vault in wrapped SOL pools
+    #[account(
+        mut,
+        seeds = [b"vault", insurance_pool.key().as_ref(), native_mint::ID.as_ref()],
+        bump
+    )]
+    pub sol_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
@@ -5,7 +5,7 @@ use crate::state::InsurancePool;
 pub struct UpdateOracle<'info> {
     #[account(
         mut,
-        seeds = [b"insurance_pool", insurance_pool.authority.as_ref()],
+        seeds = [b"insurance_pool", insurance_pool.creator.as_ref(), &insurance_pool.pool_id_seed()[..]],
         bump = insurance_pool.bump,
         constraint = insurance_pool.authority == authority.key()
     )]
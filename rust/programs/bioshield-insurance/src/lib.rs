@@ -1,5 +1,8 @@
+// Program code surfaces failures as InsuranceError variants rather than aborting
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
+
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint, MintTo};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint, MintTo, SyncNative, Burn, CloseAccount};
 use anchor_spl::associated_token::AssociatedToken;
 use solana_program::clock::Clock;
 use std::mem::size_of;
@@ -27,20 +30,93 @@ pub mod bioshield_insurance {
     ) -> Result<()> {
         let pool = &mut ctx.accounts.insurance_pool;
         let clock = Clock::get()?;
+
+        require!(
+            pool_params.marketplace_fee_basis_points <= MAX_MARKETPLACE_FEE_BASIS_POINTS,
+            InsuranceError::InvalidFee
+        );
+        utils::validations::validate_capacity_config(
+            &pool_params.pricing_curve,
+            pool_params.max_utilization_bps,
+        )?;
+        utils::validations::validate_lives_discount_schedule(&pool_params.lives_discount)?;
+        utils::validations::validate_withdrawal_config(&pool_params.withdrawal_config)?;
+        utils::validations::validate_staking_config(&pool_params.staking_config)?;
+        utils::validations::validate_coverage_types(pool_params.coverage_types)?;
+        utils::validations::validate_timelock_config(
+            &pool_params.timelock,
+            &pool_params.withdrawal_config,
+        )?;
+        require!(
+            pool_params.guardian != Pubkey::default(),
+            InsuranceError::InvalidRoleAssignment
+        );
+        // Reserved for pools migrated from the single-pool layout, which are seeded without an ID
+        require!(pool_params.pool_id != LEGACY_POOL_ID, InsuranceError::InvalidPoolId);
         
         pool.authority = ctx.accounts.authority.key();
+        pool.pending_authority = None;
+        pool.creator = ctx.accounts.authority.key();
+        pool.pool_id = pool_params.pool_id;
+        pool.coverage_types = pool_params.coverage_types;
         pool.lives_token_mint = ctx.accounts.lives_token_mint.key();
         pool.shield_token_mint = ctx.accounts.shield_token_mint.key();
+        pool.tranches = Vec::new();
+        pool.settlement_mint = ctx.accounts.settlement_mint.key();
+        pool.settlement_decimals = ctx.accounts.settlement_mint.decimals;
+        pool.lives_decimals = ctx.accounts.lives_token_mint.decimals;
+        pool.settlement_price_feed_id = pool_params.settlement_price_feed_id;
+        pool.lives_price_feed_id = pool_params.lives_price_feed_id;
+        pool.lives_discount = pool_params.lives_discount;
+        pool.asset_ledgers = Vec::new();
         pool.total_value_locked = 0;
         pool.total_coverage_amount = 0;
         pool.total_claims_paid = 0;
+        pool.reserved_claims = 0;
+        pool.unearned_premiums = 0;
+        pool.premium_rewards = PremiumRewards::default();
+        pool.staking_config = pool_params.staking_config;
+        pool.lives_emissions = LivesEmissions {
+            last_update: clock.unix_timestamp,
+            ..Default::default()
+        };
+        pool.reinsurance_treaty = None;
+        pool.ceded_premiums = 0;
+        pool.reinsurance_recoveries = 0;
+        pool.governance = None;
+        pool.timelock = pool_params.timelock;
+        pool.timelock_operation_count = 0;
         pool.pool_fee_basis_points = pool_params.fee_basis_points;
         pool.min_coverage_amount = pool_params.min_coverage_amount;
         pool.max_coverage_amount = pool_params.max_coverage_amount;
+        pool.check_coverage_limits(pool.min_coverage_amount, pool.max_coverage_amount)?;
         pool.oracle_address = pool_params.oracle_address;
+        pool.treasury = pool_params.treasury;
+        pool.marketplace_fee_basis_points = pool_params.marketplace_fee_basis_points;
+        pool.pricing_curve = pool_params.pricing_curve;
+        pool.max_utilization_bps = pool_params.max_utilization_bps;
+        pool.min_premium = pool_params.min_premium;
+        pool.withdrawal_config = pool_params.withdrawal_config;
+        pool.withdrawal_queue = WithdrawalQueue::default();
         pool.created_at = clock.unix_timestamp;
         pool.is_paused = false;
         pool.bump = ctx.bumps.insurance_pool;
+        pool.version = POOL_ACCOUNT_VERSION;
+        pool.wind_down = WindDown {
+            open_coverages: Some(0),
+            ..Default::default()
+        };
+        pool.assumed_coverage = 0;
+        pool.treaty_terminated = false;
+        pool.reserved = [0; POOL_RESERVED_BYTES];
+
+        // The creator administers the pool; pausing starts with the named guardian
+        let roles = &mut ctx.accounts.roles;
+        roles.pool = pool.key();
+        roles.members = Vec::new();
+        roles.bump = ctx.bumps.roles;
+        roles.grant(pool.authority, Role::Admin)?;
+        roles.grant(pool_params.guardian, Role::Guardian)?;
 
         emit!(PoolInitialized {
             pool: pool.key(),
@@ -51,86 +127,312 @@ pub mod bioshield_insurance {
         Ok(())
     }
 
-    /// Create a new insurance coverage
+    /// Open a page of the pool registry
+    pub fn initialize_registry(ctx: Context<InitializeRegistry>, page: u32) -> Result<()> {
+        let registry = &mut ctx.accounts.pool_registry;
+
+        registry.page = page;
+        registry.pools = Vec::new();
+        registry.bump = ctx.bumps.pool_registry;
+
+        Ok(())
+    }
+
+    /// List a pool on a registry page with room, so clients can discover it on-chain
+    pub fn register_pool(ctx: Context<RegisterPool>) -> Result<()> {
+        let pool = &ctx.accounts.insurance_pool;
+        let registry = &mut ctx.accounts.pool_registry;
+
+        registry.register(PoolEntry {
+            pool: pool.key(),
+            authority: pool.authority,
+            pool_id: pool.pool_id,
+            coverage_types: pool.coverage_types,
+            status: pool.status(),
+        })?;
+
+        emit!(PoolRegistered {
+            pool: pool.key(),
+            authority: pool.authority,
+            pool_id: pool.pool_id,
+            page: registry.page,
+            coverage_types: pool.coverage_types,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Refresh a pool's registry entry from its current state
+    pub fn sync_registry_entry(ctx: Context<SyncRegistryEntry>) -> Result<()> {
+        let pool = &ctx.accounts.insurance_pool;
+        let entry = ctx.accounts.pool_registry.entry_mut(&pool.key())?;
+
+        entry.authority = pool.authority;
+        entry.coverage_types = pool.coverage_types;
+        entry.status = pool.status();
+
+        emit!(RegistryEntrySynced {
+            pool: pool.key(),
+            coverage_types: entry.coverage_types,
+            status: entry.status.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Create a new insurance coverage, priced as `quote_premium` prices it and charged at
+    /// no more than `max_premium`
     pub fn create_coverage(
         ctx: Context<CreateCoverage>,
         coverage_params: CoverageParams,
+        risk_assessment: Option<oracles::RiskAssessment>,
     ) -> Result<()> {
         let coverage = &mut ctx.accounts.coverage_account;
         let pool = &ctx.accounts.insurance_pool;
         let clock = Clock::get()?;
 
-        // Validate coverage parameters
-        require!(
-            coverage_params.coverage_amount >= pool.min_coverage_amount &&
-            coverage_params.coverage_amount <= pool.max_coverage_amount,
-            InsuranceError::InvalidCoverageAmount
-        );
+        pool.require_allows(PoolOperation::WriteCoverage)?;
+        utils::validations::validate_coverage_type_offered(pool, &coverage_params.coverage_type)?;
+        utils::validations::validate_coverage_category(&coverage_params.coverage_type)?;
+        utils::validations::validate_trigger_conditions(&coverage_params.trigger_conditions)?;
+        utils::validations::validate_metadata_uri(&coverage_params.metadata_uri)?;
+
+        // Price the policy's specific risk from oracle-attested inputs
+        let pricing_inputs = utils::calculations::resolve_pricing_inputs(
+            risk_assessment.as_ref(),
+            ctx.accounts.oracle_authority.is_some(),
+            &coverage_params.coverage_type,
+            &coverage_params.risk_category,
+            clock.unix_timestamp,
+        )?;
+
+        // Paying from a LIVES account earns the discount tier of the insured's staked LIVES,
+        // weighted by how long it has been staked
+        let lives_balance = ctx.accounts.lives_token_account.as_ref().map(|_| {
+            ctx.accounts.lives_stake
+                .as_ref()
+                .map(|stake| stake.discount_balance(clock.unix_timestamp))
+                .unwrap_or(0)
+        });
 
-        // Calculate premium with LIVES token discount
-        let base_premium = utils::calculations::calculate_premium(
+        // Calculate premium with utilization loading, pool fee and LIVES token discount
+        let mut quote = utils::calculations::quote_premium(
+            pool,
             coverage_params.coverage_amount,
             coverage_params.coverage_period,
-            coverage_params.risk_category,
+            &coverage_params.risk_category,
+            &pricing_inputs,
+            lives_balance,
+            clock.unix_timestamp,
         )?;
 
-        let final_premium = if ctx.accounts.lives_token_account.is_some() {
-            // 50% discount when paying with LIVES token
-            base_premium.checked_div(2).unwrap()
-        } else {
-            base_premium
-        };
+        // LIVES premiums are converted from the settlement value at the oracle price
+        if ctx.accounts.lives_token_account.is_some() {
+            quote.amount_due = oracles::convert_premium_to_lives(
+                pool,
+                quote.final_premium,
+                ctx.accounts.lives_price_update.as_deref(),
+                ctx.accounts.settlement_price_update.as_deref(),
+            )?;
+        }
+        let final_premium = quote.amount_within(coverage_params.max_premium)?;
+
+        // Transfer premium into the pool vault of the asset it is paid in
+        let mut lives_burned = 0;
+        let premium_mint = if let Some(lives_account) = &ctx.accounts.lives_token_account {
+            let lives_vault = ctx.accounts.lives_vault
+                .as_ref()
+                .ok_or(InsuranceError::VaultNotInitialized)?;
 
-        // Transfer premium to pool
-        if let Some(lives_account) = &ctx.accounts.lives_token_account {
             // Transfer LIVES tokens
             let cpi_accounts = Transfer {
                 from: lives_account.to_account_info(),
-                to: ctx.accounts.pool_lives_account.to_account_info(),
+                to: lives_vault.to_account_info(),
+                authority: ctx.accounts.insured.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            token::transfer(cpi_ctx, final_premium)?;
+
+            // Burn the configured share of LIVES premiums
+            lives_burned = final_premium
+                .checked_mul(pool.lives_discount.burn_share_bps as u64)
+                .ok_or(InsuranceError::PremiumCalculationOverflow)?
+                .checked_div(BASIS_POINTS as u64)
+                .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+            if lives_burned > 0 {
+                let lives_token_mint = ctx.accounts.lives_token_mint
+                    .as_ref()
+                    .ok_or(InsuranceError::InvalidTokenMint)?;
+
+                let pool_seeds = &[
+                    b"insurance_pool",
+                    pool.creator.as_ref(),
+                    &pool.pool_id_seed()[..],
+                    &[pool.bump],
+                ];
+                let signer_seeds = &[&pool_seeds[..]];
+
+                let cpi_accounts = Burn {
+                    mint: lives_token_mint.to_account_info(),
+                    from: lives_vault.to_account_info(),
+                    authority: pool.to_account_info(),
+                };
+                let cpi_program = ctx.accounts.token_program.to_account_info();
+                let cpi_ctx = CpiContext::new_with_signer(
+                    cpi_program,
+                    cpi_accounts,
+                    signer_seeds
+                );
+                token::burn(cpi_ctx, lives_burned)?;
+            }
+
+            pool.lives_token_mint
+        } else if let Some(insured_settlement_account) = &ctx.accounts.insured_settlement_account {
+            // Transfer settlement tokens
+            let cpi_accounts = Transfer {
+                from: insured_settlement_account.to_account_info(),
+                to: ctx.accounts.settlement_vault.to_account_info(),
                 authority: ctx.accounts.insured.to_account_info(),
             };
             let cpi_program = ctx.accounts.token_program.to_account_info();
             let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
             token::transfer(cpi_ctx, final_premium)?;
+
+            pool.settlement_mint
         } else {
-            // Transfer SOL
+            // Native SOL is only accepted by wrapped SOL pools, so it can pay claims
+            require!(
+                pool.settlement_mint == anchor_spl::token::spl_token::native_mint::ID,
+                InsuranceError::MissingTokenAccount
+            );
+
+            // Transfer SOL into the wrapped SOL vault and sync its token balance
             let ix = anchor_lang::solana_program::system_instruction::transfer(
                 &ctx.accounts.insured.key(),
-                &pool.key(),
+                &ctx.accounts.settlement_vault.key(),
                 final_premium,
             );
             anchor_lang::solana_program::program::invoke(
                 &ix,
                 &[
                     ctx.accounts.insured.to_account_info(),
-                    ctx.accounts.insurance_pool.to_account_info(),
+                    ctx.accounts.settlement_vault.to_account_info(),
                 ],
             )?;
-        }
+
+            let cpi_accounts = SyncNative {
+                account: ctx.accounts.settlement_vault.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::sync_native(CpiContext::new(cpi_program, cpi_accounts))?;
+
+            pool.settlement_mint
+        };
 
         // Initialize coverage account
         coverage.insured = ctx.accounts.insured.key();
         coverage.pool = pool.key();
         coverage.coverage_amount = coverage_params.coverage_amount;
         coverage.premium_paid = final_premium;
+        coverage.premium_earned = 0;
+        coverage.premium_mint = premium_mint;
         coverage.coverage_type = coverage_params.coverage_type;
         coverage.trigger_conditions = coverage_params.trigger_conditions;
         coverage.start_time = clock.unix_timestamp;
-        coverage.end_time = clock.unix_timestamp + coverage_params.coverage_period as i64;
+        coverage.end_time = clock.unix_timestamp
+            .checked_add(coverage_params.coverage_period as i64)
+            .ok_or(InsuranceError::InvalidCoveragePeriod)?;
         coverage.status = CoverageStatus::Active;
         coverage.claims_made = 0;
+        coverage.pending_claims = 0;
         coverage.total_claimed = 0;
         coverage.metadata_uri = coverage_params.metadata_uri;
+        coverage.pricing_inputs = pricing_inputs;
+        coverage.pricing_version = PRICING_VERSION_PER_SECOND;
         coverage.bump = ctx.bumps.coverage_account;
+        coverage.version = COVERAGE_ACCOUNT_VERSION;
+        coverage.ceded_coverage = 0;
+        coverage.reserved = [0; COVERAGE_RESERVED_BYTES];
 
-        // Update pool statistics
+        // Update pool statistics, net of the exposure ceded to a reinsurer
         let pool = &mut ctx.accounts.insurance_pool;
+        utils::validations::validate_reinsurance_accounts(
+            pool,
+            ctx.accounts.reinsurance_treaty.as_ref().map(|treaty| treaty.key()),
+            ctx.accounts.reinsurance_treaty.as_deref(),
+            ctx.accounts.reinsurer_pool.as_ref().map(|reinsurer| reinsurer.key()),
+        )?;
+
+        let mut ceded_coverage = 0;
+        if let (Some(treaty), Some(reinsurer)) = (
+            ctx.accounts.reinsurance_treaty.as_mut(),
+            ctx.accounts.reinsurer_pool.as_mut(),
+        ) {
+            // Once the treaty is terminated or the reinsurer stops writing, the pool retains
+            // the whole policy rather than turning the insured away
+            if pool.cedes_new_policies(reinsurer) {
+                ceded_coverage = treaty.ceded_coverage_for(coverage_params.coverage_amount)?;
+                coverage.ceded_coverage = ceded_coverage;
+                treaty.ceded_coverage = treaty.ceded_coverage
+                    .checked_add(ceded_coverage)
+                    .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+                reinsurer.assume_coverage(ceded_coverage)?;
+
+                emit!(CoverageCeded {
+                    treaty: treaty.key(),
+                    coverage: coverage.key(),
+                    ceded_coverage,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+        }
+
         pool.total_coverage_amount = pool.total_coverage_amount
-            .checked_add(coverage_params.coverage_amount)
-            .unwrap();
-        pool.total_value_locked = pool.total_value_locked
-            .checked_add(final_premium)
-            .unwrap();
+            .checked_add(
+                coverage_params.coverage_amount
+                    .checked_sub(ceded_coverage)
+                    .ok_or(InsuranceError::InvalidTreatyTerms)?
+            )
+            .ok_or(InsuranceError::InvalidCoverageAmount)?;
+        pool.track_coverage(coverage.end_time)?;
+        if premium_mint == pool.settlement_mint {
+            pool.total_value_locked = pool.total_value_locked
+                .checked_add(final_premium)
+                .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+            // Held back from LPs until it is earned over the coverage period
+            pool.unearned_premiums = pool.unearned_premiums
+                .checked_add(final_premium)
+                .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+        }
+        let ledger = pool.ledger_mut(&premium_mint)?;
+        ledger.premiums_collected = ledger.premiums_collected
+            .checked_add(
+                final_premium
+                    .checked_sub(lives_burned)
+                    .ok_or(InsuranceError::PremiumCalculationOverflow)?
+            )
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+        emit!(PremiumPaid {
+            coverage: coverage.key(),
+            insured: coverage.insured,
+            amount: final_premium,
+            token: premium_mint,
+            timestamp: clock.unix_timestamp,
+        });
+
+        if lives_burned > 0 {
+            emit!(LivesPremiumBurned {
+                pool: pool.key(),
+                coverage: coverage.key(),
+                amount: lives_burned,
+                timestamp: clock.unix_timestamp,
+            });
+        }
 
         emit!(CoverageCreated {
             coverage: coverage.key(),
@@ -144,15 +446,76 @@ pub mod bioshield_insurance {
         Ok(())
     }
 
+    /// Quote a coverage premium without writing any state; the breakdown is returned
+    /// through return data
+    pub fn quote_premium(
+        ctx: Context<QuotePremium>,
+        coverage_params: CoverageParams,
+        risk_assessment: Option<oracles::RiskAssessment>,
+    ) -> Result<()> {
+        let pool = &ctx.accounts.insurance_pool;
+        let clock = Clock::get()?;
+
+        pool.require_allows(PoolOperation::WriteCoverage)?;
+        utils::validations::validate_coverage_type_offered(pool, &coverage_params.coverage_type)?;
+        utils::validations::validate_coverage_category(&coverage_params.coverage_type)?;
+
+        // Price the policy's specific risk from oracle-attested inputs
+        let pricing_inputs = utils::calculations::resolve_pricing_inputs(
+            risk_assessment.as_ref(),
+            ctx.accounts.oracle_authority.is_some(),
+            &coverage_params.coverage_type,
+            &coverage_params.risk_category,
+            clock.unix_timestamp,
+        )?;
+
+        // Paying from a LIVES account earns the discount tier of the insured's staked LIVES,
+        // weighted by how long it has been staked
+        let lives_balance = ctx.accounts.lives_token_account.as_ref().map(|_| {
+            ctx.accounts.lives_stake
+                .as_ref()
+                .map(|stake| stake.discount_balance(clock.unix_timestamp))
+                .unwrap_or(0)
+        });
+
+        // Calculate premium with utilization loading, pool fee and LIVES token discount
+        let mut quote = utils::calculations::quote_premium(
+            pool,
+            coverage_params.coverage_amount,
+            coverage_params.coverage_period,
+            &coverage_params.risk_category,
+            &pricing_inputs,
+            lives_balance,
+            clock.unix_timestamp,
+        )?;
+
+        // LIVES premiums are converted from the settlement value at the oracle price
+        if ctx.accounts.lives_token_account.is_some() {
+            quote.premium_mint = pool.lives_token_mint;
+            quote.amount_due = oracles::convert_premium_to_lives(
+                pool,
+                quote.final_premium,
+                ctx.accounts.lives_price_update.as_deref(),
+                ctx.accounts.settlement_price_update.as_deref(),
+            )?;
+        }
+
+        anchor_lang::solana_program::program::set_return_data(&quote.try_to_vec()?);
+
+        Ok(())
+    }
+
     /// Submit a claim for coverage
     pub fn submit_claim(
         ctx: Context<SubmitClaim>,
         claim_data: ClaimData,
     ) -> Result<()> {
         let claim = &mut ctx.accounts.claim_account;
-        let coverage = &ctx.accounts.coverage_account;
+        let coverage = &mut ctx.accounts.coverage_account;
         let clock = Clock::get()?;
 
+        ctx.accounts.insurance_pool.require_allows(PoolOperation::SubmitClaim)?;
+
         // Validate coverage is active
         require!(
             coverage.status == CoverageStatus::Active,
@@ -177,6 +540,23 @@ pub mod bioshield_insurance {
         claim.processor = None;
         claim.rejection_reason = None;
         claim.bump = ctx.bumps.claim_account;
+        claim.version = CLAIM_ACCOUNT_VERSION;
+        claim.reserved = [0; ACCOUNT_RESERVED_BYTES];
+
+        // Reserve the potential payout so LPs cannot redeem it before the claim settles
+        let remaining_coverage = coverage.coverage_amount
+            .checked_sub(coverage.total_claimed)
+            .ok_or(InsuranceError::ClaimAmountExceedsCoverage)?;
+        claim.reserved_amount = std::cmp::min(claim_data.amount, remaining_coverage);
+
+        let pool = &mut ctx.accounts.insurance_pool;
+        pool.reserved_claims = pool.reserved_claims
+            .checked_add(claim.reserved_amount)
+            .ok_or(InsuranceError::PayoutCalculationError)?;
+
+        coverage.pending_claims = coverage.pending_claims
+            .checked_add(1)
+            .ok_or(InsuranceError::ClaimProcessingFailed)?;
 
         emit!(ClaimSubmitted {
             claim: claim.key(),
@@ -199,6 +579,8 @@ pub mod bioshield_insurance {
         let pool = &mut ctx.accounts.insurance_pool;
         let clock = Clock::get()?;
 
+        pool.require_allows(PoolOperation::ProcessClaim)?;
+
         // Verify oracle consensus (require at least 2 data sources)
         require!(
             oracle_data.has_consensus(2),
@@ -210,25 +592,105 @@ pub mod bioshield_insurance {
             &coverage.trigger_conditions
         );
 
+        // The claim is settled either way, so its reserve is released
+        pool.reserved_claims = pool.reserved_claims.saturating_sub(claim.reserved_amount);
+
         if should_payout {
             // Calculate payout amount
             let payout_amount = std::cmp::min(
                 claim.claim_amount,
-                coverage.coverage_amount - coverage.total_claimed
+                coverage.coverage_amount
+                    .checked_sub(coverage.total_claimed)
+                    .ok_or(InsuranceError::PayoutCalculationError)?
             );
 
-            // Transfer payout from pool to claimant
+            // Recover the reinsured share of the loss first, so it can go towards the payout
+            utils::validations::validate_reinsurance_accounts(
+                pool,
+                ctx.accounts.reinsurance_treaty.as_ref().map(|treaty| treaty.key()),
+                ctx.accounts.reinsurance_treaty.as_deref(),
+                ctx.accounts.reinsurer_pool.as_ref().map(|reinsurer| reinsurer.key()),
+            )?;
+            let mut recovery = 0;
+            if let (Some(treaty), Some(reinsurer)) = (
+                ctx.accounts.reinsurance_treaty.as_mut(),
+                ctx.accounts.reinsurer_pool.as_mut(),
+            ) {
+                recovery = treaty.recovery_for(payout_amount, coverage.ceded_coverage)?;
+                if recovery > 0 {
+                    let reinsurer_vault = ctx.accounts.reinsurer_settlement_vault
+                        .as_ref()
+                        .ok_or(InsuranceError::VaultNotInitialized)?;
+                    require!(
+                        Some(reinsurer_vault.key()) == reinsurer.vault_for(&reinsurer.settlement_mint),
+                        InsuranceError::ReinsuranceAccountsMismatch
+                    );
+
+                    // Both pools are PDAs of this program, so it signs for the reinsurer's vault
+                    let reinsurer_seeds = &[
+                        b"insurance_pool",
+                        reinsurer.creator.as_ref(),
+                        &reinsurer.pool_id_seed()[..],
+                        &[reinsurer.bump],
+                    ];
+                    let reinsurer_signer_seeds = &[&reinsurer_seeds[..]];
+
+                    let cpi_accounts = Transfer {
+                        from: reinsurer_vault.to_account_info(),
+                        to: ctx.accounts.settlement_vault.to_account_info(),
+                        authority: reinsurer.to_account_info(),
+                    };
+                    let cpi_program = ctx.accounts.token_program.to_account_info();
+                    let cpi_ctx = CpiContext::new_with_signer(
+                        cpi_program,
+                        cpi_accounts,
+                        reinsurer_signer_seeds
+                    );
+                    token::transfer(cpi_ctx, recovery)?;
+
+                    treaty.recoveries = treaty.recoveries
+                        .checked_add(recovery)
+                        .ok_or(InsuranceError::PayoutCalculationError)?;
+                    reinsurer.total_claims_paid = reinsurer.total_claims_paid
+                        .checked_add(recovery)
+                        .ok_or(InsuranceError::PayoutCalculationError)?;
+                    reinsurer.total_value_locked = reinsurer.total_value_locked.saturating_sub(recovery);
+                    if reinsurer.has_tranches() {
+                        reinsurer.absorb_loss(recovery)?;
+                    }
+                    // The reinsurer has paid that much of its share of the policy
+                    treaty.release_ceded_coverage(reinsurer, recovery);
+                    let reinsurer_mint = reinsurer.settlement_mint;
+                    let ledger = reinsurer.ledger_mut(&reinsurer_mint)?;
+                    ledger.claims_paid = ledger.claims_paid
+                        .checked_add(recovery)
+                        .ok_or(InsuranceError::PayoutCalculationError)?;
+
+                    emit!(ReinsuranceRecovered {
+                        treaty: treaty.key(),
+                        claim: claim.key(),
+                        amount: recovery,
+                        timestamp: clock.unix_timestamp,
+                    });
+                }
+            }
+
             let pool_seeds = &[
                 b"insurance_pool",
-                pool.authority.as_ref(),
+                pool.creator.as_ref(),
+                &pool.pool_id_seed()[..],
                 &[pool.bump],
             ];
             let signer_seeds = &[&pool_seeds[..]];
 
-            if coverage.premium_paid > 0 {
-                // Transfer tokens
+            // Claims are paid in the pool's settlement asset as far as its vault allows
+            let settlement_paid = std::cmp::min(
+                payout_amount,
+                ctx.accounts.settlement_vault.amount.saturating_add(recovery),
+            );
+            if settlement_paid > 0 {
                 let cpi_accounts = Transfer {
-                    from: ctx.accounts.pool_token_account.to_account_info(),
+                    from: ctx.accounts.settlement_vault.to_account_info(),
                     to: ctx.accounts.claimant_token_account.to_account_info(),
                     authority: pool.to_account_info(),
                 };
@@ -238,8 +700,72 @@ pub mod bioshield_insurance {
                     cpi_accounts,
                     signer_seeds
                 );
-                token::transfer(cpi_ctx, payout_amount)?;
+                token::transfer(cpi_ctx, settlement_paid)?;
+            }
+
+            // Any shortfall is paid out of the LIVES and wrapped SOL vaults at oracle prices
+            let mut shortfall = payout_amount - settlement_paid;
+            let other_vaults = [
+                (
+                    ctx.accounts.lives_vault.as_ref(),
+                    ctx.accounts.claimant_lives_account.as_ref(),
+                    ctx.accounts.lives_price_update.as_deref(),
+                ),
+                (
+                    ctx.accounts.sol_vault.as_ref(),
+                    ctx.accounts.claimant_sol_account.as_ref(),
+                    ctx.accounts.sol_price_update.as_deref(),
+                ),
+            ];
+            for (vault, claimant_account, price_update) in other_vaults {
+                let (Some(vault), Some(claimant_account)) = (vault, claimant_account) else {
+                    continue;
+                };
+                if shortfall == 0 {
+                    break;
+                }
+
+                let owed = oracles::price_in_asset(
+                    pool,
+                    shortfall,
+                    &vault.mint,
+                    price_update,
+                    ctx.accounts.settlement_price_update.as_deref(),
+                )?;
+                let paid = std::cmp::min(owed, vault.amount);
+                if paid == 0 {
+                    continue;
+                }
+
+                let cpi_accounts = Transfer {
+                    from: vault.to_account_info(),
+                    to: claimant_account.to_account_info(),
+                    authority: pool.to_account_info(),
+                };
+                let cpi_program = ctx.accounts.token_program.to_account_info();
+                let cpi_ctx = CpiContext::new_with_signer(
+                    cpi_program,
+                    cpi_accounts,
+                    signer_seeds
+                );
+                token::transfer(cpi_ctx, paid)?;
+
+                let covered = utils::calculations::settlement_value_covered(shortfall, owed, paid)?;
+                shortfall -= covered;
+                let ledger = pool.ledger_mut(&vault.mint)?;
+                ledger.claims_paid = ledger.claims_paid
+                    .checked_add(paid)
+                    .ok_or(InsuranceError::PayoutCalculationError)?;
+
+                emit!(ClaimShortfallPaid {
+                    claim: claim.key(),
+                    mint: vault.mint,
+                    amount: paid,
+                    settlement_value: covered,
+                    timestamp: clock.unix_timestamp,
+                });
             }
+            require!(shortfall == 0, InsuranceError::InsufficientLiquidity);
 
             // Update claim status
             claim.status = ClaimStatus::Approved;
@@ -248,19 +774,73 @@ pub mod bioshield_insurance {
 
             // Update coverage statistics
             let coverage = &mut ctx.accounts.coverage_account;
-            coverage.claims_made += 1;
+            coverage.claims_made = coverage.claims_made
+                .checked_add(1)
+                .ok_or(InsuranceError::ClaimProcessingFailed)?;
+            coverage.pending_claims = coverage.pending_claims.saturating_sub(1);
             coverage.total_claimed = coverage.total_claimed
                 .checked_add(payout_amount)
-                .unwrap();
+                .ok_or(InsuranceError::PayoutCalculationError)?;
+            if recovery > 0 {
+                // What is left of the ceded share shrinks by the recovery, and the pool carries
+                // the policy's retained exposure, now that much larger, until it ends
+                coverage.ceded_coverage = coverage.ceded_coverage
+                    .checked_sub(recovery)
+                    .ok_or(InsuranceError::PayoutCalculationError)?;
+                pool.total_coverage_amount = pool.total_coverage_amount
+                    .checked_add(recovery)
+                    .ok_or(InsuranceError::PayoutCalculationError)?;
+            }
 
             if coverage.total_claimed >= coverage.coverage_amount {
                 coverage.status = CoverageStatus::Exhausted;
+                pool.release_coverage(coverage.retained_coverage());
+                if let (Some(treaty), Some(reinsurer)) = (
+                    ctx.accounts.reinsurance_treaty.as_mut(),
+                    ctx.accounts.reinsurer_pool.as_mut(),
+                ) {
+                    treaty.release_ceded_coverage(reinsurer, coverage.ceded_coverage);
+                    if pool.close_terminated_treaty(treaty) {
+                        emit!(ReinsuranceTreatyClosed {
+                            pool: pool.key(),
+                            treaty: treaty.key(),
+                            timestamp: clock.unix_timestamp,
+                        });
+                    }
+                }
             }
 
-            // Update pool statistics
+            // Update pool statistics with the loss retained after recoveries. Only the part
+            // paid from the settlement vault comes out of LP capital.
+            let retained_loss = payout_amount
+                .checked_sub(recovery)
+                .ok_or(InsuranceError::PayoutCalculationError)?;
+            let settlement_loss = settlement_paid
+                .checked_sub(recovery)
+                .ok_or(InsuranceError::PayoutCalculationError)?;
+            pool.reinsurance_recoveries = pool.reinsurance_recoveries
+                .checked_add(recovery)
+                .ok_or(InsuranceError::PayoutCalculationError)?;
             pool.total_claims_paid = pool.total_claims_paid
-                .checked_add(payout_amount)
-                .unwrap();
+                .checked_add(retained_loss)
+                .ok_or(InsuranceError::PayoutCalculationError)?;
+            pool.total_value_locked = pool.total_value_locked.saturating_sub(settlement_loss);
+            if pool.has_tranches() {
+                let allocation = pool.absorb_loss(settlement_loss)?;
+
+                emit!(ClaimLossAllocated {
+                    pool: pool.key(),
+                    claim: claim.key(),
+                    junior_loss: allocation.junior_loss,
+                    senior_loss: allocation.senior_loss,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            let settlement_mint = pool.settlement_mint;
+            let ledger = pool.ledger_mut(&settlement_mint)?;
+            ledger.claims_paid = ledger.claims_paid
+                .checked_add(settlement_loss)
+                .ok_or(InsuranceError::PayoutCalculationError)?;
 
             emit!(ClaimApproved {
                 claim: claim.key(),
@@ -272,7 +852,10 @@ pub mod bioshield_insurance {
             claim.status = ClaimStatus::Rejected;
             claim.processed_at = Some(clock.unix_timestamp);
             claim.processor = Some(ctx.accounts.processor.key());
-            claim.rejection_reason = Some("Oracle verification failed".to_string());
+            claim.rejection_reason = Some(ORACLE_REJECTION_REASON.to_string());
+
+            let coverage = &mut ctx.accounts.coverage_account;
+            coverage.pending_claims = coverage.pending_claims.saturating_sub(1);
 
             emit!(ClaimRejected {
                 claim: claim.key(),
@@ -292,10 +875,26 @@ pub mod bioshield_insurance {
         let pool = &mut ctx.accounts.insurance_pool;
         let clock = Clock::get()?;
 
-        // Transfer tokens to pool
+        pool.require_allows(PoolOperation::AddLiquidity)?;
+
+        // Tranched pools take liquidity per tranche
+        require!(!pool.has_tranches(), InsuranceError::InvalidTrancheConfig);
+
+        // Price the deposit against the assets already in the vault
+        let net_assets = utils::calculations::calculate_net_assets(
+            ctx.accounts.settlement_vault.amount,
+            pool.settlement_liabilities(),
+        );
+        let shield_amount = utils::calculations::calculate_shield_tokens(
+            amount,
+            net_assets,
+            ctx.accounts.shield_token_mint.supply,
+        )?;
+
+        // Transfer tokens to the settlement vault
         let cpi_accounts = Transfer {
             from: ctx.accounts.liquidity_provider_token.to_account_info(),
-            to: ctx.accounts.pool_token_account.to_account_info(),
+            to: ctx.accounts.settlement_vault.to_account_info(),
             authority: ctx.accounts.liquidity_provider.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
@@ -303,15 +902,10 @@ pub mod bioshield_insurance {
         token::transfer(cpi_ctx, amount)?;
 
         // Mint SHIELD tokens to liquidity provider
-        let shield_amount = utils::calculations::calculate_shield_tokens(
-            amount,
-            pool.total_value_locked,
-            ctx.accounts.shield_token_mint.supply,
-        )?;
-
         let pool_seeds = &[
             b"insurance_pool",
-            pool.authority.as_ref(),
+            pool.creator.as_ref(),
+            &pool.pool_id_seed()[..],
             &[pool.bump],
         ];
         let signer_seeds = &[&pool_seeds[..]];
@@ -332,7 +926,12 @@ pub mod bioshield_insurance {
         // Update pool TVL
         pool.total_value_locked = pool.total_value_locked
             .checked_add(amount)
-            .unwrap();
+            .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+        let settlement_mint = pool.settlement_mint;
+        let ledger = pool.ledger_mut(&settlement_mint)?;
+        ledger.liquidity_deposited = ledger.liquidity_deposited
+            .checked_add(amount)
+            .ok_or(InsuranceError::InvalidLiquidityAmount)?;
 
         emit!(LiquidityAdded {
             provider: ctx.accounts.liquidity_provider.key(),
@@ -344,41 +943,2167 @@ pub mod bioshield_insurance {
         Ok(())
     }
 
-    /// Emergency pause function
-    pub fn pause_pool(ctx: Context<PausePool>) -> Result<()> {
+    /// Register a senior or junior tranche with its own share mint
+    pub fn initialize_tranche(
+        ctx: Context<InitializeTranche>,
+        kind: TrancheKind,
+        premium_share_bps: u16,
+    ) -> Result<()> {
         let pool = &mut ctx.accounts.insurance_pool;
-        require!(
-            ctx.accounts.authority.key() == pool.authority,
-            InsuranceError::Unauthorized
-        );
-        
-        pool.is_paused = true;
-        
-        emit!(PoolPaused {
+
+        // Tranches must be set up before any capital is at risk
+        require!(pool.total_value_locked == 0, InsuranceError::InvalidTrancheConfig);
+        pool.add_tranche(Tranche {
+            kind: kind.clone(),
+            share_mint: ctx.accounts.share_mint.key(),
+            assets: 0,
+            premium_share_bps,
+        })?;
+
+        emit!(TrancheInitialized {
             pool: pool.key(),
-            authority: ctx.accounts.authority.key(),
+            share_mint: ctx.accounts.share_mint.key(),
+            kind,
+            premium_share_bps,
             timestamp: Clock::get()?.unix_timestamp,
         });
-        
+
         Ok(())
     }
 
-    /// Unpause pool
-    pub fn unpause_pool(ctx: Context<PausePool>) -> Result<()> {
+    /// Add liquidity to a senior or junior tranche
+    pub fn add_tranche_liquidity(
+        ctx: Context<AddTrancheLiquidity>,
+        amount: u64,
+    ) -> Result<()> {
         let pool = &mut ctx.accounts.insurance_pool;
-        require!(
-            ctx.accounts.authority.key() == pool.authority,
-            InsuranceError::Unauthorized
-        );
-        
-        pool.is_paused = false;
-        
-        emit!(PoolUnpaused {
+        let share_mint = ctx.accounts.share_mint.key();
+        let clock = Clock::get()?;
+
+        pool.require_allows(PoolOperation::AddLiquidity)?;
+        utils::validations::validate_liquidity_amount(amount)?;
+
+        // Price the deposit against the tranche's own assets
+        let net_assets = pool.tranche_net_assets(&share_mint)?;
+        let share_amount = utils::calculations::calculate_shield_tokens(
+            amount,
+            net_assets,
+            ctx.accounts.share_mint.supply,
+        )?;
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.liquidity_provider_token.to_account_info(),
+            to: ctx.accounts.settlement_vault.to_account_info(),
+            authority: ctx.accounts.liquidity_provider.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+
+        let pool_seeds = &[
+            b"insurance_pool",
+            pool.creator.as_ref(),
+            &pool.pool_id_seed()[..],
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+
+        let cpi_accounts = MintTo {
+            mint: ctx.accounts.share_mint.to_account_info(),
+            to: ctx.accounts.provider_share_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::mint_to(cpi_ctx, share_amount)?;
+
+        let tranche = pool.tranche_by_mint_mut(&share_mint)?;
+        tranche.assets = tranche.assets
+            .checked_add(amount)
+            .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+        pool.total_value_locked = pool.total_value_locked
+            .checked_add(amount)
+            .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+        let settlement_mint = pool.settlement_mint;
+        let ledger = pool.ledger_mut(&settlement_mint)?;
+        ledger.liquidity_deposited = ledger.liquidity_deposited
+            .checked_add(amount)
+            .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+
+        emit!(LiquidityAdded {
+            provider: ctx.accounts.liquidity_provider.key(),
+            amount,
+            shield_tokens_minted: share_amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Queue a withdrawal, escrowing SHIELD until the cooldown has elapsed
+    pub fn request_withdrawal(
+        ctx: Context<RequestWithdrawal>,
+        shield_amount: u64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let request = &mut ctx.accounts.withdrawal_request;
+        let clock = Clock::get()?;
+
+        pool.require_allows(PoolOperation::RequestWithdrawal)?;
+        utils::validations::validate_liquidity_amount(shield_amount)?;
+
+        // Escrow SHIELD so it cannot be transferred out of the queue
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.provider_shield_account.to_account_info(),
+            to: ctx.accounts.shield_escrow.to_account_info(),
+            authority: ctx.accounts.liquidity_provider.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, shield_amount)?;
+
+        request.pool = pool.key();
+        request.provider = ctx.accounts.liquidity_provider.key();
+        request.share_mint = ctx.accounts.shield_token_mint.key();
+        request.shield_amount = shield_amount;
+        request.sequence = pool.withdrawal_queue.tail;
+        request.requested_at = clock.unix_timestamp;
+        request.bump = ctx.bumps.withdrawal_request;
+
+        pool.withdrawal_queue.tail = pool.withdrawal_queue.tail
+            .checked_add(1)
+            .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+
+        emit!(WithdrawalRequested {
             pool: pool.key(),
-            authority: ctx.accounts.authority.key(),
-            timestamp: Clock::get()?.unix_timestamp,
+            provider: request.provider,
+            request: request.key(),
+            shield_amount,
+            sequence: request.sequence,
+            executable_at: clock.unix_timestamp.saturating_add(pool.withdrawal_config.cooldown_seconds),
+            timestamp: clock.unix_timestamp,
         });
-        
+
+        Ok(())
+    }
+
+    /// Redeem the withdrawal at the head of the queue at the current share price. A request
+    /// larger than what the epoch cap still allows is filled in part and stays at the head.
+    pub fn process_withdrawal(ctx: Context<ProcessWithdrawal>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let request = &mut ctx.accounts.withdrawal_request;
+        let clock = Clock::get()?;
+
+        pool.require_allows(PoolOperation::ProcessWithdrawal)?;
+        require!(
+            request.is_executable(&pool.withdrawal_config, clock.unix_timestamp),
+            InsuranceError::WithdrawalCooldownActive
+        );
+
+        let pool_seeds = &[
+            b"insurance_pool",
+            pool.creator.as_ref(),
+            &pool.pool_id_seed()[..],
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+
+        let mut shares = 0;
+        let mut amount = 0;
+        if !request.is_cancelled() {
+            // Roll the epoch and snapshot TVL for the outflow cap
+            pool.withdrawal_queue.roll_epoch(
+                &pool.withdrawal_config,
+                pool.total_value_locked,
+                clock.unix_timestamp,
+            );
+            let capacity = pool.withdrawal_queue.remaining_capacity(&pool.withdrawal_config)?;
+
+            let net_assets = if request.share_mint == pool.shield_token_mint {
+                utils::calculations::calculate_net_assets(
+                    ctx.accounts.settlement_vault.amount,
+                    pool.settlement_liabilities(),
+                )
+            } else {
+                pool.tranche_net_assets(&request.share_mint)?
+            };
+            let supply = ctx.accounts.shield_token_mint.supply;
+            let full_amount = utils::calculations::calculate_withdrawal_amount(
+                request.shield_amount,
+                net_assets,
+                supply,
+            )?;
+            shares = utils::calculations::fillable_shares(request.shield_amount, full_amount, capacity)?;
+            require!(shares > 0, InsuranceError::EpochWithdrawalCapReached);
+            amount = if shares == request.shield_amount {
+                full_amount
+            } else {
+                utils::calculations::calculate_withdrawal_amount(shares, net_assets, supply)?
+                    .min(capacity)
+            };
+
+            // Burn the SHIELD being filled
+            let cpi_accounts = Burn {
+                mint: ctx.accounts.shield_token_mint.to_account_info(),
+                from: ctx.accounts.shield_escrow.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::burn(cpi_ctx, shares)?;
+
+            // Pay out from the settlement vault
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.settlement_vault.to_account_info(),
+                to: ctx.accounts.provider_settlement_account.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, amount)?;
+
+            request.shield_amount -= shares;
+            pool.withdrawal_queue.epoch_withdrawn = pool.withdrawal_queue.epoch_withdrawn
+                .checked_add(amount)
+                .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+            pool.total_value_locked = pool.total_value_locked.saturating_sub(amount);
+            if request.share_mint != pool.shield_token_mint {
+                let tranche = pool.tranche_by_mint_mut(&request.share_mint)?;
+                tranche.assets = tranche.assets.saturating_sub(amount);
+            }
+            let settlement_mint = pool.settlement_mint;
+            let ledger = pool.ledger_mut(&settlement_mint)?;
+            ledger.liquidity_withdrawn = ledger.liquidity_withdrawn
+                .checked_add(amount)
+                .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+        }
+
+        // A filled or cancelled request leaves the queue, returning its rent to the provider
+        if request.shield_amount == 0 {
+            let cpi_accounts = CloseAccount {
+                account: ctx.accounts.shield_escrow.to_account_info(),
+                destination: ctx.accounts.liquidity_provider.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::close_account(cpi_ctx)?;
+
+            pool.withdrawal_queue.head = pool.withdrawal_queue.head
+                .checked_add(1)
+                .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+            request.close(ctx.accounts.liquidity_provider.to_account_info())?;
+        }
+
+        if shares > 0 {
+            emit!(LiquidityRemoved {
+                provider: request.provider,
+                amount,
+                shield_tokens_burned: shares,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Take back the SHIELD escrowed by a queued withdrawal. The request keeps its place in
+    /// the queue, empty, so the requests behind it are not held up.
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        let pool = &ctx.accounts.insurance_pool;
+        let request = &mut ctx.accounts.withdrawal_request;
+
+        require!(!request.is_cancelled(), InsuranceError::WithdrawalAlreadyCancelled);
+
+        let pool_seeds = &[
+            b"insurance_pool",
+            pool.creator.as_ref(),
+            &pool.pool_id_seed()[..],
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.shield_escrow.to_account_info(),
+            to: ctx.accounts.provider_shield_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, request.shield_amount)?;
+
+        emit!(WithdrawalCancelled {
+            pool: pool.key(),
+            provider: request.provider,
+            request: request.key(),
+            shield_amount: request.shield_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        request.shield_amount = 0;
+
+        Ok(())
+    }
+
+    /// Report the SHIELD share price without writing any state
+    pub fn get_share_price(ctx: Context<GetSharePrice>) -> Result<()> {
+        let pool = &ctx.accounts.insurance_pool;
+        let vault_balance = ctx.accounts.settlement_vault.amount;
+        let shield_supply = ctx.accounts.shield_token_mint.supply;
+
+        let net_assets = utils::calculations::calculate_net_assets(
+            vault_balance,
+            pool.settlement_liabilities(),
+        );
+
+        let share_price = SharePrice {
+            vault_balance,
+            reserved_claims: pool.reserved_claims,
+            unearned_premiums: pool.unearned_premiums,
+            rewards_owed: pool.premium_rewards.rewards_owed,
+            net_assets,
+            shield_supply,
+            price_per_share: utils::calculations::calculate_share_price(net_assets, shield_supply)?,
+        };
+
+        anchor_lang::solana_program::program::set_return_data(&share_price.try_to_vec()?);
+
+        Ok(())
+    }
+
+    /// Release the premium a policy has earned so far to LPs
+    pub fn accrue_premium(ctx: Context<AccruePremium>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let coverage = &mut ctx.accounts.coverage_account;
+        let clock = Clock::get()?;
+
+        // LIVES premiums never back SHIELD, so only settlement premiums are amortized
+        require!(
+            coverage.premium_mint == pool.settlement_mint,
+            InsuranceError::InvalidTokenMint
+        );
+
+        // A policy that can no longer be claimed against has earned its whole premium
+        let earned_until = match coverage.status {
+            CoverageStatus::Active | CoverageStatus::Listed => clock.unix_timestamp,
+            _ => coverage.end_time,
+        };
+        let earned = utils::calculations::calculate_earned_premium(
+            coverage.premium_paid,
+            coverage.start_time,
+            coverage.end_time,
+            earned_until,
+            coverage.pricing_version,
+        )?;
+        let amount = earned.saturating_sub(coverage.premium_earned);
+
+        utils::validations::validate_reinsurance_accounts(
+            pool,
+            ctx.accounts.reinsurance_treaty.as_ref().map(|treaty| treaty.key()),
+            ctx.accounts.reinsurance_treaty.as_deref(),
+            ctx.accounts.reinsurer_pool.as_ref().map(|reinsurer| reinsurer.key()),
+        )?;
+
+        let mut staker_rewards = 0;
+        if amount > 0 {
+            coverage.premium_earned = earned;
+            pool.unearned_premiums = pool.unearned_premiums.saturating_sub(amount);
+
+            // Cede the reinsurer's share of the premium as it is earned
+            let mut retained = amount;
+            if let (Some(treaty), Some(reinsurer)) = (
+                ctx.accounts.reinsurance_treaty.as_mut(),
+                ctx.accounts.reinsurer_pool.as_mut(),
+            ) {
+                let ceded = treaty.ceded_premium_for(amount)?;
+                if ceded > 0 {
+                    let reinsurer_vault = ctx.accounts.reinsurer_settlement_vault
+                        .as_ref()
+                        .ok_or(InsuranceError::VaultNotInitialized)?;
+                    require!(
+                        Some(reinsurer_vault.key()) == reinsurer.vault_for(&reinsurer.settlement_mint),
+                        InsuranceError::ReinsuranceAccountsMismatch
+                    );
+
+                    let pool_seeds = &[
+                        b"insurance_pool",
+                        pool.creator.as_ref(),
+                        &pool.pool_id_seed()[..],
+                        &[pool.bump],
+                    ];
+                    let signer_seeds = &[&pool_seeds[..]];
+
+                    let cpi_accounts = Transfer {
+                        from: ctx.accounts.settlement_vault.to_account_info(),
+                        to: reinsurer_vault.to_account_info(),
+                        authority: pool.to_account_info(),
+                    };
+                    let cpi_program = ctx.accounts.token_program.to_account_info();
+                    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+                    token::transfer(cpi_ctx, ceded)?;
+
+                    treaty.ceded_premiums = treaty.ceded_premiums
+                        .checked_add(ceded)
+                        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+                    pool.ceded_premiums = pool.ceded_premiums
+                        .checked_add(ceded)
+                        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+                    pool.total_value_locked = pool.total_value_locked.saturating_sub(ceded);
+
+                    reinsurer.total_value_locked = reinsurer.total_value_locked
+                        .checked_add(ceded)
+                        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+                    let reinsurer_staker_rewards = if reinsurer.has_tranches() {
+                        reinsurer.allocate_premium(ceded)?;
+                        0
+                    } else {
+                        let reinsurer_shield_mint = ctx.accounts.reinsurer_shield_token_mint
+                            .as_ref()
+                            .ok_or(InsuranceError::ReinsuranceAccountsMismatch)?;
+                        require!(
+                            reinsurer_shield_mint.key() == reinsurer.shield_token_mint,
+                            InsuranceError::InvalidTokenMint
+                        );
+                        // Unstaked SHIELD keeps its part of the ceded premium as net assets
+                        reinsurer.premium_rewards.distribute(ceded, reinsurer_shield_mint.supply)?
+                    };
+                    let reinsurer_mint = reinsurer.settlement_mint;
+                    let ledger = reinsurer.ledger_mut(&reinsurer_mint)?;
+                    ledger.premiums_collected = ledger.premiums_collected
+                        .checked_add(ceded)
+                        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+                    retained -= ceded;
+
+                    emit!(PremiumCeded {
+                        treaty: treaty.key(),
+                        coverage: coverage.key(),
+                        amount: ceded,
+                        staker_rewards: reinsurer_staker_rewards,
+                        timestamp: clock.unix_timestamp,
+                    });
+                }
+            }
+
+            // Tranches take earned premium through their waterfall; otherwise it streams
+            // to staked SHIELD
+            staker_rewards = if pool.has_tranches() {
+                pool.allocate_premium(retained)?;
+                0
+            } else {
+                // Staked SHIELD is credited its fraction of the supply; the rest stays in the
+                // vault, where leaving the unearned balance raises the share price for every
+                // SHIELD holder
+                pool.premium_rewards.distribute(retained, ctx.accounts.shield_token_mint.supply)?
+            };
+        }
+
+        emit!(PremiumEarned {
+            pool: pool.key(),
+            coverage: coverage.key(),
+            amount,
+            premium_earned: coverage.premium_earned,
+            unearned_premiums: pool.unearned_premiums,
+            staker_rewards,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Open a stake position with its SHIELD escrow
+    pub fn open_stake_position(ctx: Context<OpenStakePosition>) -> Result<()> {
+        let position = &mut ctx.accounts.stake_position;
+
+        position.pool = ctx.accounts.insurance_pool.key();
+        position.owner = ctx.accounts.owner.key();
+        position.staked_amount = 0;
+        position.reward_debt = 0;
+        position.pending_rewards = 0;
+        position.lock_end = 0;
+        position.last_staked_at = 0;
+        position.boost_bps = BASIS_POINTS as u32;
+        position.boosted_amount = 0;
+        position.lives_reward_debt = 0;
+        position.pending_lives_rewards = 0;
+        position.bump = ctx.bumps.stake_position;
+
+        Ok(())
+    }
+
+    /// Stake SHIELD, optionally locking the whole position for a LIVES emissions boost
+    pub fn stake_shield(
+        ctx: Context<StakeShield>,
+        amount: u64,
+        lock_seconds: i64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let position = &mut ctx.accounts.stake_position;
+        let clock = Clock::get()?;
+
+        utils::validations::validate_liquidity_amount(amount)?;
+        require!(
+            (0..=MAX_STAKE_LOCK_SECONDS).contains(&lock_seconds),
+            InsuranceError::InvalidStakingConfig
+        );
+
+        let emission_rate = pool.staking_config.lives_emission_per_second;
+        pool.lives_emissions.update(emission_rate, clock.unix_timestamp)?;
+        position.settle(&pool.premium_rewards, &pool.lives_emissions)?;
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.owner_shield_account.to_account_info(),
+            to: ctx.accounts.stake_escrow.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+
+        position.extend_lock(&pool.staking_config, lock_seconds, clock.unix_timestamp)?;
+        position.last_staked_at = clock.unix_timestamp;
+
+        position.staked_amount = position.staked_amount
+            .checked_add(amount)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+        pool.premium_rewards.total_staked = pool.premium_rewards.total_staked
+            .checked_add(amount)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+
+        let boosted_amount = utils::calculations::calculate_boosted_amount(
+            position.staked_amount,
+            position.boost_bps,
+        )?;
+        pool.lives_emissions.total_boosted = pool.lives_emissions.total_boosted
+            .saturating_sub(position.boosted_amount)
+            .checked_add(boosted_amount)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+        position.boosted_amount = boosted_amount;
+        position.sync_reward_debt(&pool.premium_rewards, &pool.lives_emissions)?;
+
+        emit!(ShieldStaked {
+            pool: pool.key(),
+            owner: position.owner,
+            amount,
+            staked_amount: position.staked_amount,
+            lock_end: position.lock_end,
+            boost_bps: position.boost_bps,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Return staked SHIELD once its lock has ended and its latest stake has aged past the
+    /// minimum staking period; accrued rewards stay claimable
+    pub fn unstake_shield(
+        ctx: Context<StakeShield>,
+        amount: u64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let position = &mut ctx.accounts.stake_position;
+        let clock = Clock::get()?;
+
+        require!(
+            amount > 0 && amount <= position.staked_amount,
+            InsuranceError::InsufficientStake
+        );
+        // Locks lapse once the pool is redeeming, so stakers can burn their SHIELD
+        require!(
+            clock.unix_timestamp >= position.lock_end || pool.status() == PoolStatus::Redeeming,
+            InsuranceError::StakeLocked
+        );
+        require!(
+            !position.is_warming_up(clock.unix_timestamp) || pool.status() == PoolStatus::Redeeming,
+            InsuranceError::StakeWarmingUp
+        );
+
+        let emission_rate = pool.staking_config.lives_emission_per_second;
+        pool.lives_emissions.update(emission_rate, clock.unix_timestamp)?;
+        position.settle(&pool.premium_rewards, &pool.lives_emissions)?;
+
+        let pool_seeds = &[
+            b"insurance_pool",
+            pool.creator.as_ref(),
+            &pool.pool_id_seed()[..],
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.stake_escrow.to_account_info(),
+            to: ctx.accounts.owner_shield_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, amount)?;
+
+        // The lock has ended, so whatever remains staked earns at 1x
+        position.staked_amount -= amount;
+        position.boost_bps = BASIS_POINTS as u32;
+        pool.premium_rewards.total_staked = pool.premium_rewards.total_staked.saturating_sub(amount);
+        pool.lives_emissions.total_boosted = pool.lives_emissions.total_boosted
+            .saturating_sub(position.boosted_amount)
+            .saturating_add(position.staked_amount);
+        position.boosted_amount = position.staked_amount;
+        position.sync_reward_debt(&pool.premium_rewards, &pool.lives_emissions)?;
+
+        emit!(ShieldUnstaked {
+            pool: pool.key(),
+            owner: position.owner,
+            amount,
+            staked_amount: position.staked_amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Open the insured's LIVES stake, which qualifies them for premium discount tiers
+    pub fn open_lives_stake(ctx: Context<OpenLivesStake>) -> Result<()> {
+        let stake = &mut ctx.accounts.lives_stake;
+
+        stake.pool = ctx.accounts.insurance_pool.key();
+        stake.owner = ctx.accounts.owner.key();
+        stake.amount = 0;
+        stake.staked_since = Clock::get()?.unix_timestamp;
+        stake.bump = ctx.bumps.lives_stake;
+
+        Ok(())
+    }
+
+    /// Stake LIVES towards a premium discount tier. It counts in full once it has
+    /// been held for the warm-up period.
+    pub fn stake_lives(
+        ctx: Context<StakeLives>,
+        amount: u64,
+    ) -> Result<()> {
+        let stake = &mut ctx.accounts.lives_stake;
+        let clock = Clock::get()?;
+
+        utils::validations::validate_liquidity_amount(amount)?;
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.owner_lives_account.to_account_info(),
+            to: ctx.accounts.lives_stake_escrow.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+
+        stake.deposit(amount, clock.unix_timestamp)?;
+
+        emit!(LivesStaked {
+            pool: stake.pool,
+            owner: stake.owner,
+            amount,
+            staked_amount: stake.amount,
+            staked_since: stake.staked_since,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Return staked LIVES; discounts already quoted are unaffected
+    pub fn unstake_lives(
+        ctx: Context<StakeLives>,
+        amount: u64,
+    ) -> Result<()> {
+        let pool = &ctx.accounts.insurance_pool;
+        let stake = &mut ctx.accounts.lives_stake;
+
+        require!(
+            amount > 0 && amount <= stake.amount,
+            InsuranceError::InsufficientStake
+        );
+
+        let pool_seeds = &[
+            b"insurance_pool",
+            pool.creator.as_ref(),
+            &pool.pool_id_seed()[..],
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.lives_stake_escrow.to_account_info(),
+            to: ctx.accounts.owner_lives_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, amount)?;
+
+        stake.withdraw(amount)?;
+
+        emit!(LivesUnstaked {
+            pool: stake.pool,
+            owner: stake.owner,
+            amount,
+            staked_amount: stake.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Drop an expired lock's boost so it stops diluting other stakers' emissions
+    pub fn refresh_stake_boost(ctx: Context<RefreshStakeBoost>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let position = &mut ctx.accounts.stake_position;
+        let clock = Clock::get()?;
+
+        require!(
+            clock.unix_timestamp >= position.lock_end,
+            InsuranceError::StakeLocked
+        );
+
+        if position.boost_bps != BASIS_POINTS as u32 {
+            let emission_rate = pool.staking_config.lives_emission_per_second;
+            pool.lives_emissions.update(emission_rate, clock.unix_timestamp)?;
+            position.settle(&pool.premium_rewards, &pool.lives_emissions)?;
+
+            position.boost_bps = BASIS_POINTS as u32;
+            pool.lives_emissions.total_boosted = pool.lives_emissions.total_boosted
+                .saturating_sub(position.boosted_amount)
+                .saturating_add(position.staked_amount);
+            position.boosted_amount = position.staked_amount;
+            position.sync_reward_debt(&pool.premium_rewards, &pool.lives_emissions)?;
+
+            emit!(StakeBoostExpired {
+                pool: pool.key(),
+                owner: position.owner,
+                boosted_amount: position.boosted_amount,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Pay out the earned premiums credited to a stake position
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let position = &mut ctx.accounts.stake_position;
+
+        let emission_rate = pool.staking_config.lives_emission_per_second;
+        pool.lives_emissions.update(emission_rate, Clock::get()?.unix_timestamp)?;
+        position.settle(&pool.premium_rewards, &pool.lives_emissions)?;
+        let amount = position.pending_rewards;
+        require!(amount > 0, InsuranceError::NoRewardsToClaim);
+
+        let pool_seeds = &[
+            b"insurance_pool",
+            pool.creator.as_ref(),
+            &pool.pool_id_seed()[..],
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.settlement_vault.to_account_info(),
+            to: ctx.accounts.owner_settlement_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, amount)?;
+
+        position.pending_rewards = 0;
+        pool.premium_rewards.rewards_owed = pool.premium_rewards.rewards_owed.saturating_sub(amount);
+        pool.total_value_locked = pool.total_value_locked.saturating_sub(amount);
+
+        emit!(RewardsClaimed {
+            pool: pool.key(),
+            owner: position.owner,
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Pay out the LIVES emissions credited to a stake position
+    pub fn claim_lives_rewards(ctx: Context<ClaimLivesRewards>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let position = &mut ctx.accounts.stake_position;
+        let clock = Clock::get()?;
+
+        let emission_rate = pool.staking_config.lives_emission_per_second;
+        pool.lives_emissions.update(emission_rate, clock.unix_timestamp)?;
+        position.settle(&pool.premium_rewards, &pool.lives_emissions)?;
+        let amount = position.pending_lives_rewards;
+        require!(amount > 0, InsuranceError::NoRewardsToClaim);
+
+        let pool_seeds = &[
+            b"insurance_pool",
+            pool.creator.as_ref(),
+            &pool.pool_id_seed()[..],
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.lives_reward_vault.to_account_info(),
+            to: ctx.accounts.owner_lives_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, amount)?;
+
+        position.pending_lives_rewards = 0;
+
+        emit!(LivesRewardsClaimed {
+            pool: pool.key(),
+            owner: position.owner,
+            amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Deposit LIVES into the reward vault to fund staking emissions
+    pub fn fund_lives_rewards(
+        ctx: Context<FundLivesRewards>,
+        amount: u64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let clock = Clock::get()?;
+
+        utils::validations::validate_liquidity_amount(amount)?;
+
+        // Accrue at the old funding level before topping it up
+        let emission_rate = pool.staking_config.lives_emission_per_second;
+        pool.lives_emissions.update(emission_rate, clock.unix_timestamp)?;
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.funder_lives_account.to_account_info(),
+            to: ctx.accounts.lives_reward_vault.to_account_info(),
+            authority: ctx.accounts.funder.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+
+        pool.lives_emissions.funded_remaining = pool.lives_emissions.funded_remaining
+            .checked_add(amount)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+
+        emit!(LivesRewardsFunded {
+            pool: pool.key(),
+            funder: ctx.accounts.funder.key(),
+            amount,
+            funded_remaining: pool.lives_emissions.funded_remaining,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Create the LIVES vault that funds staking emissions
+    pub fn initialize_lives_reward_vault(ctx: Context<InitializeLivesRewardVault>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let clock = Clock::get()?;
+
+        // Checkpoint emissions at creation; nothing accrues until the vault is funded
+        let emission_rate = pool.staking_config.lives_emission_per_second;
+        pool.lives_emissions.update(emission_rate, clock.unix_timestamp)?;
+
+        emit!(LivesRewardVaultInitialized {
+            pool: pool.key(),
+            vault: ctx.accounts.lives_reward_vault.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Cede part of the pool's risk to a backstop pool under a quota-share or excess-of-loss treaty
+    pub fn register_reinsurance_treaty(
+        ctx: Context<RegisterReinsuranceTreaty>,
+        terms: TreatyTerms,
+    ) -> Result<()> {
+        let treaty = &mut ctx.accounts.reinsurance_treaty;
+        let pool = &mut ctx.accounts.insurance_pool;
+        let clock = Clock::get()?;
+
+        utils::validations::validate_treaty_terms(&terms)?;
+
+        treaty.cedent = pool.key();
+        treaty.reinsurer = ctx.accounts.reinsurer_pool.key();
+        treaty.terms = terms;
+        treaty.ceded_coverage = 0;
+        treaty.ceded_premiums = 0;
+        treaty.recoveries = 0;
+        treaty.created_at = clock.unix_timestamp;
+        treaty.bump = ctx.bumps.reinsurance_treaty;
+
+        pool.reinsurance_treaty = Some(treaty.key());
+
+        emit!(ReinsuranceTreatyRegistered {
+            treaty: treaty.key(),
+            cedent: treaty.cedent,
+            reinsurer: treaty.reinsurer,
+            kind: treaty.terms.kind.clone(),
+            cession_bps: treaty.terms.cession_bps,
+            attachment_point: treaty.terms.attachment_point,
+            limit: treaty.terms.limit,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Hand parameter changes on this pool to SHIELD/LIVES holder governance
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        config: GovernanceConfig,
+    ) -> Result<()> {
+        let governance = &mut ctx.accounts.governance;
+        let pool = &mut ctx.accounts.insurance_pool;
+
+        utils::validations::validate_governance_config(&config)?;
+
+        governance.pool = pool.key();
+        governance.config = config;
+        governance.proposal_count = 0;
+        governance.bump = ctx.bumps.governance;
+
+        pool.governance = Some(governance.key());
+        // Governance holds authority like any admin: its passed proposals are queued in its name
+        ctx.accounts.roles.grant(governance.key(), Role::Admin)?;
+
+        emit!(GovernanceInitialized {
+            pool: pool.key(),
+            governance: governance.key(),
+            voting_period: governance.config.voting_period,
+            quorum_votes: governance.config.quorum_votes,
+            threshold_bps: governance.config.threshold_bps,
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Open a voter record with SHIELD and LIVES escrows owned by governance
+    pub fn create_voter_record(ctx: Context<CreateVoterRecord>) -> Result<()> {
+        let voter = &mut ctx.accounts.voter_record;
+
+        voter.governance = ctx.accounts.governance.key();
+        voter.owner = ctx.accounts.owner.key();
+        voter.shield_deposited = 0;
+        voter.lives_deposited = 0;
+        voter.checkpoints = Vec::new();
+        voter.bump = ctx.bumps.voter_record;
+
+        Ok(())
+    }
+
+    /// Escrow SHIELD and LIVES to gain voting weight on proposals created afterwards
+    pub fn deposit_votes(
+        ctx: Context<DepositVotes>,
+        shield_amount: u64,
+        lives_amount: u64,
+    ) -> Result<()> {
+        let governance = &ctx.accounts.governance;
+        let voter = &mut ctx.accounts.voter_record;
+        let clock = Clock::get()?;
+
+        require!(
+            shield_amount > 0 || lives_amount > 0,
+            InsuranceError::InvalidLiquidityAmount
+        );
+
+        if shield_amount > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.owner_shield_account.to_account_info(),
+                to: ctx.accounts.shield_escrow.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::transfer(CpiContext::new(cpi_program, cpi_accounts), shield_amount)?;
+        }
+
+        if lives_amount > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.owner_lives_account.to_account_info(),
+                to: ctx.accounts.lives_escrow.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::transfer(CpiContext::new(cpi_program, cpi_accounts), lives_amount)?;
+        }
+
+        voter.shield_deposited = voter.shield_deposited
+            .checked_add(shield_amount)
+            .ok_or(InsuranceError::InsufficientVotingWeight)?;
+        voter.lives_deposited = voter.lives_deposited
+            .checked_add(lives_amount)
+            .ok_or(InsuranceError::InsufficientVotingWeight)?;
+        let weight = utils::calculations::calculate_vote_weight(
+            voter.shield_deposited,
+            voter.lives_deposited,
+            governance.config.lives_vote_weight_bps,
+        )?;
+        voter.push_checkpoint(clock.unix_timestamp, weight);
+
+        emit!(VotingWeightChanged {
+            governance: governance.key(),
+            voter: voter.owner,
+            shield_deposited: voter.shield_deposited,
+            lives_deposited: voter.lives_deposited,
+            weight,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Return escrowed voting tokens; ballots already cast keep their snapshotted weight
+    pub fn withdraw_votes(
+        ctx: Context<DepositVotes>,
+        shield_amount: u64,
+        lives_amount: u64,
+    ) -> Result<()> {
+        let governance = &ctx.accounts.governance;
+        let voter = &mut ctx.accounts.voter_record;
+        let clock = Clock::get()?;
+
+        require!(
+            shield_amount <= voter.shield_deposited && lives_amount <= voter.lives_deposited,
+            InsuranceError::InsufficientVotingWeight
+        );
+
+        let pool_key = ctx.accounts.insurance_pool.key();
+        let governance_seeds = &[
+            b"governance",
+            pool_key.as_ref(),
+            &[governance.bump],
+        ];
+        let signer_seeds = &[&governance_seeds[..]];
+
+        if shield_amount > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.shield_escrow.to_account_info(),
+                to: ctx.accounts.owner_shield_account.to_account_info(),
+                authority: governance.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, shield_amount)?;
+        }
+
+        if lives_amount > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.lives_escrow.to_account_info(),
+                to: ctx.accounts.owner_lives_account.to_account_info(),
+                authority: governance.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, lives_amount)?;
+        }
+
+        voter.shield_deposited -= shield_amount;
+        voter.lives_deposited -= lives_amount;
+        let weight = utils::calculations::calculate_vote_weight(
+            voter.shield_deposited,
+            voter.lives_deposited,
+            governance.config.lives_vote_weight_bps,
+        )?;
+        voter.push_checkpoint(clock.unix_timestamp, weight);
+
+        emit!(VotingWeightChanged {
+            governance: governance.key(),
+            voter: voter.owner,
+            shield_deposited: voter.shield_deposited,
+            lives_deposited: voter.lives_deposited,
+            weight,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Propose a set of pool parameter changes
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        actions: Vec<ParameterChange>,
+    ) -> Result<()> {
+        let governance = &mut ctx.accounts.governance;
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        utils::validations::validate_proposal_actions(&actions)?;
+        for action in actions.iter() {
+            ctx.accounts.insurance_pool.check_parameter_change(action)?;
+        }
+        require!(
+            ctx.accounts.voter_record.current_weight() >= governance.config.min_proposal_weight,
+            InsuranceError::InsufficientVotingWeight
+        );
+
+        proposal.governance = governance.key();
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.id = governance.proposal_count;
+        proposal.actions = actions;
+        proposal.created_at = clock.unix_timestamp;
+        proposal.voting_ends_at = clock.unix_timestamp
+            .checked_add(governance.config.voting_period)
+            .ok_or(InsuranceError::InvalidGovernanceConfig)?;
+        proposal.yes_votes = 0;
+        proposal.no_votes = 0;
+        proposal.status = ProposalStatus::Voting;
+        proposal.bump = ctx.bumps.proposal;
+
+        governance.proposal_count = governance.proposal_count
+            .checked_add(1)
+            .ok_or(InsuranceError::InvalidProposal)?;
+
+        emit!(ProposalCreated {
+            governance: governance.key(),
+            proposal: proposal.key(),
+            proposer: proposal.proposer,
+            id: proposal.id,
+            action_count: proposal.actions.len() as u8,
+            voting_ends_at: proposal.voting_ends_at,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Vote with the weight held before the proposal was created
+    pub fn cast_vote(
+        ctx: Context<CastVote>,
+        support: bool,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let vote = &mut ctx.accounts.vote_record;
+        let clock = Clock::get()?;
+
+        require!(
+            proposal.status == ProposalStatus::Voting &&
+            clock.unix_timestamp < proposal.voting_ends_at,
+            InsuranceError::VotingClosed
+        );
+
+        let weight = ctx.accounts.voter_record.weight_at(proposal.created_at);
+        require!(weight > 0, InsuranceError::InsufficientVotingWeight);
+
+        if support {
+            proposal.yes_votes = proposal.yes_votes
+                .checked_add(weight)
+                .ok_or(InsuranceError::InvalidProposal)?;
+        } else {
+            proposal.no_votes = proposal.no_votes
+                .checked_add(weight)
+                .ok_or(InsuranceError::InvalidProposal)?;
+        }
+
+        vote.proposal = proposal.key();
+        vote.voter = ctx.accounts.voter.key();
+        vote.weight = weight;
+        vote.support = support;
+        vote.bump = ctx.bumps.vote_record;
+
+        emit!(VoteCast {
+            proposal: proposal.key(),
+            voter: vote.voter,
+            support,
+            weight,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Hand a passed proposal's actions to the pool's timelock, where they wait out the
+    /// same delay and guardian veto as an admin's changes; callable by anyone
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let proposal = &mut ctx.accounts.proposal;
+        let operation = &mut ctx.accounts.operation;
+        let clock = Clock::get()?;
+
+        require!(
+            proposal.status == ProposalStatus::Voting,
+            InsuranceError::InvalidProposal
+        );
+        require!(
+            clock.unix_timestamp >= proposal.voting_ends_at,
+            InsuranceError::VotingStillOpen
+        );
+        require!(
+            proposal.has_passed(&ctx.accounts.governance.config)?,
+            InsuranceError::ProposalNotPassed
+        );
+
+        let (id, eta) = pool.next_timelock_operation(clock.unix_timestamp)?;
+        operation.pool = pool.key();
+        operation.id = id;
+        operation.proposer = ctx.accounts.governance.key();
+        operation.payer = ctx.accounts.payer.key();
+        operation.actions = proposal.actions.clone();
+        operation.queued_at = clock.unix_timestamp;
+        operation.eta = eta;
+        operation.bump = ctx.bumps.operation;
+
+        proposal.status = ProposalStatus::Queued;
+
+        emit!(ProposalFinalized {
+            proposal: proposal.key(),
+            passed: true,
+            yes_votes: proposal.yes_votes,
+            no_votes: proposal.no_votes,
+            timestamp: clock.unix_timestamp,
+        });
+
+        emit!(ParameterChangeQueued {
+            pool: pool.key(),
+            operation: operation.key(),
+            id,
+            actions: operation.actions.clone(),
+            proposer: operation.proposer,
+            eta,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Close out a proposal that failed quorum or threshold; callable by anyone
+    pub fn reject_proposal(ctx: Context<RejectProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        require!(
+            proposal.status == ProposalStatus::Voting,
+            InsuranceError::InvalidProposal
+        );
+        require!(
+            clock.unix_timestamp >= proposal.voting_ends_at,
+            InsuranceError::VotingStillOpen
+        );
+        require!(
+            !proposal.has_passed(&ctx.accounts.governance.config)?,
+            InsuranceError::ProposalPassed
+        );
+
+        proposal.status = ProposalStatus::Defeated;
+
+        emit!(ProposalFinalized {
+            proposal: proposal.key(),
+            passed: false,
+            yes_votes: proposal.yes_votes,
+            no_votes: proposal.no_votes,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Queue a parameter change that takes effect once the pool's timelock delay has passed
+    pub fn queue_parameter_change(
+        ctx: Context<QueueParameterChange>,
+        change: ParameterChange,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let operation = &mut ctx.accounts.operation;
+        let clock = Clock::get()?;
+
+        utils::validations::validate_parameter_change(&change)?;
+        pool.check_parameter_change(&change)?;
+
+        let (id, eta) = pool.next_timelock_operation(clock.unix_timestamp)?;
+        operation.pool = pool.key();
+        operation.id = id;
+        operation.proposer = ctx.accounts.authority.key();
+        operation.payer = ctx.accounts.authority.key();
+        operation.actions = vec![change];
+        operation.queued_at = clock.unix_timestamp;
+        operation.eta = eta;
+        operation.bump = ctx.bumps.operation;
+
+        // LPs watch for these to exit before an unfavourable change lands
+        emit!(ParameterChangeQueued {
+            pool: pool.key(),
+            operation: operation.key(),
+            id,
+            actions: operation.actions.clone(),
+            proposer: operation.proposer,
+            eta,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Queue a new LIVES emission rate and lock boost schedule
+    pub fn update_staking_config(
+        ctx: Context<QueueParameterChange>,
+        staking_config: StakingConfig,
+    ) -> Result<()> {
+        queue_parameter_change(ctx, ParameterChange::StakingConfig { config: staking_config })
+    }
+
+    /// Queue a new LP withdrawal cooldown and epoch cap
+    pub fn update_withdrawal_config(
+        ctx: Context<QueueParameterChange>,
+        withdrawal_config: WithdrawalConfig,
+    ) -> Result<()> {
+        queue_parameter_change(ctx, ParameterChange::WithdrawalConfig { config: withdrawal_config })
+    }
+
+    /// Queue a new LIVES premium discount schedule
+    pub fn update_lives_discount(
+        ctx: Context<QueueParameterChange>,
+        schedule: LivesDiscountSchedule,
+    ) -> Result<()> {
+        queue_parameter_change(ctx, ParameterChange::LivesDiscount { schedule })
+    }
+
+    /// Queue a new utilization pricing curve and capacity limit
+    pub fn update_capacity_config(
+        ctx: Context<QueueParameterChange>,
+        pricing_curve: PricingCurve,
+        max_utilization_bps: u32,
+    ) -> Result<()> {
+        queue_parameter_change(ctx, ParameterChange::CapacityConfig { pricing_curve, max_utilization_bps })
+    }
+
+    /// Queue lifting a guardian pause
+    pub fn unpause_pool(ctx: Context<QueueParameterChange>) -> Result<()> {
+        queue_parameter_change(ctx, ParameterChange::Pause { paused: false })
+    }
+
+    /// Queue terminating the pool's reinsurance treaty. New policies stop ceding once it
+    /// executes; those already ceded stay reinsured until they end.
+    pub fn terminate_reinsurance_treaty(ctx: Context<QueueParameterChange>) -> Result<()> {
+        queue_parameter_change(ctx, ParameterChange::TerminateTreaty)
+    }
+
+    /// Apply a queued parameter change after its ETA; callable by anyone
+    pub fn execute_parameter_change(ctx: Context<ExecuteParameterChange>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let operation = &ctx.accounts.operation;
+        let clock = Clock::get()?;
+
+        operation.check_executable(clock.unix_timestamp)?;
+
+        for action in operation.actions.iter() {
+            match action {
+                ParameterChange::GrantRole { member, role } => {
+                    ctx.accounts.roles.apply_parameter_change(action)?;
+                    emit!(RoleGranted {
+                        pool: pool.key(),
+                        member: *member,
+                        role: *role,
+                        admin: operation.proposer,
+                        timestamp: clock.unix_timestamp,
+                    });
+                }
+                ParameterChange::RevokeRole { member, role } => {
+                    ctx.accounts.roles.apply_parameter_change(action)?;
+                    emit!(RoleRevoked {
+                        pool: pool.key(),
+                        member: *member,
+                        role: *role,
+                        admin: operation.proposer,
+                        timestamp: clock.unix_timestamp,
+                    });
+                }
+                ParameterChange::Authority { new_authority } => {
+                    pool.apply_parameter_change(action, clock.unix_timestamp)?;
+                    emit!(AuthorityProposed {
+                        pool: pool.key(),
+                        authority: pool.authority,
+                        pending_authority: *new_authority,
+                        timestamp: clock.unix_timestamp,
+                    });
+                }
+                ParameterChange::AcceptAuthority => {
+                    // The governance PDA can't sign accept_authority, so its proposals accept here
+                    let old_authority = pool.transfer_authority(
+                        &mut ctx.accounts.roles,
+                        operation.proposer,
+                    )?;
+                    emit!(AuthorityTransferred {
+                        pool: pool.key(),
+                        old_authority,
+                        new_authority: operation.proposer,
+                        timestamp: clock.unix_timestamp,
+                    });
+                }
+                ParameterChange::TerminateTreaty => {
+                    let treaty = ctx.accounts.reinsurance_treaty
+                        .as_ref()
+                        .ok_or(InsuranceError::ReinsuranceAccountsMismatch)?;
+                    require!(
+                        Some(treaty.key()) == pool.reinsurance_treaty,
+                        InsuranceError::ReinsuranceAccountsMismatch
+                    );
+                    pool.apply_parameter_change(action, clock.unix_timestamp)?;
+                    emit!(ReinsuranceTreatyTerminated {
+                        pool: pool.key(),
+                        treaty: treaty.key(),
+                        ceded_coverage: treaty.ceded_coverage,
+                        timestamp: clock.unix_timestamp,
+                    });
+
+                    // With nothing ceded there is nothing to run off
+                    if pool.close_terminated_treaty(treaty) {
+                        emit!(ReinsuranceTreatyClosed {
+                            pool: pool.key(),
+                            treaty: treaty.key(),
+                            timestamp: clock.unix_timestamp,
+                        });
+                    }
+                }
+                _ => pool.apply_parameter_change(action, clock.unix_timestamp)?,
+            }
+        }
+
+        emit!(ParameterChangeExecuted {
+            pool: pool.key(),
+            operation: operation.key(),
+            id: operation.id,
+            actions: operation.actions.clone(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Guardian veto of a queued parameter change
+    pub fn cancel_parameter_change(ctx: Context<CancelParameterChange>) -> Result<()> {
+        let operation = &ctx.accounts.operation;
+
+        emit!(ParameterChangeCancelled {
+            pool: ctx.accounts.insurance_pool.key(),
+            operation: operation.key(),
+            id: operation.id,
+            guardian: ctx.accounts.guardian.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Queue nominating a new pool authority, or clearing the nomination with `None`
+    pub fn propose_authority(
+        ctx: Context<QueueParameterChange>,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        queue_parameter_change(ctx, ParameterChange::Authority { new_authority })
+    }
+
+    /// Complete the handover; the admin role moves with the authority
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let new_authority = ctx.accounts.new_authority.key();
+
+        let old_authority = pool.transfer_authority(&mut ctx.accounts.roles, new_authority)?;
+
+        emit!(AuthorityTransferred {
+            pool: pool.key(),
+            old_authority,
+            new_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Queue giving `member` a role in the pool
+    pub fn grant_role(ctx: Context<QueueParameterChange>, member: Pubkey, role: Role) -> Result<()> {
+        queue_parameter_change(ctx, ParameterChange::GrantRole { member, role })
+    }
+
+    /// Queue taking a role away from `member`
+    pub fn revoke_role(ctx: Context<QueueParameterChange>, member: Pubkey, role: Role) -> Result<()> {
+        queue_parameter_change(ctx, ParameterChange::RevokeRole { member, role })
+    }
+
+    /// Create the roles of a pool migrated from before roles existed: its authority becomes
+    /// the admin alongside the named guardian
+    pub fn initialize_roles(ctx: Context<InitializeRoles>, guardian: Pubkey) -> Result<()> {
+        let pool = &ctx.accounts.insurance_pool;
+        let roles = &mut ctx.accounts.roles;
+        let clock = Clock::get()?;
+
+        require!(guardian != Pubkey::default(), InsuranceError::InvalidRoleAssignment);
+
+        roles.pool = pool.key();
+        roles.members = Vec::new();
+        roles.bump = ctx.bumps.roles;
+        roles.grant(pool.authority, Role::Admin)?;
+        roles.grant(guardian, Role::Guardian)?;
+
+        for (member, role) in [(pool.authority, Role::Admin), (guardian, Role::Guardian)] {
+            emit!(RoleGranted {
+                pool: pool.key(),
+                member,
+                role,
+                admin: pool.authority,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Guardian emergency pause, the one action outside the timelock; unpausing is queued like any other change
+    pub fn pause_pool(ctx: Context<PausePool>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        
+        pool.is_paused = true;
+        
+        emit!(PoolPaused {
+            pool: pool.key(),
+            authority: ctx.accounts.guardian.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Start retiring the pool: no new coverage or liquidity, while existing policies run
+    /// to expiry or are cancelled and pending claims settle
+    pub fn begin_wind_down(ctx: Context<BeginWindDown>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let clock = Clock::get()?;
+
+        pool.begin_wind_down(clock.unix_timestamp)?;
+
+        emit!(WindDownStarted {
+            pool: pool.key(),
+            admin: ctx.accounts.admin.key(),
+            open_coverages: pool.wind_down.open_coverages,
+            coverage_ends_by: pool.wind_down.coverage_ends_by,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Cancel a policy in a winding-down pool, refunding the premium not yet earned
+    pub fn cancel_coverage(ctx: Context<CancelCoverage>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let coverage = &mut ctx.accounts.coverage_account;
+        let clock = Clock::get()?;
+
+        pool.require_allows(PoolOperation::CancelCoverage)?;
+        require!(
+            coverage.status == CoverageStatus::Active,
+            InsuranceError::CoverageNotActive
+        );
+        require!(
+            coverage.pending_claims == 0,
+            InsuranceError::CoverageHasPendingClaims
+        );
+        require!(
+            clock.unix_timestamp < coverage.end_time,
+            InsuranceError::CoverageExpired
+        );
+        // LIVES premiums are not amortized, so those policies run to expiry instead
+        require!(
+            coverage.premium_mint == pool.settlement_mint,
+            InsuranceError::InvalidTokenMint
+        );
+
+        let earned = utils::calculations::calculate_earned_premium(
+            coverage.premium_paid,
+            coverage.start_time,
+            coverage.end_time,
+            clock.unix_timestamp,
+            coverage.pricing_version,
+        )?;
+        let refund = coverage.premium_paid
+            .checked_sub(earned)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+        // Truncate the policy at cancellation; accrue_premium then releases exactly the earned part
+        coverage.premium_paid = earned;
+        coverage.end_time = clock.unix_timestamp;
+        coverage.status = CoverageStatus::Cancelled;
+
+        if refund > 0 {
+            let pool_seeds = &[
+                b"insurance_pool",
+                pool.creator.as_ref(),
+                &pool.pool_id_seed()[..],
+                &[pool.bump],
+            ];
+            let signer_seeds = &[&pool_seeds[..]];
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.settlement_vault.to_account_info(),
+                to: ctx.accounts.insured_settlement_account.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, refund)?;
+        }
+
+        pool.unearned_premiums = pool.unearned_premiums.saturating_sub(refund);
+        pool.total_value_locked = pool.total_value_locked.saturating_sub(refund);
+        pool.release_coverage(coverage.retained_coverage());
+        if coverage.ceded_coverage > 0 {
+            utils::validations::validate_reinsurance_accounts(
+                pool,
+                ctx.accounts.reinsurance_treaty.as_ref().map(|treaty| treaty.key()),
+                ctx.accounts.reinsurance_treaty.as_deref(),
+                ctx.accounts.reinsurer_pool.as_ref().map(|reinsurer| reinsurer.key()),
+            )?;
+            if let (Some(treaty), Some(reinsurer)) = (
+                ctx.accounts.reinsurance_treaty.as_mut(),
+                ctx.accounts.reinsurer_pool.as_mut(),
+            ) {
+                treaty.release_ceded_coverage(reinsurer, coverage.ceded_coverage);
+                if pool.close_terminated_treaty(treaty) {
+                    emit!(ReinsuranceTreatyClosed {
+                        pool: pool.key(),
+                        treaty: treaty.key(),
+                        timestamp: clock.unix_timestamp,
+                    });
+                }
+            }
+        }
+        let settlement_mint = pool.settlement_mint;
+        let ledger = pool.ledger_mut(&settlement_mint)?;
+        ledger.premiums_collected = ledger.premiums_collected.saturating_sub(refund);
+
+        emit!(CoverageCancelled {
+            pool: pool.key(),
+            coverage: coverage.key(),
+            cancelled_by: ctx.accounts.authority.key(),
+            refund,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Retire a policy whose period has ended with no claims outstanding. A policy still
+    /// listed has its listing closed, so an abandoned listing cannot hold up the pool's run-off.
+    pub fn expire_coverage(ctx: Context<ExpireCoverage>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let coverage = &mut ctx.accounts.coverage_account;
+        let clock = Clock::get()?;
+
+        require!(
+            matches!(coverage.status, CoverageStatus::Active | CoverageStatus::Listed),
+            InsuranceError::CoverageNotActive
+        );
+        // Claims may still be submitted at end_time itself
+        require!(
+            clock.unix_timestamp > coverage.end_time,
+            InsuranceError::CoverageNotEnded
+        );
+        require!(
+            coverage.pending_claims == 0,
+            InsuranceError::CoverageHasPendingClaims
+        );
+
+        if coverage.status == CoverageStatus::Listed {
+            let listing = ctx.accounts.listing
+                .as_ref()
+                .ok_or(InsuranceError::ListingRequired)?;
+            emit!(CoverageDelisted {
+                listing: listing.key(),
+                coverage: coverage.key(),
+                seller: listing.seller,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        coverage.status = CoverageStatus::Expired;
+        pool.release_coverage(coverage.retained_coverage());
+        if coverage.ceded_coverage > 0 {
+            utils::validations::validate_reinsurance_accounts(
+                pool,
+                ctx.accounts.reinsurance_treaty.as_ref().map(|treaty| treaty.key()),
+                ctx.accounts.reinsurance_treaty.as_deref(),
+                ctx.accounts.reinsurer_pool.as_ref().map(|reinsurer| reinsurer.key()),
+            )?;
+            if let (Some(treaty), Some(reinsurer)) = (
+                ctx.accounts.reinsurance_treaty.as_mut(),
+                ctx.accounts.reinsurer_pool.as_mut(),
+            ) {
+                treaty.release_ceded_coverage(reinsurer, coverage.ceded_coverage);
+                if pool.close_terminated_treaty(treaty) {
+                    emit!(ReinsuranceTreatyClosed {
+                        pool: pool.key(),
+                        treaty: treaty.key(),
+                        timestamp: clock.unix_timestamp,
+                    });
+                }
+            }
+        }
+
+        emit!(CoverageExpired {
+            pool: pool.key(),
+            coverage: coverage.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Move a wound-down pool into redemption once nothing is left to settle
+    pub fn open_redemption(ctx: Context<OpenRedemption>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let clock = Clock::get()?;
+
+        pool.open_redemption(clock.unix_timestamp)?;
+
+        emit!(RedemptionOpened {
+            pool: pool.key(),
+            vault_balance: ctx.accounts.settlement_vault.amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Burn shares for their pro-rata slice of what remains in a redeeming pool's vaults
+    pub fn redeem_shares(
+        ctx: Context<RedeemShares>,
+        share_amount: u64,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let share_mint = ctx.accounts.share_mint.key();
+        let clock = Clock::get()?;
+
+        pool.require_allows(PoolOperation::RedeemShares)?;
+
+        let redeemable_assets = pool.redeemable_assets(
+            &share_mint,
+            ctx.accounts.settlement_vault.amount,
+        )?;
+        let amount = utils::calculations::calculate_redemption_amount(
+            share_amount,
+            redeemable_assets,
+            ctx.accounts.share_mint.supply,
+        )?;
+
+        // The LIVES and wrapped SOL vaults are paid out pro-rata alongside the settlement vault
+        let other_vaults = [
+            (
+                ctx.accounts.lives_vault.as_ref(),
+                ctx.accounts.provider_lives_account.as_ref(),
+                pool.lives_token_mint,
+            ),
+            (
+                ctx.accounts.sol_vault.as_ref(),
+                ctx.accounts.provider_sol_account.as_ref(),
+                anchor_spl::token::spl_token::native_mint::ID,
+            ),
+        ];
+        let mut other_payouts = Vec::with_capacity(other_vaults.len());
+        for (vault, provider_account, mint) in other_vaults {
+            if mint == pool.settlement_mint || pool.vault_for(&mint).is_none() {
+                continue;
+            }
+
+            let vault = vault.ok_or(InsuranceError::VaultNotInitialized)?;
+            let vault_amount = utils::calculations::calculate_redemption_amount(
+                share_amount,
+                pool.redeemable_vault_assets(&share_mint, vault.amount)?,
+                ctx.accounts.share_mint.supply,
+            )?;
+            if vault_amount > 0 {
+                let provider_account = provider_account.ok_or(InsuranceError::MissingTokenAccount)?;
+                other_payouts.push((vault, provider_account, vault_amount));
+            }
+        }
+
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.share_mint.to_account_info(),
+            from: ctx.accounts.provider_share_account.to_account_info(),
+            authority: ctx.accounts.liquidity_provider.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::burn(cpi_ctx, share_amount)?;
+
+        let pool_seeds = &[
+            b"insurance_pool",
+            pool.creator.as_ref(),
+            &pool.pool_id_seed()[..],
+            &[pool.bump],
+        ];
+        let signer_seeds = &[&pool_seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.settlement_vault.to_account_info(),
+            to: ctx.accounts.provider_settlement_account.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::transfer(cpi_ctx, amount)?;
+
+        for (vault, provider_account, vault_amount) in other_payouts {
+            let cpi_accounts = Transfer {
+                from: vault.to_account_info(),
+                to: provider_account.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, vault_amount)?;
+
+            let ledger = pool.ledger_mut(&vault.mint)?;
+            ledger.liquidity_withdrawn = ledger.liquidity_withdrawn
+                .checked_add(vault_amount)
+                .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+
+            emit!(RedemptionAssetPaid {
+                pool: pool.key(),
+                provider: ctx.accounts.liquidity_provider.key(),
+                mint: vault.mint,
+                amount: vault_amount,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        pool.total_value_locked = pool.total_value_locked.saturating_sub(amount);
+        if share_mint != pool.shield_token_mint {
+            let tranche = pool.tranche_by_mint_mut(&share_mint)?;
+            tranche.assets = tranche.assets.saturating_sub(amount);
+        }
+        let settlement_mint = pool.settlement_mint;
+        let ledger = pool.ledger_mut(&settlement_mint)?;
+        ledger.liquidity_withdrawn = ledger.liquidity_withdrawn
+            .checked_add(amount)
+            .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+
+        emit!(SharesRedeemed {
+            pool: pool.key(),
+            provider: ctx.accounts.liquidity_provider.key(),
+            share_mint,
+            shares_burned: share_amount,
+            amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Create the PDA-owned vault for one of the pool's accepted assets
+    pub fn initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let mint = ctx.accounts.mint.key();
+
+        require!(
+            pool.ledger_mut(&mint).is_err(),
+            InsuranceError::InvalidTokenMint
+        );
+
+        pool.asset_ledgers.push(AssetLedger {
+            mint,
+            vault: ctx.accounts.vault.key(),
+            liquidity_deposited: 0,
+            liquidity_withdrawn: 0,
+            premiums_collected: 0,
+            claims_paid: 0,
+            bump: ctx.bumps.vault,
+        });
+
+        emit!(VaultInitialized {
+            pool: pool.key(),
+            mint,
+            vault: ctx.accounts.vault.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Move legacy SOL premiums held on the pool account into the wrapped SOL vault
+    pub fn sweep_sol_premiums(ctx: Context<SweepSolPremiums>) -> Result<()> {
+        let pool_info = ctx.accounts.insurance_pool.to_account_info();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(pool_info.data_len());
+        let amount = pool_info.lamports().saturating_sub(rent_exempt_minimum);
+
+        if amount > 0 {
+            **pool_info.try_borrow_mut_lamports()? -= amount;
+            **ctx.accounts.sol_vault.to_account_info().try_borrow_mut_lamports()? += amount;
+
+            let cpi_accounts = SyncNative {
+                account: ctx.accounts.sol_vault.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            token::sync_native(CpiContext::new(cpi_program, cpi_accounts))?;
+
+            let pool = &mut ctx.accounts.insurance_pool;
+            let ledger = pool.ledger_mut(&anchor_spl::token::spl_token::native_mint::ID)?;
+            ledger.premiums_collected = ledger.premiums_collected
+                .checked_add(amount)
+                .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+        }
+
+        emit!(SolPremiumsSwept {
+            pool: ctx.accounts.insurance_pool.key(),
+            vault: ctx.accounts.sol_vault.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Move legacy LP liquidity out of the pool's single-pool era token account and into the
+    /// vault for its mint, where withdrawals and claims can reach it. The legacy pool's
+    /// value locked already counted it, so only the asset ledger records the deposit.
+    pub fn sweep_legacy_liquidity(ctx: Context<SweepLegacyLiquidity>) -> Result<()> {
+        let pool = &mut ctx.accounts.insurance_pool;
+        let mint = ctx.accounts.legacy_token_account.mint;
+        let amount = ctx.accounts.legacy_token_account.amount;
+
+        // The vault must be registered before anything is moved into it
+        require!(
+            pool.vault_for(&mint) == Some(ctx.accounts.vault.key()),
+            InsuranceError::VaultNotInitialized
+        );
+
+        if amount > 0 {
+            let pool_seeds = &[
+                b"insurance_pool",
+                pool.creator.as_ref(),
+                &pool.pool_id_seed()[..],
+                &[pool.bump],
+            ];
+            let signer_seeds = &[&pool_seeds[..]];
+
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.legacy_token_account.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: pool.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, amount)?;
+
+            let ledger = pool.ledger_mut(&mint)?;
+            ledger.liquidity_deposited = ledger.liquidity_deposited
+                .checked_add(amount)
+                .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+        }
+
+        emit!(LegacyLiquiditySwept {
+            pool: pool.key(),
+            from: ctx.accounts.legacy_token_account.key(),
+            vault: ctx.accounts.vault.key(),
+            mint,
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// List an active coverage on the secondary marketplace, asking `ask_price` in the
+    /// pool's settlement token
+    pub fn list_coverage(
+        ctx: Context<ListCoverage>,
+        ask_price: u64,
+    ) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+        let coverage = &mut ctx.accounts.coverage_account;
+        let clock = Clock::get()?;
+
+        ctx.accounts.insurance_pool.require_allows(PoolOperation::TradeCoverage)?;
+        require!(ask_price > 0, InsuranceError::InvalidListingPrice);
+        require!(
+            coverage.status == CoverageStatus::Active,
+            InsuranceError::CoverageNotActive
+        );
+        require!(
+            coverage.pending_claims == 0,
+            InsuranceError::CoverageHasPendingClaims
+        );
+        require!(
+            clock.unix_timestamp.saturating_add(MIN_LISTING_TIME_REMAINING) < coverage.end_time,
+            InsuranceError::CoverageNearExpiry
+        );
+
+        // Escrow the policy: a listed coverage cannot be claimed against
+        coverage.status = CoverageStatus::Listed;
+
+        listing.seller = ctx.accounts.seller.key();
+        listing.coverage = coverage.key();
+        listing.pool = ctx.accounts.insurance_pool.key();
+        listing.ask_price = ask_price;
+        listing.listed_at = clock.unix_timestamp;
+        listing.bump = ctx.bumps.listing;
+
+        emit!(CoverageListed {
+            listing: listing.key(),
+            coverage: coverage.key(),
+            seller: listing.seller,
+            ask_price,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Buy a listed coverage, becoming its beneficiary. Paid in the settlement token, at
+    /// no more than `max_price`.
+    pub fn buy_coverage(ctx: Context<BuyCoverage>, max_price: u64) -> Result<()> {
+        let listing = &ctx.accounts.listing;
+        let pool = &ctx.accounts.insurance_pool;
+        let clock = Clock::get()?;
+
+        pool.require_allows(PoolOperation::TradeCoverage)?;
+        require!(
+            ctx.accounts.coverage_account.status == CoverageStatus::Listed,
+            InsuranceError::CoverageNotActive
+        );
+        require!(
+            clock.unix_timestamp.saturating_add(MIN_LISTING_TIME_REMAINING) < ctx.accounts.coverage_account.end_time,
+            InsuranceError::CoverageNearExpiry
+        );
+
+        let (seller_proceeds, marketplace_fee) =
+            listing.sale_split(max_price, pool.marketplace_fee_basis_points)?;
+
+        // Pay the seller
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.buyer_settlement_account.to_account_info(),
+            to: ctx.accounts.seller_settlement_account.to_account_info(),
+            authority: ctx.accounts.buyer.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, seller_proceeds)?;
+
+        // Route the marketplace fee to the treasury
+        if marketplace_fee > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.buyer_settlement_account.to_account_info(),
+                to: ctx.accounts.treasury_settlement_account.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+            token::transfer(cpi_ctx, marketplace_fee)?;
+        }
+
+        // Reassign the beneficiary and release the policy from escrow
+        let coverage = &mut ctx.accounts.coverage_account;
+        coverage.insured = ctx.accounts.buyer.key();
+        coverage.status = CoverageStatus::Active;
+
+        emit!(CoverageSold {
+            coverage: coverage.key(),
+            seller: ctx.accounts.seller.key(),
+            buyer: ctx.accounts.buyer.key(),
+            price: listing.ask_price,
+            marketplace_fee,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Cancel a marketplace listing and return the policy to the seller
+    pub fn delist_coverage(ctx: Context<DelistCoverage>) -> Result<()> {
+        let coverage = &mut ctx.accounts.coverage_account;
+
+        require!(
+            coverage.status == CoverageStatus::Listed,
+            InsuranceError::CoverageNotActive
+        );
+
+        coverage.status = CoverageStatus::Active;
+
+        emit!(CoverageDelisted {
+            listing: ctx.accounts.listing.key(),
+            coverage: coverage.key(),
+            seller: ctx.accounts.seller.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Rewrite a pool account into the current layout, growing it if it predates versioning
+    pub fn migrate_pool(ctx: Context<MigrateAccount>) -> Result<()> {
+        let from_version = utils::migration::migrate_account::<InsurancePool>(
+            &ctx.accounts.account.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            InsurancePool::SIZE,
+        )?;
+
+        emit!(AccountMigrated {
+            account: ctx.accounts.account.key(),
+            from_version,
+            to_version: POOL_ACCOUNT_VERSION,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Rewrite a coverage account into the current layout
+    pub fn migrate_coverage(ctx: Context<MigrateAccount>) -> Result<()> {
+        let from_version = utils::migration::migrate_account::<CoverageAccount>(
+            &ctx.accounts.account.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            CoverageAccount::SIZE,
+        )?;
+
+        emit!(AccountMigrated {
+            account: ctx.accounts.account.key(),
+            from_version,
+            to_version: COVERAGE_ACCOUNT_VERSION,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Rewrite a claim account into the current layout. A baseline claim still open is
+    /// counted against its policy, which is what keeps the policy from expiring under it.
+    pub fn migrate_claim(ctx: Context<MigrateClaim>) -> Result<()> {
+        let from_version = utils::migration::migrate_account::<ClaimAccount>(
+            &ctx.accounts.account.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ClaimAccount::SIZE,
+        )?;
+
+        let claim = ClaimAccount::try_deserialize(&mut &ctx.accounts.account.try_borrow_data()?[..])?;
+        let coverage = &mut ctx.accounts.coverage_account;
+        require!(
+            claim.coverage == coverage.key(),
+            InsuranceError::ClaimCoverageMismatch
+        );
+        // Claims submitted since versioning began were counted when they were made
+        if from_version == 0 && claim.is_open() {
+            coverage.pending_claims = coverage.pending_claims
+                .checked_add(1)
+                .ok_or(InsuranceError::ClaimProcessingFailed)?;
+        }
+
+        emit!(AccountMigrated {
+            account: ctx.accounts.account.key(),
+            from_version,
+            to_version: CLAIM_ACCOUNT_VERSION,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
         Ok(())
     }
 }
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use std::collections::HashMap;
+use crate::state::{BASIS_POINTS, SECONDS_PER_YEAR};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct BioscienceDataFeed {
@@ -286,48 +287,51 @@ pub enum LicensingStatus {
 
 // Risk assessment functions
 impl ClinicalTrialData {
-    pub fn calculate_risk_score(&self) -> f64 {
-        let mut risk_score = 0.0;
+    /// Likelihood of trial failure in basis points
+    pub fn risk_score_bps(&self) -> u16 {
+        let mut risk_bps: u64 = 0;
 
         // Phase risk (higher phases are less risky)
-        risk_score += match self.phase {
-            ClinicalPhase::Preclinical => 0.8,
-            ClinicalPhase::PhaseI => 0.7,
-            ClinicalPhase::PhaseII => 0.5,
-            ClinicalPhase::PhaseIII => 0.3,
-            ClinicalPhase::PhaseIV => 0.1,
-            ClinicalPhase::NotApplicable => 0.4,
+        risk_bps += match self.phase {
+            ClinicalPhase::Preclinical => 8_000,
+            ClinicalPhase::PhaseI => 7_000,
+            ClinicalPhase::PhaseII => 5_000,
+            ClinicalPhase::PhaseIII => 3_000,
+            ClinicalPhase::PhaseIV => 1_000,
+            ClinicalPhase::NotApplicable => 4_000,
         };
 
         // Enrollment risk
         if self.enrollment_actual > 0 {
-            let enrollment_ratio = self.enrollment_actual as f64 / self.enrollment_target as f64;
-            if enrollment_ratio < 0.5 {
-                risk_score += 0.3; // Poor enrollment
-            } else if enrollment_ratio < 0.8 {
-                risk_score += 0.1; // Moderate enrollment issues
+            let actual = self.enrollment_actual as u64;
+            let target = self.enrollment_target as u64;
+            if actual * 2 < target {
+                risk_bps += 3_000; // Poor enrollment, under half the target
+            } else if actual * 10 < target * 8 {
+                risk_bps += 1_000; // Moderate enrollment issues, under 80% of the target
             }
         }
 
         // Status risk
-        risk_score += match self.status {
-            super::TrialStatus::Planned => 0.2,
-            super::TrialStatus::Recruiting => 0.15,
-            super::TrialStatus::Active => 0.1,
-            super::TrialStatus::Paused => 0.4,
-            super::TrialStatus::Completed => 0.0,
-            super::TrialStatus::Failed => 1.0,
-            super::TrialStatus::Terminated => 1.0,
+        risk_bps += match self.status {
+            super::TrialStatus::Planned => 2_000,
+            super::TrialStatus::Recruiting => 1_500,
+            super::TrialStatus::Active => 1_000,
+            super::TrialStatus::Paused => 4_000,
+            super::TrialStatus::Completed => 0,
+            super::TrialStatus::Failed => 10_000,
+            super::TrialStatus::Terminated => 10_000,
         };
 
-        // Adverse events risk
-        let severe_ae_rate = self.adverse_events.iter()
+        // Adverse events risk: half the severe event rate
+        let severe_events = self.adverse_events.iter()
             .filter(|ae| matches!(ae.severity, Severity::Severe | Severity::LifeThreatening | Severity::Fatal))
-            .count() as f64 / self.enrollment_actual.max(1) as f64;
+            .count() as u64;
+        risk_bps = risk_bps.saturating_add(
+            severe_events.saturating_mul(5_000) / self.enrollment_actual.max(1) as u64
+        );
 
-        risk_score += severe_ae_rate * 0.5;
-
-        risk_score.min(1.0)
+        risk_bps.min(BASIS_POINTS as u64) as u16
     }
 
     pub fn get_efficacy_probability(&self) -> Option<f64> {
@@ -347,65 +351,240 @@ impl ClinicalTrialData {
 }
 
 impl RegulatoryData {
-    pub fn calculate_approval_probability(&self) -> f64 {
-        let mut probability = 0.5; // Base probability
+    /// Likelihood of approval in basis points
+    pub fn approval_probability_bps(&self) -> u16 {
+        let mut probability_bps: i64 = 5_000; // Base probability
 
         // Application type affects probability
-        probability += match self.application_type {
-            ApplicationType::NDA => 0.1,
-            ApplicationType::BLA => 0.15,
-            ApplicationType::ANDA => 0.3, // Generics have higher approval rates
-            ApplicationType::De510k => 0.2,
-            _ => 0.0,
+        probability_bps += match self.application_type {
+            ApplicationType::NDA => 1_000,
+            ApplicationType::BLA => 1_500,
+            ApplicationType::ANDA => 3_000, // Generics have higher approval rates
+            ApplicationType::De510k => 2_000,
+            _ => 0,
         };
 
         // Special designations increase probability
-        if self.breakthrough_designation { probability += 0.2; }
-        if self.fast_track_designation { probability += 0.1; }
-        if self.orphan_drug_designation { probability += 0.15; }
-
-        // Deficiency letters decrease probability
-        let total_deficiencies: u32 = self.deficiency_letters.iter()
-            .map(|dl| dl.deficiency_count)
-            .sum();
+        if self.breakthrough_designation { probability_bps += 2_000; }
+        if self.fast_track_designation { probability_bps += 1_000; }
+        if self.orphan_drug_designation { probability_bps += 1_500; }
 
-        probability -= (total_deficiencies as f64) * 0.02;
+        // Deficiency letters decrease probability, 2% each
+        let total_deficiencies = self.deficiency_letters.iter()
+            .fold(0i64, |total, dl| total.saturating_add(dl.deficiency_count as i64));
+        probability_bps = probability_bps.saturating_sub(total_deficiencies.saturating_mul(200));
 
-        // Advisory committee recommendation
+        // Advisory committee recommendation: 30% of the vote margin over an even split
         if let Some(ref ac_data) = self.advisory_committee_meeting {
-            let vote_ratio = ac_data.votes_yes as f64 /
-                (ac_data.votes_yes + ac_data.votes_no) as f64;
-            probability += (vote_ratio - 0.5) * 0.3;
+            let votes_cast = ac_data.votes_yes as i64 + ac_data.votes_no as i64;
+            if votes_cast > 0 {
+                probability_bps += ac_data.votes_yes as i64 * 3_000 / votes_cast - 1_500;
+            }
         }
 
-        probability.max(0.0).min(1.0)
+        probability_bps.clamp(0, BASIS_POINTS as i64) as u16
     }
 }
 
 impl IntellectualPropertyData {
-    pub fn calculate_invalidation_risk(&self) -> f64 {
-        let mut risk = 0.0;
+    /// Likelihood of the patent being invalidated in basis points, as of `now`
+    pub fn invalidation_risk_bps(&self, now: i64) -> u16 {
+        let mut risk_bps: u64 = 0;
 
-        // Patent age increases risk
-        let current_time = Clock::get().unwrap().unix_timestamp;
-        let patent_age_years = (current_time - self.filing_date) / (365 * 24 * 60 * 60);
-        risk += (patent_age_years as f64 / 20.0) * 0.3; // Max 0.3 for 20-year-old patents
+        // Patent age increases risk: 0.3 for a 20-year-old patent
+        let patent_age_years = now.saturating_sub(self.filing_date).max(0) / SECONDS_PER_YEAR;
+        risk_bps = risk_bps.saturating_add(patent_age_years as u64 * 150);
 
-        // Litigation history increases risk
-        let litigation_count = self.litigation_history.len() as f64;
-        risk += (litigation_count / 10.0) * 0.2; // Max 0.2 for 10+ litigations
+        // Litigation history increases risk: 0.2 for 10 litigations
+        risk_bps = risk_bps.saturating_add(self.litigation_history.len() as u64 * 200);
 
-        // Claim count affects risk (more claims = more attack surface)
-        risk += (self.claim_count as f64 / 50.0) * 0.1; // Max 0.1 for 50+ claims
+        // Claim count affects risk (more claims = more attack surface): 0.1 for 50 claims
+        risk_bps = risk_bps.saturating_add(self.claim_count as u64 * 20);
 
         // Patent status
-        risk += match self.patent_status {
-            PatentStatus::Pending => 0.4,
-            PatentStatus::Granted => 0.0,
-            PatentStatus::Reexamination => 0.6,
-            PatentStatus::Abandoned | PatentStatus::Expired | PatentStatus::Invalidated => 1.0,
+        risk_bps = risk_bps.saturating_add(match self.patent_status {
+            PatentStatus::Pending => 4_000,
+            PatentStatus::Granted => 0,
+            PatentStatus::Reexamination => 6_000,
+            PatentStatus::Abandoned | PatentStatus::Expired | PatentStatus::Invalidated => 10_000,
+        });
+
+        risk_bps.min(BASIS_POINTS as u64) as u16
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracles::{MultiOracleData, PythOracleData, RiskAssessment, TrialStatus};
+    use crate::state::coverage_account::CoverageType;
+
+    fn sample_trial() -> ClinicalTrialData {
+        ClinicalTrialData {
+            nct_id: "NCT00000001".to_string(),
+            trial_title: String::new(),
+            sponsor: String::new(),
+            phase: ClinicalPhase::PhaseII,
+            status: TrialStatus::Active,
+            primary_endpoint: String::new(),
+            enrollment_target: 100,
+            enrollment_actual: 40,
+            start_date: 0,
+            completion_date: None,
+            primary_outcome: None,
+            secondary_outcomes: Vec::new(),
+            adverse_events: vec![AdverseEvent {
+                event_term: "hepatotoxicity".to_string(),
+                severity: Severity::Severe,
+                frequency: 1,
+                total_participants: 40,
+                causality_assessment: Causality::Possible,
+                action_taken: String::new(),
+            }],
+            interim_analyses: Vec::new(),
+            data_monitoring_committee: false,
+            early_termination_rules: Vec::new(),
+        }
+    }
+
+    fn sample_application() -> RegulatoryData {
+        RegulatoryData {
+            application_id: "BLA-1".to_string(),
+            drug_name: String::new(),
+            indication: String::new(),
+            applicant: String::new(),
+            application_type: ApplicationType::BLA,
+            submission_date: 0,
+            review_status: crate::oracles::RegulatoryStatus::UnderReview,
+            priority_review: false,
+            breakthrough_designation: true,
+            fast_track_designation: false,
+            orphan_drug_designation: false,
+            review_timeline: ReviewTimeline {
+                standard_review_date: 0,
+                priority_review_date: None,
+                actual_action_date: None,
+                review_clock_days: 0,
+                days_remaining: 0,
+            },
+            regulatory_milestones: Vec::new(),
+            deficiency_letters: vec![DeficiencyLetter {
+                issue_date: 0,
+                response_due_date: 0,
+                deficiency_count: 2,
+                major_deficiencies: 1,
+                minor_deficiencies: 1,
+                responded: false,
+                response_date: None,
+            }],
+            advisory_committee_meeting: Some(AdvisoryCommitteeData {
+                meeting_date: 0,
+                committee_name: String::new(),
+                voting_question: String::new(),
+                votes_yes: 8,
+                votes_no: 2,
+                abstentions: 0,
+                recommendation: String::new(),
+            }),
+        }
+    }
+
+    fn sample_patent(patent_status: PatentStatus) -> IntellectualPropertyData {
+        IntellectualPropertyData {
+            patent_number: "US1234567".to_string(),
+            patent_title: String::new(),
+            inventor: String::new(),
+            assignee: String::new(),
+            filing_date: 0,
+            publication_date: 0,
+            grant_date: None,
+            expiration_date: 0,
+            patent_status,
+            claim_count: 20,
+            independent_claims: 3,
+            patent_family_size: 1,
+            citations_forward: 0,
+            citations_backward: 0,
+            litigation_history: vec![
+                LitigationEvent {
+                    case_number: String::new(),
+                    court: String::new(),
+                    filing_date: 0,
+                    plaintiff: String::new(),
+                    defendant: String::new(),
+                    case_status: LitigationStatus::Settled,
+                    outcome: None,
+                };
+                2
+            ],
+            licensing_status: LicensingStatus::Available,
+        }
+    }
+
+    #[test]
+    fn test_trial_risk_is_scored_in_basis_points() {
+        // Phase II, active, 40% enrolled and one severe event among 40 participants
+        assert_eq!(sample_trial().risk_score_bps(), 5_000 + 1_000 + 3_000 + 125);
+
+        let mut failed = sample_trial();
+        failed.status = TrialStatus::Failed;
+        assert_eq!(failed.risk_score_bps(), BASIS_POINTS);
+    }
+
+    #[test]
+    fn test_approval_probability_is_scored_in_basis_points() {
+        // BLA with breakthrough designation, two deficiencies and an 8-2 committee vote
+        assert_eq!(sample_application().approval_probability_bps(), 5_000 + 1_500 + 2_000 - 400 + 900);
+
+        // A committee that never voted says nothing either way
+        let mut no_votes = sample_application();
+        if let Some(ac_data) = no_votes.advisory_committee_meeting.as_mut() {
+            ac_data.votes_yes = 0;
+            ac_data.votes_no = 0;
+        }
+        assert_eq!(no_votes.approval_probability_bps(), 8_100);
+    }
+
+    #[test]
+    fn test_invalidation_risk_is_scored_in_basis_points() {
+        let ten_years = 10 * SECONDS_PER_YEAR;
+
+        // Ten years old, two litigations and 20 claims
+        assert_eq!(sample_patent(PatentStatus::Granted).invalidation_risk_bps(ten_years), 2_300);
+        assert_eq!(sample_patent(PatentStatus::Pending).invalidation_risk_bps(ten_years), 6_300);
+        assert_eq!(
+            sample_patent(PatentStatus::Invalidated).invalidation_risk_bps(ten_years),
+            BASIS_POINTS
+        );
+    }
+
+    #[test]
+    fn test_assessment_multiplier_is_fixed_point() {
+        let assessment = RiskAssessment {
+            oracle_data: MultiOracleData {
+                price_data: Some(PythOracleData {
+                    feed_id: [0; 32],
+                    price: 100,
+                    conf: 2,
+                    expo: 0,
+                    publish_time: 0,
+                }),
+                bioscience_data: None,
+                switchboard_data: None,
+                consensus_timestamp: 0,
+                data_sources_count: 1,
+            },
+            clinical_trial: Some(sample_trial()),
+            regulatory: None,
+            intellectual_property: None,
         };
 
-        risk.min(1.0)
+        let inputs = assessment.price(&CoverageType::ClinicalTrialFailure, 500, 0).unwrap();
+        // 1.002x market loading, then 1 + 0.9125 / 2 for the trial
+        assert_eq!(inputs.risk_multiplier_bps, 10_020 * 14_562 / 10_000);
+        assert_eq!(inputs.trial_risk_score_bps, Some(9_125));
+        assert_eq!(inputs.base_rate_bps, 500);
+
+        // The subject data must match the risk being insured
+        assert!(assessment.price(&CoverageType::IpInvalidation, 500, 0).is_err());
     }
-}
\ No newline at end of file
+}
@@ -7,6 +7,7 @@ pub use switchboard_oracle::*;
 pub use bioscience_oracle::*;
 
 use anchor_lang::prelude::*;
+use crate::state::BASIS_POINTS;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct MultiOracleData {
@@ -18,29 +19,29 @@ pub struct MultiOracleData {
 }
 
 impl MultiOracleData {
-    pub fn new() -> Self {
-        Self {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
             price_data: None,
             bioscience_data: None,
             switchboard_data: None,
-            consensus_timestamp: Clock::get().unwrap().unix_timestamp,
+            consensus_timestamp: Clock::get()?.unix_timestamp,
             data_sources_count: 0,
-        }
+        })
     }
 
     pub fn add_price_data(&mut self, data: PythOracleData) {
         self.price_data = Some(data);
-        self.data_sources_count += 1;
+        self.data_sources_count = self.data_sources_count.saturating_add(1);
     }
 
     pub fn add_bioscience_data(&mut self, data: BioScienceOracleData) {
         self.bioscience_data = Some(data);
-        self.data_sources_count += 1;
+        self.data_sources_count = self.data_sources_count.saturating_add(1);
     }
 
     pub fn add_switchboard_data(&mut self, data: SwitchboardOracleData) {
         self.switchboard_data = Some(data);
-        self.data_sources_count += 1;
+        self.data_sources_count = self.data_sources_count.saturating_add(1);
     }
 
     pub fn has_consensus(&self, min_sources: u8) -> bool {
@@ -65,29 +66,114 @@ impl MultiOracleData {
         false
     }
 
-    pub fn get_risk_multiplier(&self) -> f64 {
-        let mut multiplier = 1.0;
+    /// Market and trial-status loading, in basis points on top of 1x
+    pub fn risk_multiplier_bps(&self) -> u32 {
+        let mut multiplier_bps = BASIS_POINTS as u64;
 
-        // Factor in market volatility from price data
+        // Factor in market volatility from price data: up to 10% of the confidence ratio
         if let Some(ref price_data) = self.price_data {
-            let confidence_ratio = (price_data.conf as f64) / (price_data.price.abs() as f64);
-            multiplier += confidence_ratio * 0.1; // Up to 10% increase based on volatility
+            let volatility_bps = (price_data.conf as u128)
+                .saturating_mul(1_000)
+                .checked_div(price_data.price.unsigned_abs() as u128)
+                .unwrap_or(BASIS_POINTS as u128); // No price at all is as volatile as it gets
+            multiplier_bps = multiplier_bps.saturating_add(volatility_bps.min(u64::MAX as u128) as u64);
         }
 
         // Factor in trial risk from bioscience data
         if let Some(ref bio_data) = self.bioscience_data {
-            match bio_data.trial_status {
-                crate::oracles::TrialStatus::Active => multiplier += 0.05,
-                crate::oracles::TrialStatus::Paused => multiplier += 0.15,
-                crate::oracles::TrialStatus::Failed => multiplier += 0.5,
-                _ => {}
+            multiplier_bps += match bio_data.trial_status {
+                crate::oracles::TrialStatus::Active => 500,
+                crate::oracles::TrialStatus::Paused => 1_500,
+                crate::oracles::TrialStatus::Failed => 5_000,
+                _ => 0,
+            };
+        }
+
+        multiplier_bps.min(2 * BASIS_POINTS as u64) as u32 // Cap at 2x
+    }
+}
+
+/// Oracle-attested risk inputs for the trial, application or patent behind a policy
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RiskAssessment {
+    pub oracle_data: MultiOracleData,
+    pub clinical_trial: Option<ClinicalTrialData>,
+    pub regulatory: Option<RegulatoryData>,
+    pub intellectual_property: Option<IntellectualPropertyData>,
+}
+
+impl RiskAssessment {
+    /// Derive the premium multiplier (in basis points) and the audit record for a coverage type
+    pub fn price(
+        &self,
+        coverage_type: &crate::state::coverage_account::CoverageType,
+        base_rate_bps: u16,
+        now: i64,
+    ) -> Result<crate::state::PricingInputs> {
+        use crate::state::coverage_account::CoverageType;
+        use crate::state::MAX_RISK_MULTIPLIER_BPS;
+        use anchor_lang::solana_program::hash::hash;
+
+        let mut multiplier_bps = self.oracle_data.risk_multiplier_bps() as u64;
+        let mut trial_risk_score_bps = None;
+        let mut approval_probability_bps = None;
+        let mut invalidation_risk_bps = None;
+        let mut subject_id = String::new();
+
+        // The subject data must match the risk being insured
+        match coverage_type {
+            CoverageType::ClinicalTrialFailure => {
+                let trial = self.clinical_trial.as_ref()
+                    .ok_or(crate::errors::InsuranceError::InvalidOracleData)?;
+                let risk_bps = trial.risk_score_bps();
+                // Up to 50% increase for the riskiest trials
+                multiplier_bps = apply_loading(multiplier_bps, risk_bps);
+                trial_risk_score_bps = Some(risk_bps);
+                subject_id = trial.nct_id.clone();
+            }
+            CoverageType::RegulatoryRejection => {
+                let application = self.regulatory.as_ref()
+                    .ok_or(crate::errors::InsuranceError::InvalidOracleData)?;
+                let probability_bps = application.approval_probability_bps();
+                // Up to 50% increase for likely rejections
+                multiplier_bps = apply_loading(multiplier_bps, BASIS_POINTS - probability_bps);
+                approval_probability_bps = Some(probability_bps);
+                subject_id = application.application_id.clone();
             }
+            CoverageType::IpInvalidation => {
+                let patent = self.intellectual_property.as_ref()
+                    .ok_or(crate::errors::InsuranceError::InvalidOracleData)?;
+                let risk_bps = patent.invalidation_risk_bps(now);
+                // Up to 50% increase for fragile patents
+                multiplier_bps = apply_loading(multiplier_bps, risk_bps);
+                invalidation_risk_bps = Some(risk_bps);
+                subject_id = patent.patent_number.clone();
+            }
+            _ => {}
         }
 
-        multiplier.min(2.0) // Cap at 2x
+        let risk_multiplier_bps = multiplier_bps
+            .max(BASIS_POINTS as u64)
+            .min(MAX_RISK_MULTIPLIER_BPS as u64) as u32;
+
+        Ok(crate::state::PricingInputs {
+            base_rate_bps,
+            risk_multiplier_bps,
+            trial_risk_score_bps,
+            approval_probability_bps,
+            invalidation_risk_bps,
+            subject_id_hash: hash(subject_id.as_bytes()).to_bytes(),
+            oracle_timestamp: self.oracle_data.consensus_timestamp,
+        })
     }
 }
 
+/// Scale a multiplier by 1 + risk / 2, both in basis points
+fn apply_loading(multiplier_bps: u64, risk_bps: u16) -> u64 {
+    let loading_bps = BASIS_POINTS as u64 + risk_bps.min(BASIS_POINTS) as u64 / 2;
+    multiplier_bps.saturating_mul(loading_bps) / BASIS_POINTS as u64
+}
+
 // Oracle aggregation traits
 pub trait OracleProvider {
     fn get_latest_data(&self) -> Result<Box<dyn OracleData>>;
@@ -97,7 +183,7 @@ pub trait OracleProvider {
 
 pub trait OracleData {
     fn get_timestamp(&self) -> i64;
-    fn is_fresh(&self, max_age: i64) -> bool;
+    fn is_fresh(&self, max_age: i64) -> Result<bool>;
     fn get_data_hash(&self) -> [u8; 32];
 }
 
@@ -107,7 +193,7 @@ impl OracleData for PythOracleData {
         self.publish_time
     }
 
-    fn is_fresh(&self, max_age: i64) -> bool {
+    fn is_fresh(&self, max_age: i64) -> Result<bool> {
         self.is_price_fresh(max_age)
     }
 
@@ -125,7 +211,7 @@ impl OracleData for BioScienceOracleData {
         self.last_updated
     }
 
-    fn is_fresh(&self, max_age: i64) -> bool {
+    fn is_fresh(&self, max_age: i64) -> Result<bool> {
         self.is_data_fresh(max_age)
     }
 
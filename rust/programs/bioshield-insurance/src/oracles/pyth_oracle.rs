@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2};
+use pyth_solana_receiver_sdk::price_update::{get_feed_id_from_hex, PriceUpdateV2, VerificationLevel};
 use std::str::FromStr;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -37,15 +37,15 @@ impl PythOracleData {
         Ok(price * expo)
     }
 
-    pub fn is_price_fresh(&self, max_age_seconds: i64) -> bool {
-        let current_time = Clock::get().unwrap().unix_timestamp;
-        current_time - self.publish_time <= max_age_seconds
+    pub fn is_price_fresh(&self, max_age_seconds: i64) -> Result<bool> {
+        let current_time = Clock::get()?.unix_timestamp;
+        Ok(current_time.saturating_sub(self.publish_time) <= max_age_seconds)
     }
 
-    pub fn verify_confidence(&self, max_confidence_pct: f64) -> bool {
-        let price_abs = self.price.abs() as f64;
-        let confidence_ratio = (self.conf as f64) / price_abs;
-        confidence_ratio <= max_confidence_pct / 100.0
+    pub fn verify_confidence(&self, max_confidence_bps: u16) -> bool {
+        // conf / |price| <= bps / 10_000, cross-multiplied to stay in integers
+        (self.conf as u128).saturating_mul(crate::state::BASIS_POINTS as u128) <=
+            (self.price.unsigned_abs() as u128).saturating_mul(max_confidence_bps as u128)
     }
 }
 
@@ -93,7 +93,7 @@ pub enum DataSource {
 }
 
 impl BioScienceOracleData {
-    pub fn should_trigger_payout(&self, trigger_conditions: &super::TriggerConditions) -> bool {
+    pub fn should_trigger_payout(&self, trigger_conditions: &crate::state::TriggerConditions) -> bool {
         // Check clinical trial failure
         if trigger_conditions.clinical_trial_failure {
             match self.trial_status {
@@ -120,9 +120,9 @@ impl BioScienceOracleData {
         false
     }
 
-    pub fn is_data_fresh(&self, max_age_seconds: i64) -> bool {
-        let current_time = Clock::get().unwrap().unix_timestamp;
-        current_time - self.last_updated <= max_age_seconds
+    pub fn is_data_fresh(&self, max_age_seconds: i64) -> Result<bool> {
+        let current_time = Clock::get()?.unix_timestamp;
+        Ok(current_time.saturating_sub(self.last_updated) <= max_age_seconds)
     }
 }
 
@@ -167,7 +167,7 @@ pub fn validate_pyth_price_update(
     price_update: &PriceUpdateV2,
     expected_feed_id: &[u8; 32],
     max_age_seconds: i64,
-    max_confidence_pct: f64,
+    max_confidence_bps: u16,
 ) -> Result<PythOracleData> {
     // Verify feed ID
     require!(
@@ -175,6 +175,12 @@ pub fn validate_pyth_price_update(
         crate::errors::InsuranceError::InvalidOracleFeed
     );
 
+    // Partially verified updates carry too few guardian signatures to be trusted
+    require!(
+        price_update.verification_level == VerificationLevel::Full,
+        crate::errors::InsuranceError::PriceUpdateNotVerified
+    );
+
     // Create oracle data
     let oracle_data = PythOracleData {
         feed_id: price_update.price_message.feed_id,
@@ -184,21 +190,84 @@ pub fn validate_pyth_price_update(
         publish_time: price_update.price_message.publish_time,
     };
 
+    // Amounts are only ever converted at a positive USD price
+    require!(
+        oracle_data.price > 0,
+        crate::errors::InsuranceError::InvalidOracleData
+    );
+
     // Verify freshness
     require!(
-        oracle_data.is_price_fresh(max_age_seconds),
+        oracle_data.is_price_fresh(max_age_seconds)?,
         crate::errors::InsuranceError::StaleOracleData
     );
 
     // Verify confidence
     require!(
-        oracle_data.verify_confidence(max_confidence_pct),
+        oracle_data.verify_confidence(max_confidence_bps),
         crate::errors::InsuranceError::LowOracleConfidence
     );
 
     Ok(oracle_data)
 }
 
+/// Convert a settlement-denominated premium into LIVES using validated Pyth prices
+pub fn convert_premium_to_lives(
+    pool: &crate::state::InsurancePool,
+    settlement_amount: u64,
+    lives_price_update: Option<&PriceUpdateV2>,
+    settlement_price_update: Option<&PriceUpdateV2>,
+) -> Result<u64> {
+    price_in_asset(
+        pool,
+        settlement_amount,
+        &pool.lives_token_mint,
+        lives_price_update,
+        settlement_price_update,
+    )
+}
+
+/// Convert a settlement amount into one of the pool's other accepted assets using
+/// validated Pyth prices
+pub fn price_in_asset(
+    pool: &crate::state::InsurancePool,
+    settlement_amount: u64,
+    asset_mint: &Pubkey,
+    asset_price_update: Option<&PriceUpdateV2>,
+    settlement_price_update: Option<&PriceUpdateV2>,
+) -> Result<u64> {
+    use crate::state::{MAX_PRICE_AGE_SECONDS, MAX_PRICE_CONFIDENCE_BPS};
+
+    let (asset_feed_id, asset_decimals) = pool.asset_price_feed(asset_mint)?;
+    let asset_price = validate_pyth_price_update(
+        asset_price_update.ok_or(crate::errors::InsuranceError::OracleNotConfigured)?,
+        &asset_feed_id,
+        MAX_PRICE_AGE_SECONDS,
+        MAX_PRICE_CONFIDENCE_BPS,
+    )?;
+
+    // USD stablecoins settle at $1.00
+    let settlement_price = if pool.is_settlement_pegged() {
+        (1, 0)
+    } else {
+        let price = validate_pyth_price_update(
+            settlement_price_update.ok_or(crate::errors::InsuranceError::OracleNotConfigured)?,
+            &pool.settlement_price_feed_id,
+            MAX_PRICE_AGE_SECONDS,
+            MAX_PRICE_CONFIDENCE_BPS,
+        )?;
+        (price.price, price.expo)
+    };
+
+    crate::utils::calculations::convert_settlement_to_asset(
+        settlement_amount,
+        pool.settlement_decimals,
+        settlement_price,
+        asset_decimals,
+        (asset_price.price, asset_price.expo),
+    )
+}
+
 // Common feed IDs for bioscience-related assets
 pub mod feed_ids {
     pub const SOL_USD: &str = "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
@@ -213,6 +282,25 @@ pub mod feed_ids {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pyth_solana_receiver_sdk::price_update::PriceFeedMessage;
+
+    fn price_update(verification_level: VerificationLevel, price: i64) -> PriceUpdateV2 {
+        PriceUpdateV2 {
+            write_authority: Pubkey::new_unique(),
+            verification_level,
+            price_message: PriceFeedMessage {
+                feed_id: [7; 32],
+                price,
+                conf: 0,
+                exponent: -8,
+                publish_time: 1_700_000_000,
+                prev_publish_time: 1_699_999_999,
+                ema_price: price,
+                ema_conf: 0,
+            },
+            posted_slot: 1,
+        }
+    }
 
     #[test]
     fn test_oracle_data_creation() {
@@ -225,7 +313,9 @@ mod tests {
         ).unwrap();
 
         assert_eq!(oracle_data.get_price_normalized().unwrap(), 100.0);
-        assert!(oracle_data.verify_confidence(1.0)); // 0.5% confidence is < 1%
+        assert!(oracle_data.verify_confidence(100)); // 0.5% confidence is < 1%
+        assert!(oracle_data.verify_confidence(50));
+        assert!(!oracle_data.verify_confidence(49));
     }
 
     #[test]
@@ -252,4 +342,36 @@ mod tests {
 
         assert!(oracle_data.should_trigger_payout(&trigger_conditions));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_partially_verified_price_update_is_rejected() {
+        let update = price_update(VerificationLevel::Partial { num_signatures: 5 }, 100_000_000);
+
+        assert_eq!(
+            validate_pyth_price_update(&update, &[7; 32], 60, 200).err(),
+            Some(crate::errors::InsuranceError::PriceUpdateNotVerified.into())
+        );
+    }
+
+    #[test]
+    fn test_non_positive_price_is_rejected() {
+        for price in [0, -100_000_000] {
+            let update = price_update(VerificationLevel::Full, price);
+
+            assert_eq!(
+                validate_pyth_price_update(&update, &[7; 32], 60, 200).err(),
+                Some(crate::errors::InsuranceError::InvalidOracleData.into())
+            );
+        }
+    }
+
+    #[test]
+    fn test_price_update_for_another_feed_is_rejected() {
+        let update = price_update(VerificationLevel::Full, 100_000_000);
+
+        assert_eq!(
+            validate_pyth_price_update(&update, &[8; 32], 60, 200).err(),
+            Some(crate::errors::InsuranceError::InvalidOracleFeed.into())
+        );
+    }
+}
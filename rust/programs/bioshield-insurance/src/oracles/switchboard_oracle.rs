@@ -35,9 +35,9 @@ impl SwitchboardOracleData {
             .map_err(|_| ProgramError::InvalidAccountData.into())
     }
 
-    pub fn is_data_fresh(&self, max_age_seconds: i64) -> bool {
-        let current_time = Clock::get().unwrap().unix_timestamp;
-        current_time - self.latest_timestamp <= max_age_seconds
+    pub fn is_data_fresh(&self, max_age_seconds: i64) -> Result<bool> {
+        let current_time = Clock::get()?.unix_timestamp;
+        Ok(current_time.saturating_sub(self.latest_timestamp) <= max_age_seconds)
     }
 
     pub fn get_confidence_score(&self) -> f64 {
@@ -1,10 +1,18 @@
 use anchor_lang::prelude::*;
+use crate::state::{ACCOUNT_RESERVED_BYTES, CLAIM_ACCOUNT_VERSION, MAX_REJECTION_REASON_LEN};
+use crate::state::legacy::{ClaimAccountV0, BASELINE_CLAIM_SIZE};
+use crate::utils::migration::Versioned;
+
+/// Recorded on claims the oracle data doesn't support
+pub const ORACLE_REJECTION_REASON: &str = "Oracle verification failed";
 
 #[account]
+#[derive(InitSpace)]
 pub struct ClaimAccount {
     pub coverage: Pubkey,
     pub claimant: Pubkey,
     pub claim_amount: u64,
+    pub reserved_amount: u64,
     pub claim_type: ClaimType,
     pub evidence_hash: [u8; 32],
     pub oracle_request_id: Option<[u8; 32]>,
@@ -12,34 +20,49 @@ pub struct ClaimAccount {
     pub submitted_at: i64,
     pub processed_at: Option<i64>,
     pub processor: Option<Pubkey>,
+    #[max_len(MAX_REJECTION_REASON_LEN)]
     pub rejection_reason: Option<String>,
     pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; ACCOUNT_RESERVED_BYTES], // new fields are carved out of this
 }
 
 impl ClaimAccount {
     pub const SIZE: usize = 8 + // discriminator
-        32 + // coverage
-        32 + // claimant
-        8 + // claim_amount
-        1 + 32 + // claim_type
-        32 + // evidence_hash
-        1 + 32 + // oracle_request_id (Option)
-        1 + // status
-        8 + // submitted_at
-        1 + 8 + // processed_at (Option)
-        1 + 32 + // processor (Option)
-        1 + 4 + 200 + // rejection_reason (Option<String>)
-        1; // bump
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    /// Whether the claim still holds its policy open
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, ClaimStatus::Pending | ClaimStatus::UnderReview)
+    }
+}
+
+impl Versioned for ClaimAccount {
+    const VERSION: u8 = CLAIM_ACCOUNT_VERSION;
+
+    const BASELINE_SIZE: usize = BASELINE_CLAIM_SIZE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn from_baseline(data: &mut &[u8]) -> Result<Self> {
+        Ok(ClaimAccountV0::deserialize(data)?.into_current())
+    }
+
+    fn upgrade(&mut self, _from_version: u8) {
+        self.version = CLAIM_ACCOUNT_VERSION;
+    }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum ClaimType {
     FullCoverage,
     PartialCoverage { percentage: u8 },
     Milestone { milestone_id: u32 },
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum ClaimStatus {
     Pending,
     UnderReview,
@@ -1,53 +1,126 @@
 use anchor_lang::prelude::*;
 use crate::state::oracle_data::TriggerConditions;
+use crate::state::{
+    ACCOUNT_RESERVED_BYTES, COVERAGE_ACCOUNT_VERSION, MAX_METADATA_URI_LEN, MAX_COVERAGE_CATEGORY_LEN,
+};
+
+pub const COVERAGE_RESERVED_BYTES: usize = ACCOUNT_RESERVED_BYTES - 8; // less ceded_coverage
+use crate::state::legacy::{CoverageAccountV0, BASELINE_COVERAGE_SIZE};
+use crate::utils::migration::Versioned;
 
 #[account]
+#[derive(InitSpace)]
 pub struct CoverageAccount {
     pub insured: Pubkey,
     pub pool: Pubkey,
     pub coverage_amount: u64,
     pub premium_paid: u64,
+    pub premium_earned: u64,
+    pub premium_mint: Pubkey,
     pub coverage_type: CoverageType,
     pub trigger_conditions: TriggerConditions,
     pub start_time: i64,
     pub end_time: i64,
     pub status: CoverageStatus,
     pub claims_made: u32,
+    pub pending_claims: u32,
     pub total_claimed: u64,
+    #[max_len(MAX_METADATA_URI_LEN)]
     pub metadata_uri: String,
+    pub pricing_inputs: PricingInputs,
+    pub pricing_version: u8,
     pub bump: u8,
+    pub version: u8,
+    pub ceded_coverage: u64, // since version 2; exposure carried by the pool's reinsurer
+    pub reserved: [u8; COVERAGE_RESERVED_BYTES], // new fields are carved out of this
 }
 
 impl CoverageAccount {
     pub const SIZE: usize = 8 + // discriminator
-        32 + // insured
-        32 + // pool
-        8 + // coverage_amount
-        8 + // premium_paid
-        1 + 32 + // coverage_type (enum + data)
-        TriggerConditions::SIZE + // trigger_conditions
-        8 + // start_time
-        8 + // end_time
-        1 + // status
-        4 + // claims_made
-        8 + // total_claimed
-        4 + 200 + // metadata_uri (string)
-        1; // bump
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    /// Exposure the pool kept for itself when the policy was written
+    pub fn retained_coverage(&self) -> u64 {
+        self.coverage_amount.saturating_sub(self.ceded_coverage)
+    }
+}
+
+impl Versioned for CoverageAccount {
+    const VERSION: u8 = COVERAGE_ACCOUNT_VERSION;
+
+    const BASELINE_SIZE: usize = BASELINE_COVERAGE_SIZE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn from_baseline(data: &mut &[u8]) -> Result<Self> {
+        Ok(CoverageAccountV0::deserialize(data)?.into_current())
+    }
+
+    fn upgrade(&mut self, from_version: u8) {
+        if from_version < 2 {
+            // Carved out of zeroed reserved space: older policies release no ceded exposure
+            self.ceded_coverage = 0;
+            // Version 2 also sized custom categories and trigger conditions to their bounds,
+            // which the realloc has made room for
+        }
+        self.version = COVERAGE_ACCOUNT_VERSION;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub enum RiskCategory {
+    Low,
+    Medium,
+    High,
+    VeryHigh,
+}
+
+/// Risk inputs used to price a coverage, kept for audit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub struct PricingInputs {
+    pub base_rate_bps: u16,
+    pub risk_multiplier_bps: u32,
+    pub trial_risk_score_bps: Option<u16>,
+    pub approval_probability_bps: Option<u16>,
+    pub invalidation_risk_bps: Option<u16>,
+    pub subject_id_hash: [u8; 32],
+    pub oracle_timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum CoverageType {
     ClinicalTrialFailure,
     RegulatoryRejection,
     IpInvalidation,
     ResearchInfrastructure,
-    Custom { category: String },
+    Custom {
+        #[max_len(MAX_COVERAGE_CATEGORY_LEN)]
+        category: String,
+    },
+}
+
+impl CoverageType {
+    /// Bit identifying this line of business in a pool's `coverage_types`
+    pub fn mask(&self) -> u8 {
+        match self {
+            CoverageType::ClinicalTrialFailure => 1 << 0,
+            CoverageType::RegulatoryRejection => 1 << 1,
+            CoverageType::IpInvalidation => 1 << 2,
+            CoverageType::ResearchInfrastructure => 1 << 3,
+            CoverageType::Custom { .. } => 1 << 4,
+        }
+    }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub const ALL_COVERAGE_TYPES: u8 = 0b1_1111;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum CoverageStatus {
     Active,
     Expired,
     Exhausted,
     Cancelled,
+    Listed,
 }
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{
+    LivesDiscountSchedule, PricingCurve, WithdrawalConfig, StakingConfig, Role, BASIS_POINTS,
+};
+
+pub const MAX_PROPOSAL_ACTIONS: usize = 4;
+pub const MAX_VOTE_CHECKPOINTS: usize = 8;
+
+/// Token-holder governance of one pool; also the authority over voters' escrowed tokens
+#[account]
+#[derive(InitSpace)]
+pub struct Governance {
+    pub pool: Pubkey,
+    pub config: GovernanceConfig,
+    pub proposal_count: u64,
+    pub bump: u8,
+}
+
+impl Governance {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct GovernanceConfig {
+    pub voting_period: i64,
+    pub quorum_votes: u64,
+    pub threshold_bps: u16, // share of votes cast that must be in favour
+    pub min_proposal_weight: u64,
+    pub lives_vote_weight_bps: u16, // voting weight of one LIVES unit relative to one SHIELD unit
+}
+
+/// A change to pool parameters carried by a proposal or a timelock operation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub enum ParameterChange {
+    PoolFee { fee_basis_points: u16 },
+    CoverageLimits { min_coverage_amount: u64, max_coverage_amount: u64 },
+    Oracle { oracle_address: Pubkey },
+    LivesDiscount { schedule: LivesDiscountSchedule },
+    Pause { paused: bool },
+    CapacityConfig { pricing_curve: PricingCurve, max_utilization_bps: u32 },
+    WithdrawalConfig { config: WithdrawalConfig },
+    StakingConfig { config: StakingConfig },
+    Marketplace { treasury: Pubkey, marketplace_fee_basis_points: u16 },
+    // Applied to the pool's roles rather than the pool itself
+    GrantRole { member: Pubkey, role: Role },
+    RevokeRole { member: Pubkey, role: Role },
+    // Nominate a new authority, or clear the nomination with `None`
+    Authority { new_authority: Option<Pubkey> },
+    // Accept a nomination on behalf of whoever queued it, e.g. the pool's governance
+    AcceptAuthority,
+    // Stop ceding new policies to the reinsurer; ceded ones run off under the treaty
+    TerminateTreaty,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub enum ProposalStatus {
+    Voting,
+    Queued, // passed, and handed to the pool's timelock
+    Defeated,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub governance: Pubkey,
+    pub proposer: Pubkey,
+    pub id: u64,
+    #[max_len(MAX_PROPOSAL_ACTIONS)]
+    pub actions: Vec<ParameterChange>,
+    pub created_at: i64, // voting weight is snapshotted at this time
+    pub voting_ends_at: i64,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub status: ProposalStatus,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    /// Quorum of votes cast and the approval threshold among them
+    pub fn has_passed(&self, config: &GovernanceConfig) -> Result<bool> {
+        let votes_cast = self.yes_votes
+            .checked_add(self.no_votes)
+            .ok_or(InsuranceError::InvalidProposal)?;
+        if votes_cast == 0 || votes_cast < config.quorum_votes {
+            return Ok(false);
+        }
+
+        let required_yes = (votes_cast as u128)
+            .checked_mul(config.threshold_bps as u128)
+            .ok_or(InsuranceError::InvalidProposal)?
+            / BASIS_POINTS as u128;
+
+        Ok(self.yes_votes as u128 > required_yes)
+    }
+}
+
+/// SHIELD and LIVES a holder has escrowed for voting, with a history of their weight
+#[account]
+#[derive(InitSpace)]
+pub struct VoterRecord {
+    pub governance: Pubkey,
+    pub owner: Pubkey,
+    pub shield_deposited: u64,
+    pub lives_deposited: u64,
+    #[max_len(MAX_VOTE_CHECKPOINTS)]
+    pub checkpoints: Vec<WeightCheckpoint>,
+    pub bump: u8,
+}
+
+impl VoterRecord {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    pub fn current_weight(&self) -> u64 {
+        self.checkpoints.last().map(|checkpoint| checkpoint.weight).unwrap_or(0)
+    }
+
+    /// Weight held strictly before `timestamp`. Once the history has rolled past it the
+    /// weight is unknown and counts as zero, so tokens can never be counted twice.
+    pub fn weight_at(&self, timestamp: i64) -> u64 {
+        match self.checkpoints.iter().rposition(|checkpoint| checkpoint.timestamp < timestamp) {
+            Some(index) => self.checkpoints[index].weight,
+            None => 0,
+        }
+    }
+
+    /// Record the weight after a deposit or withdrawal
+    pub fn push_checkpoint(&mut self, timestamp: i64, weight: u64) {
+        if let Some(last) = self.checkpoints.last_mut() {
+            if last.timestamp == timestamp {
+                last.weight = weight;
+                return;
+            }
+        }
+        if self.checkpoints.len() == MAX_VOTE_CHECKPOINTS {
+            self.checkpoints.remove(0);
+        }
+        self.checkpoints.push(WeightCheckpoint { timestamp, weight });
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct WeightCheckpoint {
+    pub timestamp: i64,
+    pub weight: u64,
+}
+
+/// One voter's ballot on one proposal; its existence prevents voting twice
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub support: bool,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+}
@@ -1,35 +1,316 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use crate::errors::InsuranceError;
+use crate::state::withdrawal::{WithdrawalConfig, WithdrawalQueue};
+use crate::state::tranche::{Tranche, MAX_TRANCHES};
+use crate::state::staking::{PremiumRewards, StakingConfig, LivesEmissions};
+use crate::state::governance::ParameterChange;
+use crate::state::timelock::TimelockConfig;
+use crate::state::wind_down::WindDown;
+use crate::state::{
+    ACCOUNT_RESERVED_BYTES, MAX_COVERAGE_TOKENS, MIN_COVERAGE_TOKENS, POOL_ACCOUNT_VERSION,
+};
+use crate::state::legacy::{InsurancePoolV0, BASELINE_POOL_SIZE, LEGACY_POOL_ID};
+use crate::utils::migration::Versioned;
+
+/// Reserved bytes left on the pool after the fields carved out since versioning began
+pub const POOL_RESERVED_BYTES: usize =
+    ACCOUNT_RESERVED_BYTES - WindDown::INIT_SPACE - 8 - 1; // less wind_down, assumed_coverage and treaty_terminated
 
 #[account]
+#[derive(InitSpace)]
 pub struct InsurancePool {
     pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub creator: Pubkey, // PDA seed, fixed at creation so the authority can rotate
+    pub pool_id: u64,
+    pub coverage_types: u8,
     pub lives_token_mint: Pubkey,
     pub shield_token_mint: Pubkey,
+    #[max_len(MAX_TRANCHES)]
+    pub tranches: Vec<Tranche>,
+    pub settlement_mint: Pubkey,
+    pub settlement_decimals: u8,
+    pub lives_decimals: u8,
+    pub settlement_price_feed_id: [u8; 32],
+    pub lives_price_feed_id: [u8; 32],
+    pub lives_discount: LivesDiscountSchedule,
+    #[max_len(MAX_POOL_ASSETS)]
+    pub asset_ledgers: Vec<AssetLedger>,
     pub total_value_locked: u64,
     pub total_coverage_amount: u64,
     pub total_claims_paid: u64,
+    pub reserved_claims: u64,
+    pub unearned_premiums: u64,
+    pub premium_rewards: PremiumRewards,
+    pub staking_config: StakingConfig,
+    pub lives_emissions: LivesEmissions,
+    pub reinsurance_treaty: Option<Pubkey>,
+    pub ceded_premiums: u64,
+    pub reinsurance_recoveries: u64,
+    pub governance: Option<Pubkey>,
+    pub timelock: TimelockConfig,
+    pub timelock_operation_count: u64,
     pub pool_fee_basis_points: u16,
     pub min_coverage_amount: u64,
     pub max_coverage_amount: u64,
     pub oracle_address: Pubkey,
+    pub treasury: Pubkey,
+    pub marketplace_fee_basis_points: u16,
+    pub pricing_curve: PricingCurve,
+    pub max_utilization_bps: u32,
+    pub min_premium: u64,
+    pub withdrawal_config: WithdrawalConfig,
+    pub withdrawal_queue: WithdrawalQueue,
     pub created_at: i64,
     pub is_paused: bool,
     pub bump: u8,
+    pub version: u8,
+    pub wind_down: WindDown, // since version 2
+    pub assumed_coverage: u64, // since version 4; exposure other pools' treaties cede to this one
+    pub treaty_terminated: bool, // since version 5; the treaty only runs off policies already ceded
+    pub reserved: [u8; POOL_RESERVED_BYTES], // new fields are carved out of this
 }
 
 impl InsurancePool {
     pub const SIZE: usize = 8 + // discriminator
-        32 + // authority
-        32 + // lives_token_mint
-        32 + // shield_token_mint
-        8 + // total_value_locked
-        8 + // total_coverage_amount
-        8 + // total_claims_paid
-        2 + // pool_fee_basis_points
-        8 + // min_coverage_amount
-        8 + // max_coverage_amount
-        32 + // oracle_address
-        8 + // created_at
-        1 + // is_paused
-        1; // bump
+        Self::INIT_SPACE; // fields, bounded by their max_len
+}
+
+impl Versioned for InsurancePool {
+    const VERSION: u8 = POOL_ACCOUNT_VERSION;
+
+    const BASELINE_SIZE: usize = BASELINE_POOL_SIZE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn from_baseline(data: &mut &[u8]) -> Result<Self> {
+        Ok(InsurancePoolV0::deserialize(data)?.into_current())
+    }
+
+    fn upgrade(&mut self, from_version: u8) {
+        if from_version < 2 {
+            // Policies written so far were never counted, so the run-off falls back to time
+            self.wind_down = WindDown::default();
+        }
+        // Version 3 made room for a third asset ledger; the realloc is all it takes
+        if from_version < 4 {
+            // Cessions were not counted before, so only exposure ceded from now on
+            // holds back this pool's redemption
+            self.assumed_coverage = 0;
+        }
+        if from_version < 5 {
+            self.treaty_terminated = false;
+        }
+        self.version = POOL_ACCOUNT_VERSION;
+    }
+}
+
+impl InsurancePool {
+    /// Pool ID seed of the pool's PDA. Legacy pools were seeded by their authority alone,
+    /// and an empty seed derives the same address, so they keep their accounts.
+    pub fn pool_id_seed(&self) -> Vec<u8> {
+        if self.pool_id == LEGACY_POOL_ID {
+            Vec::new()
+        } else {
+            self.pool_id.to_le_bytes().to_vec()
+        }
+    }
+
+    /// Settlement assets without a price feed are treated as USD stablecoins
+    pub fn is_settlement_pegged(&self) -> bool {
+        self.settlement_price_feed_id == [0u8; 32]
+    }
+
+    /// Vault balance that does not belong to SHIELD holders: claim reserves,
+    /// premiums not yet earned and rewards already credited to stakers
+    pub fn settlement_liabilities(&self) -> u64 {
+        self.reserved_claims
+            .saturating_add(self.unearned_premiums)
+            .saturating_add(self.premium_rewards.rewards_owed)
+    }
+
+    /// PDA vault registered for one of the pool's accepted assets
+    pub fn vault_for(&self, mint: &Pubkey) -> Option<Pubkey> {
+        self.asset_ledgers
+            .iter()
+            .find(|ledger| ledger.mint == *mint)
+            .map(|ledger| ledger.vault)
+    }
+
+    /// Pyth feed and decimals of an accepted asset, or an error for any other mint.
+    /// A zero feed marks a USD stablecoin.
+    pub fn asset_price_feed(&self, mint: &Pubkey) -> Result<([u8; 32], u8)> {
+        if *mint == self.settlement_mint {
+            Ok((self.settlement_price_feed_id, self.settlement_decimals))
+        } else if *mint == self.lives_token_mint {
+            Ok((self.lives_price_feed_id, self.lives_decimals))
+        } else if *mint == native_mint::ID {
+            Ok((SOL_USD_FEED_ID, native_mint::DECIMALS))
+        } else {
+            err!(InsuranceError::InvalidTokenMint)
+        }
+    }
+
+    /// Ledger for one of the pool's accepted assets
+    pub fn ledger_mut(&mut self, mint: &Pubkey) -> Result<&mut AssetLedger> {
+        self.asset_ledgers
+            .iter_mut()
+            .find(|ledger| ledger.mint == *mint)
+            .ok_or_else(|| InsuranceError::VaultNotInitialized.into())
+    }
+
+    /// Coverage limits must sit within the protocol bounds, in this pool's settlement units
+    pub fn check_coverage_limits(&self, min_coverage_amount: u64, max_coverage_amount: u64) -> Result<()> {
+        let unit = 10u64
+            .checked_pow(self.settlement_decimals as u32)
+            .ok_or(InsuranceError::InvalidCoverageAmount)?;
+        let lower_bound = MIN_COVERAGE_TOKENS
+            .checked_mul(unit)
+            .ok_or(InsuranceError::InvalidCoverageAmount)?;
+        let upper_bound = MAX_COVERAGE_TOKENS
+            .checked_mul(unit)
+            .ok_or(InsuranceError::InvalidCoverageAmount)?;
+
+        require!(
+            min_coverage_amount >= lower_bound &&
+            max_coverage_amount <= upper_bound &&
+            min_coverage_amount < max_coverage_amount,
+            InsuranceError::InvalidCoverageAmount
+        );
+        Ok(())
+    }
+
+    /// Checks of a parameter change that depend on the pool it applies to
+    pub fn check_parameter_change(&self, change: &ParameterChange) -> Result<()> {
+        self.check_timelock_covers(change)?;
+        match change {
+            ParameterChange::CoverageLimits { min_coverage_amount, max_coverage_amount } => {
+                self.check_coverage_limits(*min_coverage_amount, *max_coverage_amount)?;
+            }
+            ParameterChange::TerminateTreaty => require!(
+                self.reinsurance_treaty.is_some() && !self.treaty_terminated,
+                InsuranceError::NoActiveTreaty
+            ),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Apply a validated change from a passed proposal or a matured timelock operation
+    pub fn apply_parameter_change(&mut self, change: &ParameterChange, now: i64) -> Result<()> {
+        self.check_parameter_change(change)?;
+
+        match change {
+            ParameterChange::PoolFee { fee_basis_points } => {
+                self.pool_fee_basis_points = *fee_basis_points;
+            }
+            ParameterChange::CoverageLimits { min_coverage_amount, max_coverage_amount } => {
+                self.min_coverage_amount = *min_coverage_amount;
+                self.max_coverage_amount = *max_coverage_amount;
+            }
+            ParameterChange::Oracle { oracle_address } => {
+                self.oracle_address = *oracle_address;
+            }
+            ParameterChange::LivesDiscount { schedule } => {
+                self.lives_discount = schedule.clone();
+            }
+            ParameterChange::Pause { paused } => {
+                self.is_paused = *paused;
+            }
+            ParameterChange::CapacityConfig { pricing_curve, max_utilization_bps } => {
+                self.pricing_curve = pricing_curve.clone();
+                self.max_utilization_bps = *max_utilization_bps;
+            }
+            ParameterChange::WithdrawalConfig { config } => {
+                self.withdrawal_config = config.clone();
+            }
+            ParameterChange::StakingConfig { config } => {
+                // Emissions up to now are owed at the old rate
+                let emission_rate = self.staking_config.lives_emission_per_second;
+                self.lives_emissions.update(emission_rate, now)?;
+                self.staking_config = config.clone();
+            }
+            ParameterChange::Marketplace { treasury, marketplace_fee_basis_points } => {
+                self.treasury = *treasury;
+                self.marketplace_fee_basis_points = *marketplace_fee_basis_points;
+            }
+            ParameterChange::Authority { new_authority } => {
+                if let Some(new_authority) = new_authority {
+                    require!(*new_authority != self.authority, InsuranceError::InvalidAuthority);
+                }
+                self.pending_authority = *new_authority;
+            }
+            ParameterChange::TerminateTreaty => {
+                self.treaty_terminated = true;
+            }
+            ParameterChange::GrantRole { .. } |
+            ParameterChange::RevokeRole { .. } |
+            ParameterChange::AcceptAuthority => {
+                return err!(InsuranceError::InvalidProposal);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Settlement mint, wrapped SOL and LIVES: one vault each
+pub const MAX_POOL_ASSETS: usize = 3;
+
+/// Pyth SOL/USD feed, used to value the wrapped SOL vault
+pub const SOL_USD_FEED_ID: [u8; 32] = [
+    239, 13, 139, 111, 218, 44, 235, 164, 29, 161, 93, 64, 149, 209, 218, 57,
+    42, 13, 47, 142, 208, 198, 199, 188, 15, 76, 250, 200, 194, 128, 181, 109,
+];
+
+/// Per-asset accounting for a PDA-owned pool vault
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct AssetLedger {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub liquidity_deposited: u64,
+    pub liquidity_withdrawn: u64,
+    pub premiums_collected: u64,
+    pub claims_paid: u64,
+    pub bump: u8,
+}
+
+/// Kinked utilization curve applied on top of the base premium rate
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct PricingCurve {
+    pub kink_utilization_bps: u32,
+    pub slope_below_kink_bps: u32,
+    pub slope_above_kink_bps: u32,
+}
+
+/// Discount for paying premiums in LIVES, tiered by the LIVES the insured has staked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct LivesDiscountSchedule {
+    pub base_discount_bps: u16,
+    #[max_len(MAX_DISCOUNT_TIERS)]
+    pub tiers: Vec<DiscountTier>,
+    pub burn_share_bps: u16,
+}
+
+impl LivesDiscountSchedule {
+    /// Best discount the insured qualifies for with `lives_balance` LIVES counted from their stake
+    pub fn discount_bps_for(&self, lives_balance: u64) -> u16 {
+        self.tiers
+            .iter()
+            .filter(|tier| lives_balance >= tier.min_lives_balance)
+            .map(|tier| tier.discount_bps)
+            .fold(self.base_discount_bps, u16::max)
+    }
+}
+
+pub const MAX_DISCOUNT_TIERS: usize = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct DiscountTier {
+    pub min_lives_balance: u64,
+    pub discount_bps: u16,
 }
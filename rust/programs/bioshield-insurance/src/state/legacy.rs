@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use crate::state::coverage_account::{
+    CoverageAccount, CoverageStatus, CoverageType, PricingInputs, COVERAGE_RESERVED_BYTES,
+};
+use crate::state::claim::{ClaimAccount, ClaimStatus, ClaimType};
+use crate::state::insurance_pool::{
+    InsurancePool, LivesDiscountSchedule, PricingCurve, POOL_RESERVED_BYTES, SOL_USD_FEED_ID,
+};
+use crate::state::oracle_data::TriggerConditions;
+use crate::state::staking::{LivesEmissions, PremiumRewards, StakingConfig};
+use crate::state::timelock::TimelockConfig;
+use crate::state::wind_down::WindDown;
+use crate::state::withdrawal::{WithdrawalConfig, WithdrawalQueue};
+use crate::state::{
+    ACCOUNT_RESERVED_BYTES, ALL_COVERAGE_TYPES, BASIS_POINTS, MIN_TIMELOCK_DELAY, SECONDS_PER_DAY,
+};
+
+// Allocated sizes of the layouts deployed before accounts carried a version
+pub const BASELINE_POOL_SIZE: usize = 188;
+pub const BASELINE_COVERAGE_SIZE: usize = 530;
+pub const BASELINE_CLAIM_SIZE: usize = 435;
+
+/// Pools from the single-pool layout were seeded by their authority alone and have no pool ID
+pub const LEGACY_POOL_ID: u64 = u64::MAX;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct InsurancePoolV0 {
+    pub authority: Pubkey,
+    pub lives_token_mint: Pubkey,
+    pub shield_token_mint: Pubkey,
+    pub total_value_locked: u64,
+    pub total_coverage_amount: u64,
+    pub total_claims_paid: u64,
+    pub pool_fee_basis_points: u16,
+    pub min_coverage_amount: u64,
+    pub max_coverage_amount: u64,
+    pub oracle_address: Pubkey,
+    pub created_at: i64,
+    pub is_paused: bool,
+    pub bump: u8,
+}
+
+impl InsurancePoolV0 {
+    /// Baseline pools settled in SOL and had no vaults, so the migrated pool starts paused
+    /// until its vaults are registered
+    pub fn into_current(self) -> InsurancePool {
+        InsurancePool {
+            authority: self.authority,
+            pending_authority: None,
+            creator: self.authority,
+            pool_id: LEGACY_POOL_ID,
+            coverage_types: ALL_COVERAGE_TYPES,
+            lives_token_mint: self.lives_token_mint,
+            shield_token_mint: self.shield_token_mint,
+            tranches: Vec::new(),
+            // Baseline pools took their premiums in lamports
+            settlement_mint: native_mint::ID,
+            settlement_decimals: 9,
+            lives_decimals: 9,
+            settlement_price_feed_id: SOL_USD_FEED_ID,
+            lives_price_feed_id: [0; 32],
+            lives_discount: LivesDiscountSchedule {
+                base_discount_bps: 0,
+                tiers: Vec::new(),
+                burn_share_bps: 0,
+            },
+            asset_ledgers: Vec::new(),
+            total_value_locked: self.total_value_locked,
+            total_coverage_amount: self.total_coverage_amount,
+            total_claims_paid: self.total_claims_paid,
+            reserved_claims: 0,
+            unearned_premiums: 0,
+            premium_rewards: PremiumRewards::default(),
+            staking_config: StakingConfig::default(),
+            lives_emissions: LivesEmissions::default(),
+            reinsurance_treaty: None,
+            ceded_premiums: 0,
+            reinsurance_recoveries: 0,
+            governance: None,
+            timelock: TimelockConfig { delay: MIN_TIMELOCK_DELAY },
+            timelock_operation_count: 0,
+            pool_fee_basis_points: self.pool_fee_basis_points,
+            min_coverage_amount: self.min_coverage_amount,
+            max_coverage_amount: self.max_coverage_amount,
+            oracle_address: self.oracle_address,
+            treasury: self.authority,
+            marketplace_fee_basis_points: 0,
+            // Flat pricing, as before utilization was priced in
+            pricing_curve: PricingCurve {
+                kink_utilization_bps: 8_000,
+                slope_below_kink_bps: 0,
+                slope_above_kink_bps: 0,
+            },
+            max_utilization_bps: BASIS_POINTS as u32,
+            min_premium: 0,
+            // Baseline liquidity could leave at any time
+            withdrawal_config: WithdrawalConfig {
+                cooldown_seconds: 0,
+                epoch_duration: SECONDS_PER_DAY,
+                max_epoch_withdrawal_bps: BASIS_POINTS,
+            },
+            withdrawal_queue: WithdrawalQueue::default(),
+            created_at: self.created_at,
+            is_paused: true,
+            bump: self.bump,
+            version: 0,
+            wind_down: WindDown::default(),
+            assumed_coverage: 0,
+            treaty_terminated: false,
+            reserved: [0; POOL_RESERVED_BYTES],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct CoverageAccountV0 {
+    pub insured: Pubkey,
+    pub pool: Pubkey,
+    pub coverage_amount: u64,
+    pub premium_paid: u64,
+    pub coverage_type: CoverageType,
+    pub trigger_conditions: TriggerConditions,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub status: CoverageStatus,
+    pub claims_made: u32,
+    pub total_claimed: u64,
+    pub metadata_uri: String,
+    pub bump: u8,
+}
+
+impl CoverageAccountV0 {
+    /// Baseline premiums went straight into the pool, so they count as fully earned and
+    /// carry no premium mint: the policy runs to expiry without accrual or refunds
+    pub fn into_current(self) -> CoverageAccount {
+        CoverageAccount {
+            insured: self.insured,
+            pool: self.pool,
+            coverage_amount: self.coverage_amount,
+            premium_paid: self.premium_paid,
+            premium_earned: self.premium_paid,
+            premium_mint: Pubkey::default(),
+            coverage_type: self.coverage_type,
+            trigger_conditions: self.trigger_conditions,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            status: self.status,
+            claims_made: self.claims_made,
+            pending_claims: 0, // counted back as each open claim is migrated
+            total_claimed: self.total_claimed,
+            metadata_uri: self.metadata_uri,
+            pricing_inputs: PricingInputs::default(),
+            pricing_version: 0,
+            bump: self.bump,
+            version: 0,
+            ceded_coverage: 0,
+            reserved: [0; COVERAGE_RESERVED_BYTES],
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ClaimAccountV0 {
+    pub coverage: Pubkey,
+    pub claimant: Pubkey,
+    pub claim_amount: u64,
+    pub claim_type: ClaimType,
+    pub evidence_hash: [u8; 32],
+    pub oracle_request_id: Option<[u8; 32]>,
+    pub status: ClaimStatus,
+    pub submitted_at: i64,
+    pub processed_at: Option<i64>,
+    pub processor: Option<Pubkey>,
+    pub rejection_reason: Option<String>,
+    pub bump: u8,
+}
+
+impl ClaimAccountV0 {
+    /// Baseline claims reserved nothing against the pool
+    pub fn into_current(self) -> ClaimAccount {
+        ClaimAccount {
+            coverage: self.coverage,
+            claimant: self.claimant,
+            claim_amount: self.claim_amount,
+            reserved_amount: 0,
+            claim_type: self.claim_type,
+            evidence_hash: self.evidence_hash,
+            oracle_request_id: self.oracle_request_id,
+            status: self.status,
+            submitted_at: self.submitted_at,
+            processed_at: self.processed_at,
+            processor: self.processor,
+            rejection_reason: self.rejection_reason,
+            bump: self.bump,
+            version: 0,
+            reserved: [0; ACCOUNT_RESERVED_BYTES],
+        }
+    }
+}
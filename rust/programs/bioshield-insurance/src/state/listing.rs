@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::BASIS_POINTS;
+
+#[account]
+#[derive(InitSpace)]
+pub struct CoverageListing {
+    pub seller: Pubkey,
+    pub coverage: Pubkey,
+    pub pool: Pubkey,
+    pub ask_price: u64, // in the pool's settlement token
+    pub listed_at: i64,
+    pub bump: u8,
+}
+
+impl CoverageListing {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    /// Split the ask into the seller's proceeds and the treasury's marketplace fee, refusing
+    /// a price above what the buyer agreed to (e.g. after a delist and relist)
+    pub fn sale_split(&self, max_price: u64, marketplace_fee_basis_points: u16) -> Result<(u64, u64)> {
+        require!(
+            self.ask_price <= max_price,
+            InsuranceError::ListingPriceExceedsMaximum
+        );
+
+        let marketplace_fee = (self.ask_price as u128)
+            .checked_mul(marketplace_fee_basis_points as u128)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?
+            .checked_div(BASIS_POINTS as u128)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)? as u64;
+        let seller_proceeds = self.ask_price
+            .checked_sub(marketplace_fee)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+        Ok((seller_proceeds, marketplace_fee))
+    }
+}
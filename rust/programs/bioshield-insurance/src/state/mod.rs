@@ -4,164 +4,2235 @@ pub mod insurance_pool;
 pub mod coverage_account;
 pub mod claim;
 pub mod oracle_data;
+pub mod listing;
+pub mod withdrawal;
+pub mod tranche;
+pub mod staking;
+pub mod reinsurance;
+pub mod registry;
+pub mod governance;
+pub mod timelock;
+pub mod roles;
+pub mod wind_down;
+pub mod legacy;
 
 pub use insurance_pool::*;
 pub use coverage_account::*;
 pub use claim::*;
 pub use oracle_data::*;
+pub use listing::*;
+pub use withdrawal::*;
+pub use tranche::*;
+pub use staking::*;
+pub use reinsurance::*;
+pub use registry::*;
+pub use governance::*;
+pub use timelock::*;
+pub use roles::*;
+pub use wind_down::*;
+pub use legacy::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub struct PoolParams {
+    pub pool_id: u64,
+    pub coverage_types: u8, // bitmask of CoverageType::mask
     pub fee_basis_points: u16,
     pub min_coverage_amount: u64,
     pub max_coverage_amount: u64,
     pub oracle_address: Pubkey,
+    pub treasury: Pubkey,
+    pub marketplace_fee_basis_points: u16,
+    pub pricing_curve: PricingCurve,
+    pub max_utilization_bps: u32,
+    pub min_premium: u64,
+    pub settlement_price_feed_id: [u8; 32], // zero for a USD stablecoin
+    pub lives_price_feed_id: [u8; 32],
+    pub lives_discount: LivesDiscountSchedule,
+    pub withdrawal_config: WithdrawalConfig,
+    pub staking_config: StakingConfig,
+    pub timelock: TimelockConfig,
+    pub guardian: Pubkey, // initial holder of the guardian role
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub struct CoverageParams {
     pub coverage_amount: u64,
-    pub coverage_period: u32,
+    pub coverage_period: u32, // seconds
     pub coverage_type: CoverageType,
     pub trigger_conditions: TriggerConditions,
     pub risk_category: RiskCategory,
     pub metadata_uri: String,
+    pub max_premium: u64, // in the units actually paid (LIVES when paying from a LIVES account)
+    pub nonce: u64, // coverage PDA seed, so an insured can hold any number of policies in a pool
 }
 
+/// Premium breakdown returned by `quote_premium`. In settlement units,
+/// `final_premium = risk_premium + fee - lives_discount`; `amount_due` is that converted
+/// into the asset the premium is paid in, which is what `create_coverage` charges against
+/// the same pool state.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub struct ClaimData {
-    pub amount: u64,
-    pub claim_type: ClaimType,
-    pub evidence_hash: [u8; 32],
+pub struct PremiumQuote {
+    pub base_premium: u64, // before risk loading
+    pub risk_multiplier_bps: u32,
+    pub utilization_multiplier_bps: u32,
+    pub risk_premium: u64, // after risk loading and the pool's minimum premium
+    pub fee: u64,
+    pub lives_discount: u64,
+    pub final_premium: u64,
+    pub premium_mint: Pubkey,
+    pub amount_due: u64,
+    pub expires_at: i64, // prices and utilization may have moved on by then; quote again
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub struct OracleData {
-    pub request_id: [u8; 32],
-    pub timestamp: i64,
-    pub data_points: Vec<DataPoint>,
-    pub signature: [u8; 64],
-}
+impl PremiumQuote {
+    /// The amount to charge, refused when it is over the insured's slippage bound
+    pub fn amount_within(&self, max_premium: u64) -> Result<u64> {
+        require!(
+            self.amount_due <= max_premium,
+            crate::errors::InsuranceError::PremiumExceedsMaximum
+        );
 
-impl OracleData {
-    pub fn verify_signature(&self, oracle_pubkey: &Pubkey) -> Result<bool> {
-        // Oracle signature verification logic
-        // In production, this would verify the signature against the oracle's pubkey
-        Ok(true) // Simplified for example
-    }
-
-    pub fn verify_trigger_conditions(&self, conditions: &TriggerConditions) -> Result<bool> {
-        for data_point in &self.data_points {
-            match &data_point.data_type {
-                DataType::ClinicalTrialResult { success } => {
-                    if conditions.clinical_trial_failure && !success {
-                        return Ok(true);
-                    }
-                },
-                DataType::RegulatoryDecision { approved } => {
-                    if conditions.regulatory_rejection && !approved {
-                        return Ok(true);
-                    }
-                },
-                DataType::IpValidation { valid } => {
-                    if conditions.ip_invalidation && !valid {
-                        return Ok(true);
-                    }
-                },
-                DataType::CustomVerification { verified } => {
-                    if !verified {
-                        return Ok(true);
-                    }
-                },
-            }
-        }
-        Ok(false)
+        Ok(self.amount_due)
     }
 }
 
+/// SHIELD share price returned by `get_share_price`
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub struct DataPoint {
-    pub data_type: DataType,
-    pub value: u64,
-    pub source: String,
+pub struct SharePrice {
+    pub vault_balance: u64,
+    pub reserved_claims: u64,
+    pub unearned_premiums: u64,
+    pub rewards_owed: u64,
+    pub net_assets: u64,
+    pub shield_supply: u64,
+    pub price_per_share: u64, // scaled by SHARE_PRICE_PRECISION
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub enum DataType {
-    ClinicalTrialResult { success: bool },
-    RegulatoryDecision { approved: bool },
-    IpValidation { valid: bool },
-    CustomVerification { verified: bool },
+pub struct ClaimData {
+    pub amount: u64,
+    pub claim_type: ClaimType,
+    pub evidence_hash: [u8; 32],
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub enum CoverageType {
-    ClinicalTrialFailure,
-    RegulatoryRejection,
-    IpInvalidation,
-    ResearchInfrastructure,
-    Custom,
-}
+// Constants
+// Coverage bounds are whole settlement tokens, scaled by each pool's settlement_decimals
+pub const MAX_COVERAGE_TOKENS: u64 = 15_000_000; // $15M of a USD stablecoin
+pub const MIN_COVERAGE_TOKENS: u64 = 1_000; // $1K of a USD stablecoin
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+pub const SECONDS_PER_YEAR: i64 = 365 * SECONDS_PER_DAY;
+pub const MIN_COVERAGE_PERIOD: u32 = 60 * 60; // 1 hour in seconds
+pub const MAX_COVERAGE_PERIOD: u32 = 5 * SECONDS_PER_YEAR as u32; // 5 years in seconds
+pub const PRICING_VERSION_PER_SECOND: u8 = 1; // 0 = legacy whole-day pricing, grandfathered
+pub const MAX_LIVES_DISCOUNT_BPS: u16 = 7_500; // 75% discount
+pub const BASIS_POINTS: u16 = 10_000;
+pub const MAX_RISK_MULTIPLIER_BPS: u32 = 30_000; // 3x cap on oracle risk loading
+pub const MAX_RISK_DATA_AGE: i64 = 24 * 60 * 60; // 1 day
+pub const QUOTE_VALIDITY_SECONDS: i64 = 5 * 60; // 5 minutes
+pub const MAX_PRICE_AGE_SECONDS: i64 = 60;
+pub const VIRTUAL_SHIELD_SHARES: u64 = 1_000_000; // Offsets against first-depositor inflation
+pub const VIRTUAL_SETTLEMENT_ASSETS: u64 = 1_000_000;
+pub const SHARE_PRICE_PRECISION: u64 = 1_000_000_000;
+pub const MAX_PRICE_CONFIDENCE_BPS: u16 = 200; // 2% of price
+pub const MIN_LISTING_TIME_REMAINING: i64 = 7 * 24 * 60 * 60; // 7 days before end_time
+pub const MAX_MARKETPLACE_FEE_BASIS_POINTS: u16 = 1_000; // 10%
+pub const MAX_STAKE_LOCK_SECONDS: i64 = 4 * SECONDS_PER_YEAR;
+pub const MIN_STAKE_AGE_SECONDS: i64 = 7 * SECONDS_PER_DAY; // staked SHIELD stays at least this long
+pub const LIVES_DISCOUNT_WARMUP_SECONDS: i64 = 30 * SECONDS_PER_DAY; // staked LIVES counts in full after this
+pub const MAX_LOCK_BOOST_BPS: u32 = 30_000; // 3x for the longest lock
+pub const MIN_VOTING_PERIOD: i64 = 24 * 60 * 60; // 1 day
+pub const MAX_VOTING_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+pub const MIN_TIMELOCK_DELAY: i64 = 24 * 60 * 60; // 1 day
+pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60; // 30 days
+pub const TIMELOCK_GRACE_PERIOD: i64 = 14 * 24 * 60 * 60; // queued changes lapse this long after their ETA
+// Account layout versions; bump when a field is carved out of the reserved bytes
+// or a bounded field grows, so existing accounts are reallocated by `migrate_account`
+pub const POOL_ACCOUNT_VERSION: u8 = 5;
+pub const COVERAGE_ACCOUNT_VERSION: u8 = 2;
+pub const CLAIM_ACCOUNT_VERSION: u8 = 1;
+pub const ACCOUNT_RESERVED_BYTES: usize = 64;
+// Bounds on variable-length fields, used to size accounts exactly
+pub const MAX_METADATA_URI_LEN: usize = 200;
+pub const MAX_COVERAGE_CATEGORY_LEN: usize = 32;
+pub const MAX_CUSTOM_CONDITIONS: usize = 5;
+pub const MAX_CONDITION_TYPE_LEN: usize = 32;
+pub const MAX_REJECTION_REASON_LEN: usize = 200;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub enum CoverageStatus {
-    Active,
-    Expired,
-    Exhausted,
-    Cancelled,
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::calculations::{calculate_net_assets, calculate_share_price};
+    use crate::utils::migration::{decode_versioned, Versioned};
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub enum ClaimType {
-    FullCoverage,
-    PartialCoverage,
-    Milestone,
-}
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub enum ClaimStatus {
-    Pending,
-    UnderReview,
-    Approved,
-    Rejected,
-    Paid,
-}
+    fn string(value: &str) -> Vec<u8> {
+        [&(value.len() as u32).to_le_bytes()[..], value.as_bytes()].concat()
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub enum RiskCategory {
-    Low,
-    Medium,
-    High,
-    VeryHigh,
-}
+    fn serialize<T: AnchorSerialize>(value: &T) -> Vec<u8> {
+        value.try_to_vec().unwrap()
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub struct TriggerConditions {
-    pub clinical_trial_failure: bool,
-    pub regulatory_rejection: bool,
-    pub ip_invalidation: bool,
-    pub minimum_threshold: u64,
-    pub custom_conditions: Vec<CustomCondition>,
-}
+    fn sample_trigger_conditions() -> TriggerConditions {
+        TriggerConditions {
+            clinical_trial_failure: true,
+            regulatory_rejection: false,
+            ip_invalidation: true,
+            minimum_threshold: 50,
+            custom_conditions: vec![CustomCondition {
+                condition_type: "efficacy".to_string(),
+                threshold_value: 30,
+                comparison_operator: ComparisonOperator::LessThan,
+            }],
+        }
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub struct CustomCondition {
-    pub condition_type: String,
-    pub threshold: u64,
-    pub operator: ComparisonOperator,
-}
+    fn sample_trigger_conditions_bytes() -> Vec<u8> {
+        [
+            &[1u8, 0, 1][..],
+            &50u64.to_le_bytes(),
+            &1u32.to_le_bytes(),
+            &string("efficacy"),
+            &30u64.to_le_bytes(),
+            &[1],
+        ]
+        .concat()
+    }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub enum ComparisonOperator {
-    GreaterThan,
-    LessThan,
-    Equal,
-    NotEqual,
-}
+    fn sample_pool() -> InsurancePool {
+        InsurancePool {
+            authority: key(1),
+            pending_authority: None,
+            creator: key(2),
+            pool_id: 7,
+            coverage_types: ALL_COVERAGE_TYPES,
+            lives_token_mint: key(3),
+            shield_token_mint: key(4),
+            tranches: Vec::new(),
+            settlement_mint: key(5),
+            settlement_decimals: 6,
+            lives_decimals: 9,
+            settlement_price_feed_id: [0; 32],
+            lives_price_feed_id: [6; 32],
+            lives_discount: LivesDiscountSchedule {
+                base_discount_bps: 1_000,
+                tiers: Vec::new(),
+                burn_share_bps: 0,
+            },
+            asset_ledgers: Vec::new(),
+            total_value_locked: 0,
+            total_coverage_amount: 0,
+            total_claims_paid: 0,
+            reserved_claims: 0,
+            unearned_premiums: 0,
+            premium_rewards: PremiumRewards::default(),
+            staking_config: StakingConfig::default(),
+            lives_emissions: LivesEmissions::default(),
+            reinsurance_treaty: None,
+            ceded_premiums: 0,
+            reinsurance_recoveries: 0,
+            governance: None,
+            timelock: TimelockConfig { delay: 14 * SECONDS_PER_DAY },
+            timelock_operation_count: 0,
+            pool_fee_basis_points: 100,
+            min_coverage_amount: MIN_COVERAGE_TOKENS * 1_000_000,
+            max_coverage_amount: MAX_COVERAGE_TOKENS * 1_000_000,
+            oracle_address: key(7),
+            treasury: key(8),
+            marketplace_fee_basis_points: 250,
+            pricing_curve: PricingCurve {
+                kink_utilization_bps: 8_000,
+                slope_below_kink_bps: 2_000,
+                slope_above_kink_bps: 20_000,
+            },
+            max_utilization_bps: 9_000,
+            min_premium: 0,
+            withdrawal_config: WithdrawalConfig {
+                cooldown_seconds: 14 * SECONDS_PER_DAY,
+                epoch_duration: SECONDS_PER_DAY,
+                max_epoch_withdrawal_bps: 1_000,
+            },
+            withdrawal_queue: WithdrawalQueue::default(),
+            created_at: 0,
+            is_paused: false,
+            bump: 255,
+            version: POOL_ACCOUNT_VERSION,
+            wind_down: WindDown::default(),
+            assumed_coverage: 0,
+            treaty_terminated: false,
+            reserved: [0; POOL_RESERVED_BYTES],
+        }
+    }
 
-// Constants
-pub const MAX_COVERAGE_AMOUNT: u64 = 15_000_000 * 1_000_000_000; // $15M in lamports
-pub const MIN_COVERAGE_AMOUNT: u64 = 1_000 * 1_000_000_000; // $1K in lamports
-pub const MAX_COVERAGE_PERIOD: u32 = 365 * 24 * 60 * 60; // 1 year in seconds
-pub const LIVES_DISCOUNT_PERCENTAGE: u8 = 50; // 50% discount
-pub const BASIS_POINTS: u16 = 10_000;
+    #[test]
+    fn test_account_discriminators_are_pinned() {
+        assert_eq!(InsurancePool::DISCRIMINATOR, [239, 152, 145, 201, 228, 155, 139, 140]);
+        assert_eq!(CoverageAccount::DISCRIMINATOR, [173, 35, 152, 17, 131, 84, 10, 80]);
+        assert_eq!(ClaimAccount::DISCRIMINATOR, [113, 109, 47, 96, 242, 219, 61, 165]);
+        assert_eq!(CoverageListing::DISCRIMINATOR, [196, 248, 205, 138, 114, 59, 12, 153]);
+        assert_eq!(WithdrawalRequest::DISCRIMINATOR, [242, 88, 147, 173, 182, 62, 229, 193]);
+        assert_eq!(StakePosition::DISCRIMINATOR, [78, 165, 30, 111, 171, 125, 11, 220]);
+        assert_eq!(LivesStake::DISCRIMINATOR, [138, 21, 88, 168, 234, 191, 41, 220]);
+        assert_eq!(ReinsuranceTreaty::DISCRIMINATOR, [142, 3, 249, 147, 230, 123, 149, 37]);
+        assert_eq!(PoolRegistry::DISCRIMINATOR, [113, 149, 124, 60, 130, 240, 64, 157]);
+        assert_eq!(Governance::DISCRIMINATOR, [18, 143, 88, 13, 73, 217, 47, 49]);
+        assert_eq!(Proposal::DISCRIMINATOR, [26, 94, 189, 187, 116, 136, 53, 33]);
+        assert_eq!(VoterRecord::DISCRIMINATOR, [178, 96, 138, 116, 143, 202, 115, 33]);
+        assert_eq!(VoteRecord::DISCRIMINATOR, [112, 9, 123, 165, 234, 9, 157, 167]);
+        assert_eq!(TimelockOperation::DISCRIMINATOR, [68, 155, 88, 49, 38, 204, 153, 102]);
+        assert_eq!(PoolRoles::DISCRIMINATOR, [49, 167, 105, 237, 226, 58, 61, 110]);
+    }
+
+    #[test]
+    fn test_enum_variant_tags_are_pinned() {
+        assert_eq!(serialize(&CoverageType::ResearchInfrastructure), vec![3]);
+        assert_eq!(
+            serialize(&CoverageType::Custom { category: "lab".to_string() }),
+            [&[4u8][..], &string("lab")].concat()
+        );
+        assert_eq!(serialize(&CoverageStatus::Listed), vec![4]);
+        assert_eq!(serialize(&ClaimType::PartialCoverage { percentage: 50 }), vec![1, 50]);
+        assert_eq!(serialize(&ClaimType::Milestone { milestone_id: 7 }), vec![2, 7, 0, 0, 0]);
+        assert_eq!(serialize(&ClaimStatus::Paid), vec![4]);
+        assert_eq!(serialize(&RiskCategory::VeryHigh), vec![3]);
+        assert_eq!(serialize(&ComparisonOperator::LessThanOrEqual), vec![4]);
+        assert_eq!(serialize(&TrancheKind::Junior), vec![1]);
+        assert_eq!(serialize(&TreatyKind::ExcessOfLoss), vec![1]);
+        assert_eq!(serialize(&PoolStatus::Paused), vec![1]);
+        assert_eq!(serialize(&PoolStatus::Redeeming), vec![3]);
+        assert_eq!(serialize(&ProposalStatus::Defeated), vec![2]);
+        assert_eq!(serialize(&Role::OracleOperator), vec![3]);
+        assert_eq!(serialize(&ParameterChange::Pause { paused: true }), vec![4, 1]);
+        assert_eq!(
+            serialize(&ParameterChange::StakingConfig { config: StakingConfig::default() }),
+            [&[7u8][..], &0u64.to_le_bytes(), &0u32.to_le_bytes()].concat()
+        );
+        assert_eq!(
+            serialize(&ParameterChange::Marketplace {
+                treasury: key(1),
+                marketplace_fee_basis_points: 250,
+            }),
+            [&[8u8][..], &[1; 32], &250u16.to_le_bytes()].concat()
+        );
+        assert_eq!(
+            serialize(&ParameterChange::RevokeRole { member: key(1), role: Role::Guardian }),
+            [&[10u8][..], &[1; 32], &[1]].concat()
+        );
+        assert_eq!(serialize(&ParameterChange::AcceptAuthority), vec![12]);
+        assert_eq!(serialize(&ParameterChange::TerminateTreaty), vec![13]);
+    }
+
+    #[test]
+    fn test_claim_data_layout_is_pinned() {
+        let claim_data = ClaimData {
+            amount: 1_000,
+            claim_type: ClaimType::PartialCoverage { percentage: 40 },
+            evidence_hash: [9; 32],
+        };
+
+        let expected = [&1_000u64.to_le_bytes()[..], &[1, 40], &[9; 32]].concat();
+        assert_eq!(serialize(&claim_data), expected);
+    }
+
+    #[test]
+    fn test_coverage_params_layout_is_pinned() {
+        let params = CoverageParams {
+            coverage_amount: 1_000_000_000,
+            coverage_period: 86_400,
+            coverage_type: CoverageType::ClinicalTrialFailure,
+            trigger_conditions: sample_trigger_conditions(),
+            risk_category: RiskCategory::High,
+            metadata_uri: "ipfs://policy".to_string(),
+            max_premium: 25_000,
+            nonce: 7,
+        };
+
+        let expected = [
+            &1_000_000_000u64.to_le_bytes()[..],
+            &86_400u32.to_le_bytes(),
+            &[0],
+            &sample_trigger_conditions_bytes(),
+            &[2],
+            &string("ipfs://policy"),
+            &25_000u64.to_le_bytes(),
+            &7u64.to_le_bytes(),
+        ]
+        .concat();
+        assert_eq!(serialize(&params), expected);
+    }
+
+    #[test]
+    fn test_pool_params_layout_is_pinned() {
+        let params = PoolParams {
+            pool_id: 3,
+            coverage_types: 0b0_0101,
+            fee_basis_points: 100,
+            min_coverage_amount: 10,
+            max_coverage_amount: 20,
+            oracle_address: key(1),
+            treasury: key(2),
+            marketplace_fee_basis_points: 250,
+            pricing_curve: PricingCurve {
+                kink_utilization_bps: 8_000,
+                slope_below_kink_bps: 2_000,
+                slope_above_kink_bps: 20_000,
+            },
+            max_utilization_bps: 9_000,
+            min_premium: 5,
+            settlement_price_feed_id: [0; 32],
+            lives_price_feed_id: [3; 32],
+            lives_discount: LivesDiscountSchedule {
+                base_discount_bps: 1_000,
+                tiers: vec![DiscountTier { min_lives_balance: 100, discount_bps: 2_000 }],
+                burn_share_bps: 500,
+            },
+            withdrawal_config: WithdrawalConfig {
+                cooldown_seconds: 60,
+                epoch_duration: 120,
+                max_epoch_withdrawal_bps: 1_000,
+            },
+            staking_config: StakingConfig {
+                lives_emission_per_second: 4,
+                lock_boosts: vec![LockBoost { min_lock_seconds: 30, multiplier_bps: 15_000 }],
+            },
+            timelock: TimelockConfig { delay: 86_400 },
+            guardian: key(4),
+        };
+
+        let expected = [
+            &3u64.to_le_bytes()[..],
+            &[0b0_0101],
+            &100u16.to_le_bytes(),
+            &10u64.to_le_bytes(),
+            &20u64.to_le_bytes(),
+            &[1; 32],
+            &[2; 32],
+            &250u16.to_le_bytes(),
+            &8_000u32.to_le_bytes(),
+            &2_000u32.to_le_bytes(),
+            &20_000u32.to_le_bytes(),
+            &9_000u32.to_le_bytes(),
+            &5u64.to_le_bytes(),
+            &[0; 32],
+            &[3; 32],
+            &1_000u16.to_le_bytes(),
+            &1u32.to_le_bytes(),
+            &100u64.to_le_bytes(),
+            &2_000u16.to_le_bytes(),
+            &500u16.to_le_bytes(),
+            &60i64.to_le_bytes(),
+            &120i64.to_le_bytes(),
+            &1_000u16.to_le_bytes(),
+            &4u64.to_le_bytes(),
+            &1u32.to_le_bytes(),
+            &30i64.to_le_bytes(),
+            &15_000u32.to_le_bytes(),
+            &86_400i64.to_le_bytes(),
+            &[4; 32],
+        ]
+        .concat();
+        assert_eq!(serialize(&params), expected);
+    }
+
+    #[test]
+    fn test_claim_account_layout_is_pinned() {
+        let claim = ClaimAccount {
+            coverage: key(1),
+            claimant: key(2),
+            claim_amount: 3,
+            reserved_amount: 4,
+            claim_type: ClaimType::FullCoverage,
+            evidence_hash: [5; 32],
+            oracle_request_id: None,
+            status: ClaimStatus::Approved,
+            submitted_at: 6,
+            processed_at: Some(7),
+            processor: None,
+            rejection_reason: Some("no".to_string()),
+            bump: 254,
+            version: CLAIM_ACCOUNT_VERSION,
+            reserved: [0; ACCOUNT_RESERVED_BYTES],
+        };
+
+        let mut data = Vec::new();
+        claim.try_serialize(&mut data).unwrap();
+
+        let expected = [
+            &ClaimAccount::DISCRIMINATOR[..],
+            &[1; 32],
+            &[2; 32],
+            &3u64.to_le_bytes(),
+            &4u64.to_le_bytes(),
+            &[0],
+            &[5; 32],
+            &[0],
+            &[2],
+            &6i64.to_le_bytes(),
+            &[1],
+            &7i64.to_le_bytes(),
+            &[0],
+            &[1],
+            &string("no"),
+            &[254, CLAIM_ACCOUNT_VERSION],
+            &[0; ACCOUNT_RESERVED_BYTES],
+        ]
+        .concat();
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn test_versioned_account_sizes_are_pinned() {
+        // Growing an account means bumping its version, so `migrate_account` reallocates it
+        assert_eq!(CoverageAccount::SIZE, 749);
+        assert_eq!(ClaimAccount::SIZE, 480);
+    }
+
+    #[test]
+    fn test_stored_strings_fit_their_bounds() {
+        let validate = crate::utils::validations::validate_coverage_category;
+        let custom = |len| CoverageType::Custom { category: max_string(len) };
+
+        assert!(validate(&custom(MAX_COVERAGE_CATEGORY_LEN)).is_ok());
+        assert_eq!(
+            validate(&custom(MAX_COVERAGE_CATEGORY_LEN + 1)).err(),
+            Some(crate::errors::InsuranceError::CoverageCategoryTooLong.into())
+        );
+        assert!(validate(&CoverageType::ClinicalTrialFailure).is_ok());
+
+        assert!(crate::utils::validations::validate_rejection_reason(ORACLE_REJECTION_REASON).is_ok());
+    }
+
+    #[test]
+    fn test_coverage_account_layout_is_pinned() {
+        let coverage = CoverageAccount {
+            insured: key(1),
+            pool: key(2),
+            coverage_amount: 3,
+            premium_paid: 4,
+            premium_earned: 5,
+            premium_mint: key(6),
+            coverage_type: CoverageType::IpInvalidation,
+            trigger_conditions: sample_trigger_conditions(),
+            start_time: 7,
+            end_time: 8,
+            status: CoverageStatus::Active,
+            claims_made: 9,
+            pending_claims: 10,
+            total_claimed: 11,
+            metadata_uri: "ipfs://policy".to_string(),
+            pricing_inputs: PricingInputs {
+                base_rate_bps: 500,
+                risk_multiplier_bps: 12_000,
+                trial_risk_score_bps: Some(4_000),
+                approval_probability_bps: None,
+                invalidation_risk_bps: None,
+                subject_id_hash: [12; 32],
+                oracle_timestamp: 13,
+            },
+            pricing_version: PRICING_VERSION_PER_SECOND,
+            bump: 253,
+            version: COVERAGE_ACCOUNT_VERSION,
+            ceded_coverage: 14,
+            reserved: [0; COVERAGE_RESERVED_BYTES],
+        };
+
+        let mut data = Vec::new();
+        coverage.try_serialize(&mut data).unwrap();
+
+        let expected = [
+            &CoverageAccount::DISCRIMINATOR[..],
+            &[1; 32],
+            &[2; 32],
+            &3u64.to_le_bytes(),
+            &4u64.to_le_bytes(),
+            &5u64.to_le_bytes(),
+            &[6; 32],
+            &[2],
+            &sample_trigger_conditions_bytes(),
+            &7i64.to_le_bytes(),
+            &8i64.to_le_bytes(),
+            &[0],
+            &9u32.to_le_bytes(),
+            &10u32.to_le_bytes(),
+            &11u64.to_le_bytes(),
+            &string("ipfs://policy"),
+            &500u16.to_le_bytes(),
+            &12_000u32.to_le_bytes(),
+            &[1],
+            &4_000u16.to_le_bytes(),
+            &[0, 0],
+            &[12; 32],
+            &13i64.to_le_bytes(),
+            &[PRICING_VERSION_PER_SECOND, 253, COVERAGE_ACCOUNT_VERSION],
+            &14u64.to_le_bytes(),
+            &[0; COVERAGE_RESERVED_BYTES],
+        ]
+        .concat();
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn test_pool_account_offsets_are_pinned() {
+        // Clients filter pools by these offsets with memcmp
+        let mut data = Vec::new();
+        sample_pool().try_serialize(&mut data).unwrap();
+
+        assert_eq!(&data[..8], &InsurancePool::DISCRIMINATOR[..]);
+        assert_eq!(&data[8..40], &[1; 32]); // authority
+        assert_eq!(data[40], 0); // pending_authority: None
+        assert_eq!(&data[41..73], &[2; 32]); // creator
+        assert_eq!(&data[73..81], &7u64.to_le_bytes()); // pool_id
+        assert_eq!(data[81], ALL_COVERAGE_TYPES);
+
+        // Version and reserved space always close the account
+        let tail = &data[data.len() - ACCOUNT_RESERVED_BYTES - 2..];
+        assert_eq!(tail[..2], [255, POOL_ACCOUNT_VERSION]);
+        assert!(tail[2..].iter().all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn test_stake_position_layout_is_pinned() {
+        let position = StakePosition {
+            pool: key(1),
+            owner: key(2),
+            staked_amount: 3,
+            reward_debt: 4,
+            pending_rewards: 5,
+            lock_end: 6,
+            last_staked_at: 7,
+            boost_bps: 8,
+            boosted_amount: 9,
+            lives_reward_debt: 10,
+            pending_lives_rewards: 11,
+            bump: 254,
+        };
+
+        let mut data = Vec::new();
+        position.try_serialize(&mut data).unwrap();
+
+        let expected = [
+            &StakePosition::DISCRIMINATOR[..],
+            &[1; 32],
+            &[2; 32],
+            &3u64.to_le_bytes(),
+            &4u128.to_le_bytes(),
+            &5u64.to_le_bytes(),
+            &6i64.to_le_bytes(),
+            &7i64.to_le_bytes(),
+            &8u32.to_le_bytes(),
+            &9u64.to_le_bytes(),
+            &10u128.to_le_bytes(),
+            &11u64.to_le_bytes(),
+            &[254],
+        ]
+        .concat();
+        assert_eq!(data, expected);
+        assert_eq!(data.len(), StakePosition::SIZE);
+    }
+
+    #[test]
+    fn test_lives_stake_layout_is_pinned() {
+        let stake = LivesStake {
+            pool: key(1),
+            owner: key(2),
+            amount: 3,
+            staked_since: 4,
+            bump: 254,
+        };
+
+        let mut data = Vec::new();
+        stake.try_serialize(&mut data).unwrap();
+
+        let expected = [
+            &LivesStake::DISCRIMINATOR[..],
+            &[1; 32],
+            &[2; 32],
+            &3u64.to_le_bytes(),
+            &4i64.to_le_bytes(),
+            &[254],
+        ]
+        .concat();
+        assert_eq!(data, expected);
+        assert_eq!(data.len(), LivesStake::SIZE);
+    }
+
+    #[test]
+    fn test_withdrawal_request_layout_is_pinned() {
+        let request = WithdrawalRequest {
+            pool: key(1),
+            provider: key(2),
+            share_mint: key(3),
+            shield_amount: 4,
+            sequence: 5,
+            requested_at: 6,
+            bump: 254,
+        };
+
+        let mut data = Vec::new();
+        request.try_serialize(&mut data).unwrap();
+
+        let expected = [
+            &WithdrawalRequest::DISCRIMINATOR[..],
+            &[1; 32],
+            &[2; 32],
+            &[3; 32],
+            &4u64.to_le_bytes(),
+            &5u64.to_le_bytes(),
+            &6i64.to_le_bytes(),
+            &[254],
+        ]
+        .concat();
+        assert_eq!(data, expected);
+        assert_eq!(data.len(), WithdrawalRequest::SIZE);
+    }
+
+    #[test]
+    fn test_treaty_layout_is_pinned() {
+        let terms = TreatyTerms {
+            kind: TreatyKind::ExcessOfLoss,
+            cession_bps: 2_500,
+            attachment_point: 1_000,
+            limit: 50_000,
+        };
+        let terms_bytes = [
+            &[1u8][..],
+            &2_500u16.to_le_bytes(),
+            &1_000u64.to_le_bytes(),
+            &50_000u64.to_le_bytes(),
+        ]
+        .concat();
+        assert_eq!(serialize(&terms), terms_bytes);
+
+        let treaty = ReinsuranceTreaty {
+            cedent: key(1),
+            reinsurer: key(2),
+            terms,
+            ceded_coverage: 3,
+            ceded_premiums: 4,
+            recoveries: 5,
+            created_at: 6,
+            bump: 254,
+        };
+
+        let mut data = Vec::new();
+        treaty.try_serialize(&mut data).unwrap();
+
+        let expected = [
+            &ReinsuranceTreaty::DISCRIMINATOR[..],
+            &[1; 32],
+            &[2; 32],
+            &terms_bytes,
+            &3u64.to_le_bytes(),
+            &4u64.to_le_bytes(),
+            &5u64.to_le_bytes(),
+            &6i64.to_le_bytes(),
+            &[254],
+        ]
+        .concat();
+        assert_eq!(data, expected);
+        assert_eq!(data.len(), ReinsuranceTreaty::SIZE);
+    }
+
+    #[test]
+    fn test_governance_config_layout_is_pinned() {
+        let config = GovernanceConfig {
+            voting_period: 1,
+            quorum_votes: 2,
+            threshold_bps: 3,
+            min_proposal_weight: 4,
+            lives_vote_weight_bps: 5,
+        };
+
+        let expected = [
+            &1i64.to_le_bytes()[..],
+            &2u64.to_le_bytes(),
+            &3u16.to_le_bytes(),
+            &4u64.to_le_bytes(),
+            &5u16.to_le_bytes(),
+        ]
+        .concat();
+        assert_eq!(serialize(&config), expected);
+    }
+
+    #[test]
+    fn test_wind_down_layout_is_pinned() {
+        // Carved out of the pool's reserved bytes, so its size is fixed as well as its order
+        let wind_down = WindDown {
+            started_at: Some(1),
+            redemption_opened_at: None,
+            open_coverages: Some(2),
+            coverage_ends_by: 3,
+        };
+
+        let expected = [
+            &[1u8][..],
+            &1i64.to_le_bytes(),
+            &[0],
+            &[1],
+            &2u64.to_le_bytes(),
+            &3i64.to_le_bytes(),
+        ]
+        .concat();
+        assert_eq!(serialize(&wind_down), expected);
+        assert_eq!(WindDown::INIT_SPACE, 9 + 9 + 9 + 8);
+    }
+
+    #[test]
+    fn test_risk_assessment_layout_is_pinned() {
+        let assessment = crate::oracles::RiskAssessment {
+            oracle_data: crate::oracles::MultiOracleData {
+                price_data: None,
+                bioscience_data: None,
+                switchboard_data: None,
+                consensus_timestamp: 1,
+                data_sources_count: 2,
+            },
+            clinical_trial: None,
+            regulatory: None,
+            intellectual_property: None,
+        };
+
+        let expected = [&[0u8, 0, 0][..], &1i64.to_le_bytes(), &[2], &[0, 0, 0]].concat();
+        assert_eq!(serialize(&assessment), expected);
+    }
+
+    fn max_string(len: usize) -> String {
+        "x".repeat(len)
+    }
+
+    /// Serialize at the largest size the bounds allow, then check it fits the account exactly
+    fn assert_round_trips_at_size<T>(account: &T, size: usize)
+    where
+        T: AccountSerialize + AccountDeserialize,
+    {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), size);
+
+        let decoded = T::try_deserialize(&mut &data[..]).unwrap();
+        let mut reencoded = Vec::new();
+        decoded.try_serialize(&mut reencoded).unwrap();
+        assert_eq!(reencoded, data);
+    }
+
+    fn max_staking_config() -> StakingConfig {
+        StakingConfig {
+            lives_emission_per_second: 1,
+            lock_boosts: vec![
+                LockBoost { min_lock_seconds: 30, multiplier_bps: 15_000 };
+                MAX_LOCK_BOOSTS
+            ],
+        }
+    }
+
+    #[test]
+    fn test_max_size_coverage_account_round_trips() {
+        let coverage = CoverageAccount {
+            insured: key(1),
+            pool: key(2),
+            coverage_amount: u64::MAX,
+            premium_paid: u64::MAX,
+            premium_earned: u64::MAX,
+            premium_mint: key(3),
+            coverage_type: CoverageType::Custom {
+                category: max_string(MAX_COVERAGE_CATEGORY_LEN),
+            },
+            trigger_conditions: TriggerConditions {
+                clinical_trial_failure: true,
+                regulatory_rejection: true,
+                ip_invalidation: true,
+                minimum_threshold: u64::MAX,
+                custom_conditions: vec![
+                    CustomCondition {
+                        condition_type: max_string(MAX_CONDITION_TYPE_LEN),
+                        threshold_value: u64::MAX,
+                        comparison_operator: ComparisonOperator::GreaterThanOrEqual,
+                    };
+                    MAX_CUSTOM_CONDITIONS
+                ],
+            },
+            start_time: i64::MAX,
+            end_time: i64::MAX,
+            status: CoverageStatus::Listed,
+            claims_made: u32::MAX,
+            pending_claims: u32::MAX,
+            total_claimed: u64::MAX,
+            metadata_uri: max_string(MAX_METADATA_URI_LEN),
+            pricing_inputs: PricingInputs {
+                base_rate_bps: u16::MAX,
+                risk_multiplier_bps: u32::MAX,
+                trial_risk_score_bps: Some(u16::MAX),
+                approval_probability_bps: Some(u16::MAX),
+                invalidation_risk_bps: Some(u16::MAX),
+                subject_id_hash: [4; 32],
+                oracle_timestamp: i64::MAX,
+            },
+            pricing_version: PRICING_VERSION_PER_SECOND,
+            bump: 255,
+            version: COVERAGE_ACCOUNT_VERSION,
+            ceded_coverage: u64::MAX,
+            reserved: [0; COVERAGE_RESERVED_BYTES],
+        };
+
+        assert_round_trips_at_size(&coverage, CoverageAccount::SIZE);
+    }
+
+    #[test]
+    fn test_max_size_claim_account_round_trips() {
+        let claim = ClaimAccount {
+            coverage: key(1),
+            claimant: key(2),
+            claim_amount: u64::MAX,
+            reserved_amount: u64::MAX,
+            claim_type: ClaimType::Milestone { milestone_id: u32::MAX },
+            evidence_hash: [3; 32],
+            oracle_request_id: Some([4; 32]),
+            status: ClaimStatus::Rejected,
+            submitted_at: i64::MAX,
+            processed_at: Some(i64::MAX),
+            processor: Some(key(5)),
+            rejection_reason: Some(max_string(MAX_REJECTION_REASON_LEN)),
+            bump: 255,
+            version: CLAIM_ACCOUNT_VERSION,
+            reserved: [0; ACCOUNT_RESERVED_BYTES],
+        };
+
+        assert_round_trips_at_size(&claim, ClaimAccount::SIZE);
+    }
+
+    #[test]
+    fn test_max_size_pool_round_trips() {
+        let mut pool = sample_pool();
+        pool.pending_authority = Some(key(9));
+        pool.tranches = vec![
+            Tranche {
+                kind: TrancheKind::Senior,
+                share_mint: key(10),
+                assets: u64::MAX,
+                premium_share_bps: 6_000,
+            };
+            MAX_TRANCHES
+        ];
+        pool.lives_discount.tiers = vec![
+            DiscountTier { min_lives_balance: u64::MAX, discount_bps: 2_000 };
+            MAX_DISCOUNT_TIERS
+        ];
+        pool.asset_ledgers = vec![
+            AssetLedger {
+                mint: key(11),
+                vault: key(12),
+                liquidity_deposited: u64::MAX,
+                liquidity_withdrawn: u64::MAX,
+                premiums_collected: u64::MAX,
+                claims_paid: u64::MAX,
+                bump: 255,
+            };
+            MAX_POOL_ASSETS
+        ];
+        pool.staking_config = max_staking_config();
+        pool.reinsurance_treaty = Some(key(13));
+        pool.governance = Some(key(14));
+        pool.wind_down = WindDown {
+            started_at: Some(i64::MAX),
+            redemption_opened_at: Some(i64::MAX),
+            open_coverages: Some(u64::MAX),
+            coverage_ends_by: i64::MAX,
+        };
+
+        assert_round_trips_at_size(&pool, InsurancePool::SIZE);
+    }
+
+    /// Lay an account out as the baseline program allocated it, then grow it as migration does
+    fn baseline_account(discriminator: [u8; 8], fields: &[u8], baseline_size: usize, size: usize) -> Vec<u8> {
+        let mut data = [&discriminator[..], fields].concat();
+        assert!(data.len() <= baseline_size);
+        data.resize(size, 0);
+        data
+    }
+
+    /// Write a migrated account back and check it now reads as the current version
+    fn assert_migrated<T: Versioned>(account: &T, size: usize) {
+        let mut data = vec![0; size];
+        account.try_serialize(&mut &mut data[..]).unwrap();
+
+        let (decoded, version) = decode_versioned::<T>(&data, size).unwrap();
+        assert_eq!(version, T::VERSION);
+        let mut reencoded = vec![0; size];
+        decoded.try_serialize(&mut &mut reencoded[..]).unwrap();
+        assert_eq!(reencoded, data);
+    }
+
+    #[test]
+    fn test_baseline_sizes_match_the_baseline_layouts() {
+        assert_eq!(BASELINE_POOL_SIZE, 8 + 32 * 3 + 8 * 3 + 2 + 8 * 2 + 32 + 8 + 1 + 1);
+        assert_eq!(
+            BASELINE_COVERAGE_SIZE,
+            8 + 32 * 2 + 8 * 2 + (1 + 32) + (1 + 1 + 1 + 8 + 4 + 160) + 8 * 2 + 1 + 4 + 8 + (4 + 200) + 1
+        );
+        assert_eq!(
+            BASELINE_CLAIM_SIZE,
+            8 + 32 * 2 + 8 + (1 + 32) + 32 + (1 + 32) + 1 + 8 + (1 + 8) + (1 + 32) + (1 + 4 + 200) + 1
+        );
+    }
+
+    #[test]
+    fn test_baseline_pool_migrates_to_the_current_layout() {
+        let fields = [
+            &[1u8; 32][..],
+            &[2; 32],
+            &[3; 32],
+            &1_000u64.to_le_bytes(),
+            &500u64.to_le_bytes(),
+            &50u64.to_le_bytes(),
+            &100u16.to_le_bytes(),
+            &10u64.to_le_bytes(),
+            &20u64.to_le_bytes(),
+            &[4; 32],
+            &1_600_000_000i64.to_le_bytes(),
+            &[0],
+            &[253],
+        ]
+        .concat();
+        assert_eq!(8 + fields.len(), BASELINE_POOL_SIZE);
+        let data = baseline_account(
+            InsurancePool::DISCRIMINATOR,
+            &fields,
+            BASELINE_POOL_SIZE,
+            InsurancePool::SIZE,
+        );
+
+        let (mut pool, from_version) =
+            decode_versioned::<InsurancePool>(&data, BASELINE_POOL_SIZE).unwrap();
+        assert_eq!(from_version, 0);
+        pool.upgrade(from_version);
+
+        assert_eq!(pool.authority, key(1));
+        assert_eq!(pool.creator, key(1));
+        assert_eq!(pool.pool_id, LEGACY_POOL_ID);
+        assert_eq!(pool.lives_token_mint, key(2));
+        assert_eq!(pool.shield_token_mint, key(3));
+        assert_eq!(pool.total_value_locked, 1_000);
+        assert_eq!(pool.total_coverage_amount, 500);
+        assert_eq!(pool.total_claims_paid, 50);
+        assert_eq!(pool.pool_fee_basis_points, 100);
+        assert_eq!((pool.min_coverage_amount, pool.max_coverage_amount), (10, 20));
+        assert_eq!(pool.oracle_address, key(4));
+        assert_eq!(pool.created_at, 1_600_000_000);
+        assert_eq!(pool.bump, 253);
+        // Baseline premiums were lamports, and nothing can be written until vaults exist
+        assert_eq!(pool.settlement_mint, anchor_spl::token::spl_token::native_mint::ID);
+        assert!(pool.asset_ledgers.is_empty());
+        assert!(pool.is_paused);
+        assert_eq!(pool.treasury, key(1));
+        assert_eq!(pool.wind_down.open_coverages, None);
+        assert_eq!(pool.version, POOL_ACCOUNT_VERSION);
+
+        assert_migrated(&pool, InsurancePool::SIZE);
+    }
+
+    #[test]
+    fn test_baseline_coverage_migrates_to_the_current_layout() {
+        let fields = [
+            &[1u8; 32][..],
+            &[2; 32],
+            &1_000u64.to_le_bytes(),
+            &40u64.to_le_bytes(),
+            &[4],
+            &string("lab"),
+            &sample_trigger_conditions_bytes(),
+            &100i64.to_le_bytes(),
+            &200i64.to_le_bytes(),
+            &[0],
+            &2u32.to_le_bytes(),
+            &300u64.to_le_bytes(),
+            &string("ipfs://policy"),
+            &[252],
+        ]
+        .concat();
+        let data = baseline_account(
+            CoverageAccount::DISCRIMINATOR,
+            &fields,
+            BASELINE_COVERAGE_SIZE,
+            CoverageAccount::SIZE,
+        );
+
+        let (mut coverage, from_version) =
+            decode_versioned::<CoverageAccount>(&data, BASELINE_COVERAGE_SIZE).unwrap();
+        assert_eq!(from_version, 0);
+        coverage.upgrade(from_version);
+
+        assert_eq!(coverage.insured, key(1));
+        assert_eq!(coverage.pool, key(2));
+        assert_eq!(coverage.coverage_amount, 1_000);
+        assert_eq!(coverage.coverage_type, CoverageType::Custom { category: "lab".to_string() });
+        assert_eq!(coverage.trigger_conditions, sample_trigger_conditions());
+        assert_eq!((coverage.start_time, coverage.end_time), (100, 200));
+        assert_eq!(coverage.status, CoverageStatus::Active);
+        assert_eq!(coverage.claims_made, 2);
+        assert_eq!(coverage.total_claimed, 300);
+        assert_eq!(coverage.metadata_uri, "ipfs://policy");
+        assert_eq!(coverage.bump, 252);
+        // The premium already sits in the pool, so there is nothing left to accrue or refund
+        assert_eq!((coverage.premium_paid, coverage.premium_earned), (40, 40));
+        assert_eq!(coverage.premium_mint, Pubkey::default());
+        assert_eq!(coverage.pending_claims, 0);
+        assert_eq!(coverage.pricing_version, 0);
+        assert_eq!(coverage.ceded_coverage, 0);
+        assert_eq!(coverage.version, COVERAGE_ACCOUNT_VERSION);
+
+        assert_migrated(&coverage, CoverageAccount::SIZE);
+    }
+
+    #[test]
+    fn test_baseline_claim_migrates_to_the_current_layout() {
+        let fields = [
+            &[1u8; 32][..],
+            &[2; 32],
+            &500u64.to_le_bytes(),
+            &[1, 40],
+            &[5; 32],
+            &[1],
+            &[6; 32],
+            &[3],
+            &10i64.to_le_bytes(),
+            &[1],
+            &20i64.to_le_bytes(),
+            &[0],
+            &[1],
+            &string("no"),
+            &[251],
+        ]
+        .concat();
+        let data = baseline_account(
+            ClaimAccount::DISCRIMINATOR,
+            &fields,
+            BASELINE_CLAIM_SIZE,
+            ClaimAccount::SIZE,
+        );
+
+        let (mut claim, from_version) =
+            decode_versioned::<ClaimAccount>(&data, BASELINE_CLAIM_SIZE).unwrap();
+        assert_eq!(from_version, 0);
+        claim.upgrade(from_version);
+
+        assert_eq!(claim.coverage, key(1));
+        assert_eq!(claim.claimant, key(2));
+        assert_eq!(claim.claim_amount, 500);
+        assert_eq!(claim.reserved_amount, 0);
+        assert_eq!(claim.claim_type, ClaimType::PartialCoverage { percentage: 40 });
+        assert_eq!(claim.evidence_hash, [5; 32]);
+        assert_eq!(claim.oracle_request_id, Some([6; 32]));
+        assert_eq!(claim.status, ClaimStatus::Rejected);
+        assert_eq!(claim.submitted_at, 10);
+        assert_eq!(claim.processed_at, Some(20));
+        assert_eq!(claim.processor, None);
+        assert_eq!(claim.rejection_reason, Some("no".to_string()));
+        assert_eq!(claim.bump, 251);
+        assert_eq!(claim.version, CLAIM_ACCOUNT_VERSION);
+
+        assert_migrated(&claim, ClaimAccount::SIZE);
+    }
+
+    #[test]
+    fn test_migrated_pool_gets_a_marketplace_through_a_parameter_change() {
+        let pool_v0 = InsurancePoolV0 {
+            authority: key(1),
+            lives_token_mint: key(2),
+            shield_token_mint: key(3),
+            total_value_locked: 0,
+            total_coverage_amount: 0,
+            total_claims_paid: 0,
+            pool_fee_basis_points: 100,
+            min_coverage_amount: 10,
+            max_coverage_amount: 20,
+            oracle_address: key(4),
+            created_at: 0,
+            is_paused: false,
+            bump: 255,
+        };
+        let mut pool = pool_v0.into_current();
+        // Fee-free until governance says otherwise, with any fee going to the authority
+        assert_eq!(pool.treasury, key(1));
+        assert_eq!(pool.marketplace_fee_basis_points, 0);
+
+        let change = ParameterChange::Marketplace {
+            treasury: key(5),
+            marketplace_fee_basis_points: 250,
+        };
+        crate::utils::validations::validate_parameter_change(&change).unwrap();
+        pool.apply_parameter_change(&change, 0).unwrap();
+        assert_eq!(pool.treasury, key(5));
+        assert_eq!(pool.marketplace_fee_basis_points, 250);
+
+        let excessive = ParameterChange::Marketplace {
+            treasury: key(5),
+            marketplace_fee_basis_points: MAX_MARKETPLACE_FEE_BASIS_POINTS + 1,
+        };
+        assert!(crate::utils::validations::validate_parameter_change(&excessive).is_err());
+    }
+
+    #[test]
+    fn test_pool_prices_a_vault_for_each_accepted_asset() {
+        let pool = sample_pool();
+        let native_mint = anchor_spl::token::spl_token::native_mint::ID;
+
+        assert_eq!(pool.asset_price_feed(&pool.settlement_mint).unwrap(), ([0; 32], 6));
+        assert_eq!(pool.asset_price_feed(&pool.lives_token_mint).unwrap(), ([6; 32], 9));
+        assert_eq!(pool.asset_price_feed(&native_mint).unwrap(), (SOL_USD_FEED_ID, 9));
+        assert_eq!(
+            pool.asset_price_feed(&pool.shield_token_mint).err(),
+            Some(crate::errors::InsuranceError::InvalidTokenMint.into())
+        );
+    }
+
+    fn lives_stake(amount: u64, staked_since: i64) -> LivesStake {
+        LivesStake { pool: key(1), owner: key(2), amount, staked_since, bump: 255 }
+    }
+
+    #[test]
+    fn test_staked_lives_counts_towards_a_tier_as_it_ages() {
+        let stake = lives_stake(1_000, 0);
+
+        assert_eq!(stake.discount_balance(0), 0);
+        assert_eq!(stake.discount_balance(LIVES_DISCOUNT_WARMUP_SECONDS / 2), 500);
+        assert_eq!(stake.discount_balance(LIVES_DISCOUNT_WARMUP_SECONDS), 1_000);
+        assert_eq!(stake.discount_balance(10 * LIVES_DISCOUNT_WARMUP_SECONDS), 1_000);
+    }
+
+    #[test]
+    fn test_lives_staked_just_before_a_quote_earns_no_tier() {
+        // A long-standing small stake tops up with a large, freshly acquired balance
+        let mut stake = lives_stake(100, 0);
+        let now = 10 * LIVES_DISCOUNT_WARMUP_SECONDS;
+        stake.deposit(9_900, now).unwrap();
+
+        // Only the stake that had already matured counts
+        assert_eq!(stake.amount, 10_000);
+        assert_eq!(stake.discount_balance(now), 100);
+
+        // The schedule's top tier stays out of reach until the new LIVES has aged
+        let schedule = LivesDiscountSchedule {
+            base_discount_bps: 500,
+            tiers: vec![DiscountTier { min_lives_balance: 10_000, discount_bps: 2_000 }],
+            burn_share_bps: 0,
+        };
+        assert_eq!(schedule.discount_bps_for(stake.discount_balance(now)), 500);
+        assert_eq!(schedule.discount_bps_for(stake.discount_balance(now + LIVES_DISCOUNT_WARMUP_SECONDS)), 2_000);
+    }
+
+    #[test]
+    fn test_unstaking_keeps_the_age_of_the_remaining_lives() {
+        let mut stake = lives_stake(1_000, 0);
+        stake.withdraw(400).unwrap();
+
+        assert_eq!(stake.staked_since, 0);
+        assert_eq!(stake.discount_balance(LIVES_DISCOUNT_WARMUP_SECONDS), 600);
+        assert_eq!(
+            stake.withdraw(601).err(),
+            Some(crate::errors::InsuranceError::InsufficientStake.into())
+        );
+    }
+
+    fn stake_position(staked_amount: u64, last_staked_at: i64, rewards: &PremiumRewards) -> StakePosition {
+        let mut position = StakePosition {
+            pool: key(1),
+            owner: key(2),
+            staked_amount,
+            reward_debt: 0,
+            pending_rewards: 0,
+            lock_end: 0,
+            last_staked_at,
+            boost_bps: BASIS_POINTS as u32,
+            boosted_amount: staked_amount,
+            lives_reward_debt: 0,
+            pending_lives_rewards: 0,
+            bump: 255,
+        };
+        position.sync_reward_debt(rewards, &LivesEmissions::default()).unwrap();
+        position
+    }
+
+    #[test]
+    fn test_premium_with_nobody_staked_is_left_to_share_holders() {
+        let mut rewards = PremiumRewards::default();
+
+        assert_eq!(rewards.distribute(1_000, 5_000).unwrap(), 0);
+        assert_eq!(rewards, PremiumRewards::default());
+    }
+
+    #[test]
+    fn test_premium_is_split_between_staked_and_unstaked_shield() {
+        let emissions = LivesEmissions::default();
+        let mut pool = sample_pool();
+        pool.premium_rewards.total_staked = 1_000;
+        let mut staker = stake_position(1_000, 0, &pool.premium_rewards);
+
+        // A quarter of the SHIELD supply is staked, so stakers are credited a quarter
+        let vault_balance = 100_000;
+        let shield_supply = 4_000;
+        pool.unearned_premiums = 2_000;
+        let net_assets_before = calculate_net_assets(vault_balance, pool.settlement_liabilities());
+
+        pool.unearned_premiums -= 2_000;
+        let credited = pool.premium_rewards.distribute(2_000, shield_supply).unwrap();
+        assert_eq!(credited, 500);
+        assert_eq!(pool.premium_rewards.rewards_owed, 500);
+
+        staker.settle(&pool.premium_rewards, &emissions).unwrap();
+        assert_eq!(staker.pending_rewards, 500);
+
+        // The other 1,500 is never owed to anyone, so it raises the share price
+        let net_assets_after = calculate_net_assets(vault_balance, pool.settlement_liabilities());
+        assert_eq!(net_assets_after - net_assets_before, 1_500);
+        assert!(
+            calculate_share_price(net_assets_after, shield_supply).unwrap() >
+                calculate_share_price(net_assets_before, shield_supply).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_stake_sniped_before_an_accrual_is_held_for_the_minimum_period() {
+        let emissions = LivesEmissions::default();
+        let mut rewards = PremiumRewards { total_staked: 1_000, ..Default::default() };
+        let mut holder = stake_position(1_000, 0, &rewards);
+
+        // A large accrual is about to land; the sniper stakes just before it
+        let sniped_at = 100 * SECONDS_PER_DAY;
+        rewards.total_staked += 1_000;
+        let mut sniper = stake_position(1_000, sniped_at, &rewards);
+        assert_eq!(rewards.distribute(2_000, rewards.total_staked).unwrap(), 2_000);
+
+        holder.settle(&rewards, &emissions).unwrap();
+        sniper.settle(&rewards, &emissions).unwrap();
+        assert_eq!(holder.pending_rewards, 1_000);
+        assert_eq!(sniper.pending_rewards, 1_000);
+
+        // ...but cannot take the SHIELD straight back out
+        assert!(!holder.is_warming_up(sniped_at + 1));
+        assert!(sniper.is_warming_up(sniped_at + 1));
+        assert!(sniper.is_warming_up(sniped_at + MIN_STAKE_AGE_SECONDS - 1));
+        assert!(!sniper.is_warming_up(sniped_at + MIN_STAKE_AGE_SECONDS));
+    }
+
+    fn lock_boost_config() -> StakingConfig {
+        StakingConfig {
+            lives_emission_per_second: 0,
+            lock_boosts: vec![
+                LockBoost { min_lock_seconds: SECONDS_PER_YEAR, multiplier_bps: 15_000 },
+                LockBoost { min_lock_seconds: MAX_STAKE_LOCK_SECONDS, multiplier_bps: MAX_LOCK_BOOST_BPS },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_top_up_keeps_the_boost_of_a_running_lock() {
+        let config = lock_boost_config();
+        let mut position = stake_position(1_000, 0, &PremiumRewards::default());
+        position.extend_lock(&config, MAX_STAKE_LOCK_SECONDS, 0).unwrap();
+        assert_eq!(position.boost_bps, MAX_LOCK_BOOST_BPS);
+
+        // Two years in, an unlocked top-up has less than the max lock left but keeps 3x
+        let later = 2 * SECONDS_PER_YEAR;
+        position.extend_lock(&config, 0, later).unwrap();
+        assert_eq!(position.lock_end, MAX_STAKE_LOCK_SECONDS);
+        assert_eq!(position.boost_bps, MAX_LOCK_BOOST_BPS);
+
+        // A shorter lock on the top-up neither shortens the lock nor lowers the boost
+        position.extend_lock(&config, SECONDS_PER_YEAR, later).unwrap();
+        assert_eq!(position.lock_end, MAX_STAKE_LOCK_SECONDS);
+        assert_eq!(position.boost_bps, MAX_LOCK_BOOST_BPS);
+    }
+
+    #[test]
+    fn test_top_up_after_the_lock_ends_earns_only_its_own_boost() {
+        let config = lock_boost_config();
+        let mut position = stake_position(1_000, 0, &PremiumRewards::default());
+        position.extend_lock(&config, MAX_STAKE_LOCK_SECONDS, 0).unwrap();
+
+        // The expired lock's 3x has not been refreshed away yet, but it does not carry over
+        position.extend_lock(&config, SECONDS_PER_YEAR, MAX_STAKE_LOCK_SECONDS).unwrap();
+        assert_eq!(position.lock_end, MAX_STAKE_LOCK_SECONDS + SECONDS_PER_YEAR);
+        assert_eq!(position.boost_bps, 15_000);
+
+        position.extend_lock(&config, 0, position.lock_end).unwrap();
+        assert_eq!(position.boost_bps, BASIS_POINTS as u32);
+    }
+
+    fn treaty(kind: TreatyKind, cession_bps: u16, attachment_point: u64, limit: u64) -> ReinsuranceTreaty {
+        ReinsuranceTreaty {
+            cedent: key(1),
+            reinsurer: key(2),
+            terms: TreatyTerms { kind, cession_bps, attachment_point, limit },
+            ceded_coverage: 0,
+            ceded_premiums: 0,
+            recoveries: 0,
+            created_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_apply_bps_rounds_down_and_checks_overflow() {
+        assert_eq!(apply_bps(999, 2_500).unwrap(), 249);
+        assert_eq!(apply_bps(u64::MAX, BASIS_POINTS).unwrap(), u64::MAX);
+        assert!(apply_bps(u64::MAX, u16::MAX).is_err());
+    }
+
+    #[test]
+    fn test_quota_share_cedes_its_share_of_exposure_and_losses() {
+        let mut treaty = treaty(TreatyKind::QuotaShare, 3_000, 0, 1_000);
+
+        assert_eq!(treaty.ceded_coverage_for(10_000).unwrap(), 3_000);
+        assert_eq!(treaty.ceded_premium_for(500).unwrap(), 150);
+        assert_eq!(treaty.recovery_for(2_000, 3_000).unwrap(), 600);
+
+        // Recoveries stop at the limit
+        treaty.recoveries = 800;
+        assert_eq!(treaty.recovery_for(2_000, 3_000).unwrap(), 200);
+    }
+
+    #[test]
+    fn test_excess_of_loss_limit_is_aggregate() {
+        let mut treaty = treaty(TreatyKind::ExcessOfLoss, 1_000, 5_000, 8_000);
+
+        assert_eq!(treaty.ceded_coverage_for(4_000).unwrap(), 0);
+        assert_eq!(treaty.ceded_coverage_for(10_000).unwrap(), 5_000);
+
+        // Open policies already take 5,000 of the 8,000 limit
+        treaty.ceded_coverage = 5_000;
+        assert_eq!(treaty.ceded_coverage_for(10_000).unwrap(), 3_000);
+
+        assert_eq!(treaty.recovery_for(4_000, 5_000).unwrap(), 0);
+        assert_eq!(treaty.recovery_for(7_000, 5_000).unwrap(), 2_000);
+        treaty.recoveries = 7_000;
+        assert_eq!(treaty.recovery_for(7_000, 5_000).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_recoveries_are_capped_by_the_policy_ceded_share() {
+        let mut treaty = treaty(TreatyKind::QuotaShare, 3_000, 0, 100_000);
+        let mut reinsurer = sample_pool();
+
+        // A policy written before the treaty ceded nothing, so it recovers nothing
+        assert_eq!(treaty.recovery_for(5_000, 0).unwrap(), 0);
+
+        // A ceded policy recovers no more than it ceded, across all of its claims
+        let mut ceded_coverage = treaty.ceded_coverage_for(10_000).unwrap();
+        treaty.ceded_coverage = ceded_coverage;
+        reinsurer.total_coverage_amount = ceded_coverage;
+        reinsurer.assumed_coverage = ceded_coverage;
+
+        let recovery = treaty.recovery_for(8_000, ceded_coverage).unwrap();
+        assert_eq!(recovery, 2_400);
+        treaty.recoveries += recovery;
+        treaty.release_ceded_coverage(&mut reinsurer, recovery);
+        ceded_coverage -= recovery;
+
+        assert_eq!(treaty.recovery_for(8_000, ceded_coverage).unwrap(), 600);
+        assert_eq!(treaty.ceded_coverage, 600);
+        assert_eq!(reinsurer.assumed_coverage, 600);
+    }
+
+    #[test]
+    fn test_ended_policy_hands_back_its_ceded_exposure() {
+        let mut treaty = treaty(TreatyKind::ExcessOfLoss, 1_000, 5_000, 8_000);
+        let mut reinsurer = sample_pool();
+        treaty.ceded_coverage = 8_000;
+        reinsurer.total_coverage_amount = 20_000;
+        reinsurer.assumed_coverage = 8_000;
+
+        treaty.release_ceded_coverage(&mut reinsurer, 5_000);
+        assert_eq!(treaty.ceded_coverage, 3_000);
+        assert_eq!(reinsurer.total_coverage_amount, 15_000);
+        assert_eq!(reinsurer.assumed_coverage, 3_000);
+        assert_eq!(treaty.ceded_coverage_for(10_000).unwrap(), 5_000);
+    }
+
+    #[test]
+    fn test_terminated_treaty_runs_off_before_it_is_closed() {
+        let mut pool = sample_pool();
+        let mut reinsurer = sample_pool();
+        let mut treaty = treaty(TreatyKind::QuotaShare, 3_000, 0, 100_000);
+        treaty.ceded_coverage = 3_000;
+        reinsurer.total_coverage_amount = 3_000;
+        reinsurer.assumed_coverage = 3_000;
+
+        // Only a pool with a live treaty can terminate one
+        assert_eq!(
+            pool.check_parameter_change(&ParameterChange::TerminateTreaty).err(),
+            Some(crate::errors::InsuranceError::NoActiveTreaty.into())
+        );
+        pool.reinsurance_treaty = Some(key(13));
+        assert!(pool.cedes_new_policies(&reinsurer));
+
+        pool.apply_parameter_change(&ParameterChange::TerminateTreaty, 0).unwrap();
+        assert!(!pool.cedes_new_policies(&reinsurer));
+        assert!(pool.apply_parameter_change(&ParameterChange::TerminateTreaty, 0).is_err());
+
+        // The policy already ceded keeps the treaty linked until it hands its exposure back
+        assert!(!pool.close_terminated_treaty(&treaty));
+        assert_eq!(pool.reinsurance_treaty, Some(key(13)));
+
+        treaty.release_ceded_coverage(&mut reinsurer, 3_000);
+        assert!(pool.close_terminated_treaty(&treaty));
+        assert_eq!(pool.reinsurance_treaty, None);
+        assert!(!pool.treaty_terminated);
+        assert_eq!(reinsurer.assumed_coverage, 0);
+    }
+
+    #[test]
+    fn test_live_treaty_stops_ceding_while_the_reinsurer_is_not_active() {
+        let mut pool = sample_pool();
+        let mut reinsurer = sample_pool();
+        pool.reinsurance_treaty = Some(key(13));
+
+        reinsurer.begin_wind_down(1_000).unwrap();
+        assert!(!pool.cedes_new_policies(&reinsurer));
+
+        // Nothing was terminated, so the treaty stays linked
+        let treaty = treaty(TreatyKind::QuotaShare, 3_000, 0, 100_000);
+        assert!(!pool.close_terminated_treaty(&treaty));
+        assert_eq!(pool.reinsurance_treaty, Some(key(13)));
+    }
+
+    #[test]
+    fn test_only_an_active_reinsurer_assumes_exposure() {
+        let mut reinsurer = sample_pool();
+        reinsurer.total_value_locked = 10_000;
+        reinsurer.max_utilization_bps = 8_000;
+
+        reinsurer.assume_coverage(5_000).unwrap();
+        assert_eq!(reinsurer.total_coverage_amount, 5_000);
+        assert_eq!(reinsurer.assumed_coverage, 5_000);
+
+        // Assumed exposure counts against the reinsurer's own capacity
+        assert_eq!(
+            reinsurer.assume_coverage(3_001).err(),
+            Some(crate::errors::InsuranceError::InsufficientLiquidity.into())
+        );
+
+        reinsurer.begin_wind_down(1_000).unwrap();
+        assert_eq!(
+            reinsurer.assume_coverage(1).err(),
+            Some(crate::errors::InsuranceError::ReinsurerNotActive.into())
+        );
+    }
+
+    #[test]
+    fn test_treaty_terms_must_cede_premium() {
+        let terms = |kind, cession_bps, attachment_point| TreatyTerms {
+            kind,
+            cession_bps,
+            attachment_point,
+            limit: 1_000,
+        };
+        let validate = crate::utils::validations::validate_treaty_terms;
+
+        assert!(validate(&terms(TreatyKind::QuotaShare, 2_000, 0)).is_ok());
+        assert!(validate(&terms(TreatyKind::QuotaShare, 0, 0)).is_err());
+        assert!(validate(&terms(TreatyKind::ExcessOfLoss, 500, 100)).is_ok());
+        assert!(validate(&terms(TreatyKind::ExcessOfLoss, 0, 100)).is_err());
+        assert!(validate(&terms(TreatyKind::ExcessOfLoss, 500, 0)).is_err());
+    }
+
+    fn registry_entry(pool: Pubkey) -> PoolEntry {
+        PoolEntry {
+            pool,
+            authority: key(1),
+            pool_id: 1,
+            coverage_types: ALL_COVERAGE_TYPES,
+            status: PoolStatus::Active,
+        }
+    }
+
+    #[test]
+    fn test_registry_page_lists_each_pool_once_until_full() {
+        let mut registry = PoolRegistry { page: 0, pools: Vec::new(), bump: 255 };
+        registry.register(registry_entry(key(1))).unwrap();
+        assert_eq!(
+            registry.register(registry_entry(key(1))).err(),
+            Some(crate::errors::InsuranceError::PoolAlreadyRegistered.into())
+        );
+
+        for n in 2..=REGISTRY_PAGE_SIZE as u8 {
+            registry.register(registry_entry(key(n))).unwrap();
+        }
+        assert_eq!(
+            registry.register(registry_entry(key(200))).err(),
+            Some(crate::errors::InsuranceError::RegistryFull.into())
+        );
+    }
+
+    #[test]
+    fn test_pool_id_seed_is_empty_for_legacy_pools() {
+        let mut pool = sample_pool();
+        assert_eq!(pool.pool_id_seed(), pool.pool_id.to_le_bytes().to_vec());
+
+        pool.pool_id = LEGACY_POOL_ID;
+        assert!(pool.pool_id_seed().is_empty());
+    }
+
+    fn withdrawal_config() -> WithdrawalConfig {
+        WithdrawalConfig {
+            cooldown_seconds: 14 * SECONDS_PER_DAY,
+            epoch_duration: SECONDS_PER_DAY,
+            max_epoch_withdrawal_bps: 1_000,
+        }
+    }
+
+    #[test]
+    fn test_withdrawal_waits_out_the_cooldown_unless_cancelled() {
+        let config = withdrawal_config();
+        let mut request = WithdrawalRequest {
+            pool: key(1),
+            provider: key(2),
+            share_mint: key(3),
+            shield_amount: 1_000,
+            sequence: 0,
+            requested_at: 100,
+            bump: 255,
+        };
+
+        assert!(!request.is_executable(&config, 100 + config.cooldown_seconds - 1));
+        assert!(request.is_executable(&config, 100 + config.cooldown_seconds));
+
+        // Once its SHIELD is back with the provider it only needs clearing from the queue
+        request.shield_amount = 0;
+        assert!(request.is_cancelled());
+        assert!(request.is_executable(&config, 100));
+    }
+
+    #[test]
+    fn test_withdrawal_cap_is_set_per_epoch() {
+        let config = withdrawal_config();
+        let mut queue = WithdrawalQueue::default();
+
+        queue.roll_epoch(&config, 1_000_000, SECONDS_PER_DAY);
+        assert_eq!(queue.remaining_capacity(&config).unwrap(), 100_000);
+
+        // Later in the same epoch the snapshot stands, whatever TVL does
+        queue.epoch_withdrawn = 60_000;
+        queue.roll_epoch(&config, 5_000_000, 2 * SECONDS_PER_DAY - 1);
+        assert_eq!(queue.remaining_capacity(&config).unwrap(), 40_000);
+        queue.epoch_withdrawn = 100_000;
+        assert_eq!(queue.remaining_capacity(&config).unwrap(), 0);
+
+        // The next epoch starts over from the TVL left
+        queue.roll_epoch(&config, 900_000, 2 * SECONDS_PER_DAY);
+        assert_eq!(queue.epoch_withdrawn, 0);
+        assert_eq!(queue.remaining_capacity(&config).unwrap(), 90_000);
+    }
+
+    #[test]
+    fn test_listing_sale_pays_the_fee_out_of_the_ask() {
+        let listing = CoverageListing {
+            seller: key(1),
+            coverage: key(2),
+            pool: key(3),
+            ask_price: 10_000,
+            listed_at: 0,
+            bump: 255,
+        };
+
+        assert_eq!(listing.sale_split(10_000, 250).unwrap(), (9_750, 250));
+        assert_eq!(listing.sale_split(u64::MAX, 0).unwrap(), (10_000, 0));
+        assert_eq!(listing.sale_split(10_000, 3).unwrap(), (9_997, 3));
+    }
+
+    #[test]
+    fn test_listing_sale_refuses_a_price_above_the_buyers_maximum() {
+        let listing = CoverageListing {
+            seller: key(1),
+            coverage: key(2),
+            pool: key(3),
+            ask_price: 10_000,
+            listed_at: 0,
+            bump: 255,
+        };
+
+        assert_eq!(
+            listing.sale_split(9_999, 250).err(),
+            Some(crate::errors::InsuranceError::ListingPriceExceedsMaximum.into())
+        );
+    }
+
+    fn sample_coverage_params() -> CoverageParams {
+        CoverageParams {
+            coverage_amount: MIN_COVERAGE_TOKENS * 1_000_000,
+            coverage_period: SECONDS_PER_YEAR as u32,
+            coverage_type: CoverageType::ClinicalTrialFailure,
+            trigger_conditions: sample_trigger_conditions(),
+            risk_category: RiskCategory::Medium,
+            metadata_uri: "ipfs://policy".to_string(),
+            max_premium: u64::MAX,
+            nonce: 0,
+        }
+    }
+
+    #[test]
+    fn test_quote_breakdown_adds_up_to_the_amount_due() {
+        let mut pool = sample_pool();
+        pool.total_value_locked = 100 * MIN_COVERAGE_TOKENS * 1_000_000;
+        let params = sample_coverage_params();
+        let inputs = PricingInputs {
+            base_rate_bps: 500,
+            risk_multiplier_bps: 15_000,
+            ..Default::default()
+        };
+
+        let quote = crate::utils::calculations::quote_premium(
+            &pool,
+            params.coverage_amount,
+            params.coverage_period,
+            &params.risk_category,
+            &inputs,
+            Some(0),
+            0,
+        )
+        .unwrap();
+
+        // The pool fee is part of the quoted total, not added on top of it
+        assert!(quote.fee > 0 && quote.lives_discount > 0);
+        assert_eq!(quote.final_premium, quote.risk_premium + quote.fee - quote.lives_discount);
+        assert_eq!(quote.amount_due, quote.final_premium);
+        assert_eq!(quote.premium_mint, pool.settlement_mint);
+        assert_eq!(quote.expires_at, QUOTE_VALIDITY_SECONDS);
+    }
+
+    #[test]
+    fn test_coverage_limits_scale_with_settlement_decimals() {
+        let mut pool = sample_pool();
+        let usdc = 1_000_000;
+        pool.check_coverage_limits(MIN_COVERAGE_TOKENS * usdc, MAX_COVERAGE_TOKENS * usdc).unwrap();
+        assert!(pool.check_coverage_limits(MIN_COVERAGE_TOKENS * usdc - 1, 2 * MIN_COVERAGE_TOKENS * usdc).is_err());
+        assert!(pool.check_coverage_limits(MIN_COVERAGE_TOKENS * usdc, MAX_COVERAGE_TOKENS * usdc + 1).is_err());
+
+        // A wrapped SOL pool counts in lamports, so the same bounds are 1,000 times larger
+        pool.settlement_decimals = 9;
+        let sol = 1_000_000_000;
+        assert!(pool.check_coverage_limits(MIN_COVERAGE_TOKENS * usdc, MAX_COVERAGE_TOKENS * usdc).is_err());
+        pool.check_coverage_limits(MIN_COVERAGE_TOKENS * sol, MAX_COVERAGE_TOKENS * sol).unwrap();
+
+        let change = ParameterChange::CoverageLimits {
+            min_coverage_amount: MIN_COVERAGE_TOKENS * usdc,
+            max_coverage_amount: MAX_COVERAGE_TOKENS * usdc,
+        };
+        assert!(pool.apply_parameter_change(&change, 0).is_err());
+    }
+
+    #[test]
+    fn test_premium_over_the_slippage_bound_is_refused() {
+        let mut pool = sample_pool();
+        pool.total_value_locked = 100 * MIN_COVERAGE_TOKENS * 1_000_000;
+        let params = sample_coverage_params();
+        let quote = crate::utils::calculations::quote_premium(
+            &pool,
+            params.coverage_amount,
+            params.coverage_period,
+            &params.risk_category,
+            &PricingInputs::default(),
+            None,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(quote.amount_within(quote.amount_due).unwrap(), quote.amount_due);
+        assert_eq!(
+            quote.amount_within(quote.amount_due - 1).err(),
+            Some(crate::errors::InsuranceError::PremiumExceedsMaximum.into())
+        );
+    }
+
+    #[test]
+    fn test_quote_expiry_is_checked_without_overflow() {
+        let mut pool = sample_pool();
+        pool.total_value_locked = 100 * MIN_COVERAGE_TOKENS * 1_000_000;
+        let params = sample_coverage_params();
+        let quote_at = |now| crate::utils::calculations::quote_premium(
+            &pool,
+            params.coverage_amount,
+            params.coverage_period,
+            &params.risk_category,
+            &PricingInputs::default(),
+            None,
+            now,
+        );
+
+        assert_eq!(quote_at(100).unwrap().expires_at, 100 + QUOTE_VALIDITY_SECONDS);
+        assert_eq!(
+            quote_at(i64::MAX).err(),
+            Some(crate::errors::InsuranceError::QuoteExpired.into())
+        );
+    }
+
+    #[test]
+    fn test_version_2_pool_keeps_its_state_when_reallocated_for_a_third_vault() {
+        let mut pool = sample_pool();
+        pool.version = 2;
+        pool.wind_down.open_coverages = Some(4);
+        pool.asset_ledgers = vec![
+            AssetLedger {
+                mint: key(5),
+                vault: key(12),
+                liquidity_deposited: 100,
+                liquidity_withdrawn: 0,
+                premiums_collected: 10,
+                claims_paid: 0,
+                bump: 254,
+            };
+            2
+        ];
+        let mut data = Vec::new();
+        pool.try_serialize(&mut data).unwrap();
+        assert!(data.len() < InsurancePool::SIZE);
+
+        let (mut migrated, from_version) =
+            decode_versioned::<InsurancePool>(&data, data.len()).unwrap();
+        assert_eq!(from_version, 2);
+        migrated.upgrade(from_version);
+
+        assert_eq!(migrated.version, POOL_ACCOUNT_VERSION);
+        assert_eq!(migrated.asset_ledgers, pool.asset_ledgers);
+        assert_eq!(migrated.wind_down, pool.wind_down);
+    }
+
+    #[test]
+    fn test_baseline_decoding_checks_the_discriminator() {
+        let data = baseline_account(
+            CoverageAccount::DISCRIMINATOR,
+            &[0; BASELINE_POOL_SIZE - 8],
+            BASELINE_POOL_SIZE,
+            InsurancePool::SIZE,
+        );
+
+        assert!(decode_versioned::<InsurancePool>(&data, BASELINE_POOL_SIZE).is_err());
+    }
+
+    fn tracked_pool() -> InsurancePool {
+        let mut pool = sample_pool();
+        pool.wind_down.open_coverages = Some(0);
+        pool
+    }
+
+    #[test]
+    fn test_each_pool_status_has_its_own_instruction_matrix() {
+        use PoolOperation::*;
+        let operations = [
+            WriteCoverage, TradeCoverage, AddLiquidity, RequestWithdrawal, ProcessWithdrawal,
+            SubmitClaim, ProcessClaim, CancelCoverage, RedeemShares,
+        ];
+        let allowed = |status: PoolStatus| -> Vec<PoolOperation> {
+            operations.iter().copied().filter(|operation| status.allows(*operation)).collect()
+        };
+
+        assert_eq!(
+            allowed(PoolStatus::Active),
+            vec![WriteCoverage, TradeCoverage, AddLiquidity, RequestWithdrawal, ProcessWithdrawal, SubmitClaim, ProcessClaim]
+        );
+        assert_eq!(allowed(PoolStatus::Paused), vec![SubmitClaim, ProcessClaim]);
+        assert_eq!(allowed(PoolStatus::WindingDown), vec![SubmitClaim, ProcessClaim, CancelCoverage]);
+        assert_eq!(allowed(PoolStatus::Redeeming), vec![ProcessWithdrawal, RedeemShares]);
+    }
+
+    #[test]
+    fn test_wind_down_supersedes_pause_and_cannot_restart() {
+        let mut pool = tracked_pool();
+        pool.is_paused = true;
+        assert!(pool.require_allows(PoolOperation::WriteCoverage).is_err());
+
+        pool.begin_wind_down(1_000).unwrap();
+        assert_eq!(pool.status(), PoolStatus::WindingDown);
+        assert!(pool.require_allows(PoolOperation::CancelCoverage).is_ok());
+        assert!(pool.begin_wind_down(2_000).is_err());
+
+        // Lifting the pause does not bring the pool back
+        pool.is_paused = false;
+        assert!(pool.require_allows(PoolOperation::WriteCoverage).is_err());
+    }
+
+    #[test]
+    fn test_redemption_waits_for_policies_claims_and_premiums() {
+        let mut pool = tracked_pool();
+        pool.track_coverage(5_000).unwrap();
+        pool.track_coverage(9_000).unwrap();
+        assert!(pool.open_redemption(1_000).is_err()); // still active
+
+        pool.begin_wind_down(1_000).unwrap();
+        assert!(pool.open_redemption(1_000).is_err());
+
+        // One policy cancelled, the other still open
+        pool.release_coverage(0);
+        assert!(!pool.is_run_off_complete(2_000));
+
+        // A claim filed before expiry holds redemption back even after every policy closes
+        pool.release_coverage(0);
+        pool.reserved_claims = 100;
+        assert!(!pool.is_run_off_complete(2_000));
+        pool.reserved_claims = 0;
+
+        // Premium not yet pushed through accrue_premium still belongs to the waterfall
+        pool.unearned_premiums = 50;
+        assert!(!pool.is_run_off_complete(2_000));
+        pool.unearned_premiums = 0;
+
+        pool.open_redemption(2_000).unwrap();
+        assert_eq!(pool.status(), PoolStatus::Redeeming);
+        assert!(pool.open_redemption(3_000).is_err());
+    }
+
+    #[test]
+    fn test_reinsurer_redemption_waits_for_ceded_exposure_to_end() {
+        let mut reinsurer = tracked_pool();
+        reinsurer.total_value_locked = 10_000;
+        reinsurer.assume_coverage(2_000).unwrap();
+        reinsurer.begin_wind_down(1_000).unwrap();
+        assert!(!reinsurer.is_run_off_complete(2_000));
+
+        let mut treaty = treaty(TreatyKind::QuotaShare, 2_000, 0, 0);
+        treaty.ceded_coverage = 2_000;
+        treaty.release_ceded_coverage(&mut reinsurer, 2_000);
+        reinsurer.open_redemption(2_000).unwrap();
+    }
+
+    #[test]
+    fn test_policies_past_their_end_time_need_not_be_expired_one_by_one() {
+        let mut pool = tracked_pool();
+        pool.track_coverage(5_000).unwrap();
+        pool.begin_wind_down(1_000).unwrap();
+
+        // Claims can still be filed at the end time itself
+        assert!(!pool.is_run_off_complete(5_000));
+        assert!(pool.is_run_off_complete(5_001));
+    }
+
+    #[test]
+    fn test_migrated_pool_waits_out_the_longest_coverage_period() {
+        // Pools written before version 2 never counted their policies
+        let mut pool = sample_pool();
+        pool.track_coverage(5_000).unwrap();
+        assert_eq!(pool.wind_down.open_coverages, None);
+
+        pool.begin_wind_down(1_000).unwrap();
+        let run_off_ends = 1_000 + MAX_COVERAGE_PERIOD as i64;
+        assert_eq!(pool.wind_down.coverage_ends_by, run_off_ends);
+        assert!(!pool.is_run_off_complete(run_off_ends));
+        assert!(pool.is_run_off_complete(run_off_ends + 1));
+    }
+
+    #[test]
+    fn test_redeemable_assets_leave_rewards_and_tranches_alone() {
+        let mut pool = sample_pool();
+        pool.premium_rewards.rewards_owed = 100;
+        assert_eq!(pool.redeemable_assets(&pool.shield_token_mint, 1_000).unwrap(), 900);
+
+        pool.tranches = vec![Tranche {
+            kind: TrancheKind::Senior,
+            share_mint: key(10),
+            assets: 600,
+            premium_share_bps: 10_000,
+        }];
+        assert_eq!(pool.redeemable_assets(&pool.shield_token_mint, 1_000).unwrap(), 300);
+        assert_eq!(pool.redeemable_assets(&key(10), 1_000).unwrap(), 600);
+        assert!(pool.redeemable_assets(&key(11), 1_000).is_err());
+    }
+
+    #[test]
+    fn test_other_vaults_are_redeemed_by_shield_or_split_between_tranches() {
+        let mut pool = sample_pool();
+        assert_eq!(pool.redeemable_vault_assets(&pool.shield_token_mint, 900).unwrap(), 900);
+
+        pool.tranches = vec![
+            Tranche { assets: 600, ..tranche(TrancheKind::Senior, key(10), 3_000) },
+            Tranche { assets: 300, ..tranche(TrancheKind::Junior, key(11), 7_000) },
+        ];
+        assert_eq!(pool.redeemable_vault_assets(&key(10), 900).unwrap(), 600);
+        assert_eq!(pool.redeemable_vault_assets(&key(11), 900).unwrap(), 300);
+        assert_eq!(pool.redeemable_vault_assets(&pool.shield_token_mint, 900).unwrap(), 0);
+
+        // Tranches with nothing left still share the other vaults
+        for tranche in pool.tranches.iter_mut() {
+            tranche.assets = 0;
+        }
+        assert_eq!(pool.redeemable_vault_assets(&key(10), 900).unwrap(), 450);
+    }
+
+    fn tranche(kind: TrancheKind, share_mint: Pubkey, premium_share_bps: u16) -> Tranche {
+        Tranche { kind, share_mint, assets: 0, premium_share_bps }
+    }
+
+    fn tranched_pool(senior_bps: u16, junior_bps: u16) -> InsurancePool {
+        let mut pool = sample_pool();
+        pool.add_tranche(tranche(TrancheKind::Senior, key(10), senior_bps)).unwrap();
+        pool.add_tranche(tranche(TrancheKind::Junior, key(11), junior_bps)).unwrap();
+        pool
+    }
+
+    #[test]
+    fn test_junior_tranche_must_out_earn_the_senior_tranche() {
+        let mut pool = sample_pool();
+        pool.add_tranche(tranche(TrancheKind::Senior, key(10), 4_000)).unwrap();
+
+        assert!(pool.add_tranche(tranche(TrancheKind::Junior, key(11), 4_000)).is_err());
+        assert!(pool.add_tranche(tranche(TrancheKind::Junior, key(11), 7_000)).is_err());
+        assert!(pool.add_tranche(tranche(TrancheKind::Senior, key(12), 1_000)).is_err());
+        pool.add_tranche(tranche(TrancheKind::Junior, key(11), 6_000)).unwrap();
+        assert!(pool.add_tranche(tranche(TrancheKind::Junior, key(12), 0)).is_err());
+
+        // Order of registration does not matter
+        let mut pool = sample_pool();
+        pool.add_tranche(tranche(TrancheKind::Junior, key(11), 3_000)).unwrap();
+        assert!(pool.add_tranche(tranche(TrancheKind::Senior, key(10), 3_000)).is_err());
+        pool.add_tranche(tranche(TrancheKind::Senior, key(10), 2_000)).unwrap();
+    }
+
+    #[test]
+    fn test_premium_remainder_goes_to_the_senior_tranche() {
+        let mut pool = tranched_pool(3_333, 6_666);
+        pool.allocate_premium(1_000).unwrap();
+
+        // 333 + 666 by the cuts, the unassigned basis point and the dust go to the senior tranche
+        assert_eq!(pool.tranches[0].assets, 334);
+        assert_eq!(pool.tranches[1].assets, 666);
+    }
+
+    #[test]
+    fn test_premium_remainder_goes_to_a_lone_junior_tranche() {
+        let mut pool = sample_pool();
+        pool.add_tranche(tranche(TrancheKind::Junior, key(11), 7_000)).unwrap();
+        pool.allocate_premium(999).unwrap();
+
+        assert_eq!(pool.tranches[0].assets, 999);
+    }
+
+    #[test]
+    fn test_loss_hits_the_junior_tranche_first() {
+        let mut pool = tranched_pool(3_000, 7_000);
+        pool.tranches[0].assets = 1_000;
+        pool.tranches[1].assets = 400;
+
+        let allocation = pool.absorb_loss(300).unwrap();
+        assert_eq!(allocation, LossAllocation { junior_loss: 300, senior_loss: 0 });
+
+        let allocation = pool.absorb_loss(500).unwrap();
+        assert_eq!(allocation, LossAllocation { junior_loss: 100, senior_loss: 400 });
+        assert_eq!(pool.tranches[0].assets, 600);
+        assert_eq!(pool.tranches[1].assets, 0);
+
+        assert!(pool.absorb_loss(601).is_err());
+    }
+
+    #[test]
+    fn test_max_size_proposal_round_trips() {
+        // Staking config changes are the widest action
+        let proposal = Proposal {
+            governance: key(1),
+            proposer: key(2),
+            id: u64::MAX,
+            actions: vec![
+                ParameterChange::StakingConfig { config: max_staking_config() };
+                MAX_PROPOSAL_ACTIONS
+            ],
+            created_at: i64::MAX,
+            voting_ends_at: i64::MAX,
+            yes_votes: u64::MAX,
+            no_votes: u64::MAX,
+            status: ProposalStatus::Voting,
+            bump: 255,
+        };
+
+        assert_round_trips_at_size(&proposal, Proposal::SIZE);
+    }
+
+    #[test]
+    fn test_max_size_timelock_operation_round_trips() {
+        // A passed proposal queues all of its actions as one operation
+        let operation = TimelockOperation {
+            pool: key(1),
+            id: u64::MAX,
+            proposer: key(2),
+            payer: key(3),
+            actions: vec![
+                ParameterChange::StakingConfig { config: max_staking_config() };
+                MAX_PROPOSAL_ACTIONS
+            ],
+            queued_at: i64::MAX,
+            eta: i64::MAX,
+            bump: 255,
+        };
+
+        assert_round_trips_at_size(&operation, TimelockOperation::SIZE);
+    }
+
+    #[test]
+    fn test_timelock_operations_take_sequential_ids() {
+        let mut pool = sample_pool();
+        pool.timelock.delay = 3_600;
+        pool.timelock_operation_count = 4;
+
+        assert_eq!(pool.next_timelock_operation(1_000).unwrap(), (4, 4_600));
+        assert_eq!(pool.next_timelock_operation(2_000).unwrap(), (5, 5_600));
+        assert_eq!(pool.timelock_operation_count, 6);
+
+        assert!(pool.next_timelock_operation(i64::MAX).is_err());
+    }
+
+    fn pool_roles(admin: Pubkey) -> PoolRoles {
+        let mut roles = PoolRoles { pool: key(1), members: Vec::new(), bump: 255 };
+        roles.grant(admin, Role::Admin).unwrap();
+        roles
+    }
+
+    fn grant(member: Pubkey, role: Role) -> ParameterChange {
+        ParameterChange::GrantRole { member, role }
+    }
+
+    fn revoke(member: Pubkey, role: Role) -> ParameterChange {
+        ParameterChange::RevokeRole { member, role }
+    }
+
+    #[test]
+    fn test_role_changes_apply_to_the_pools_roles() {
+        let mut roles = pool_roles(key(2));
+
+        roles.apply_parameter_change(&grant(key(3), Role::Guardian)).unwrap();
+        roles.apply_parameter_change(&grant(key(3), Role::ClaimsProcessor)).unwrap();
+        assert!(roles.has_role(&key(3), Role::Guardian));
+        assert!(roles.has_role(&key(3), Role::ClaimsProcessor));
+        assert_eq!(roles.members.len(), 2);
+
+        roles.apply_parameter_change(&revoke(key(3), Role::Guardian)).unwrap();
+        assert!(!roles.has_role(&key(3), Role::Guardian));
+        assert!(roles.has_role(&key(3), Role::ClaimsProcessor));
+
+        // Dropped once it holds nothing
+        roles.apply_parameter_change(&revoke(key(3), Role::ClaimsProcessor)).unwrap();
+        assert_eq!(roles.members.len(), 1);
+
+        // Revoking a role the member doesn't hold is a mistake in the proposal
+        assert!(roles.apply_parameter_change(&revoke(key(3), Role::Guardian)).is_err());
+
+        // Pool parameters are not the roles' to apply, nor roles the pool's
+        assert!(roles.apply_parameter_change(&ParameterChange::Pause { paused: true }).is_err());
+        assert!(sample_pool().apply_parameter_change(&grant(key(3), Role::Admin), 0).is_err());
+    }
+
+    #[test]
+    fn test_last_admin_cannot_be_revoked() {
+        let mut roles = pool_roles(key(2));
+
+        assert_eq!(
+            roles.apply_parameter_change(&revoke(key(2), Role::Admin)).err(),
+            Some(crate::errors::InsuranceError::InvalidRoleAssignment.into())
+        );
+
+        // With a second admin in place the first can go
+        roles.apply_parameter_change(&grant(key(3), Role::Admin)).unwrap();
+        roles.apply_parameter_change(&revoke(key(2), Role::Admin)).unwrap();
+        assert!(!roles.has_role(&key(2), Role::Admin));
+        assert!(roles.apply_parameter_change(&revoke(key(3), Role::Admin)).is_err());
+    }
+
+    #[test]
+    fn test_authority_nomination_is_a_parameter_change() {
+        let mut pool = sample_pool();
+        let nominate = |new_authority| ParameterChange::Authority { new_authority };
+
+        pool.apply_parameter_change(&nominate(Some(key(9))), 0).unwrap();
+        assert_eq!(pool.pending_authority, Some(key(9)));
+        pool.apply_parameter_change(&nominate(None), 0).unwrap();
+        assert_eq!(pool.pending_authority, None);
+
+        assert_eq!(
+            pool.apply_parameter_change(&nominate(Some(pool.authority)), 0).err(),
+            Some(crate::errors::InsuranceError::InvalidAuthority.into())
+        );
+        let validate = crate::utils::validations::validate_parameter_change;
+        assert!(validate(&nominate(Some(Pubkey::default()))).is_err());
+
+        // Accepting moves roles too, so only the timelock's executor may apply it
+        assert!(pool.apply_parameter_change(&ParameterChange::AcceptAuthority, 0).is_err());
+    }
+
+    #[test]
+    fn test_authority_moves_only_to_the_nominee_and_takes_the_admin_role() {
+        let mut pool = sample_pool();
+        let mut roles = pool_roles(pool.authority);
+        let old_authority = pool.authority;
+
+        assert!(pool.transfer_authority(&mut roles, key(9)).is_err());
+
+        pool.pending_authority = Some(key(9));
+        assert!(pool.transfer_authority(&mut roles, key(10)).is_err());
+        assert_eq!(pool.transfer_authority(&mut roles, key(9)).unwrap(), old_authority);
+
+        assert_eq!(pool.authority, key(9));
+        assert_eq!(pool.pending_authority, None);
+        assert!(roles.has_role(&key(9), Role::Admin));
+        assert!(!roles.has_role(&old_authority, Role::Admin));
+    }
+
+    #[test]
+    fn test_role_grants_need_a_member() {
+        let validate = crate::utils::validations::validate_parameter_change;
+
+        assert!(validate(&grant(key(3), Role::Guardian)).is_ok());
+        assert_eq!(
+            validate(&grant(Pubkey::default(), Role::Guardian)).err(),
+            Some(crate::errors::InsuranceError::InvalidRoleAssignment.into())
+        );
+    }
+
+    #[test]
+    fn test_timelock_operation_runs_from_its_eta_until_the_grace_period_ends() {
+        let operation = TimelockOperation {
+            pool: key(1),
+            id: 0,
+            proposer: key(2),
+            payer: key(2),
+            actions: vec![ParameterChange::Pause { paused: false }],
+            queued_at: 0,
+            eta: 1_000,
+            bump: 255,
+        };
+
+        assert_eq!(
+            operation.check_executable(999).err(),
+            Some(crate::errors::InsuranceError::TimelockNotReady.into())
+        );
+        assert!(operation.check_executable(1_000).is_ok());
+        assert!(operation.check_executable(1_000 + TIMELOCK_GRACE_PERIOD).is_ok());
+        assert_eq!(
+            operation.check_executable(1_001 + TIMELOCK_GRACE_PERIOD).err(),
+            Some(crate::errors::InsuranceError::TimelockExpired.into())
+        );
+    }
+
+    #[test]
+    fn test_timelock_delay_covers_the_withdrawal_cooldown() {
+        let validate = crate::utils::validations::validate_timelock_config;
+        let delay = TimelockConfig { delay: 7 * SECONDS_PER_DAY };
+        let cooldown = |days: i64| WithdrawalConfig {
+            cooldown_seconds: days * SECONDS_PER_DAY,
+            epoch_duration: SECONDS_PER_DAY,
+            max_epoch_withdrawal_bps: 1_000,
+        };
+
+        assert!(validate(&delay, &cooldown(7)).is_ok());
+        assert!(validate(&delay, &cooldown(8)).is_err());
+
+        // Nor can the cooldown later outgrow the delay
+        let mut pool = sample_pool();
+        pool.timelock = delay;
+        let longer = ParameterChange::WithdrawalConfig { config: cooldown(8) };
+        assert_eq!(
+            pool.apply_parameter_change(&longer, 0).err(),
+            Some(crate::errors::InsuranceError::InvalidWithdrawalConfig.into())
+        );
+        let shorter = ParameterChange::WithdrawalConfig { config: cooldown(3) };
+        pool.apply_parameter_change(&shorter, 0).unwrap();
+        assert_eq!(pool.withdrawal_config, cooldown(3));
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::state::{MAX_CUSTOM_CONDITIONS, MAX_CONDITION_TYPE_LEN};
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub struct OracleData {
     pub request_id: [u8; 32],
     pub timestamp: i64,
@@ -45,14 +46,14 @@ impl OracleData {
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub struct DataPoint {
     pub data_type: DataType,
     pub value: u64,
     pub source: String,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub enum DataType {
     ClinicalTrialResult { success: bool },
     RegulatoryDecision { approved: bool },
@@ -60,27 +61,25 @@ pub enum DataType {
     CustomMetric { name: String },
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub struct TriggerConditions {
     pub clinical_trial_failure: bool,
     pub regulatory_rejection: bool,
     pub ip_invalidation: bool,
     pub minimum_threshold: u64,
+    #[max_len(MAX_CUSTOM_CONDITIONS)]
     pub custom_conditions: Vec<CustomCondition>,
 }
 
-impl TriggerConditions {
-    pub const SIZE: usize = 1 + 1 + 1 + 8 + 4 + (32 * 5); // Max 5 custom conditions
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub struct CustomCondition {
+    #[max_len(MAX_CONDITION_TYPE_LEN)]
     pub condition_type: String,
     pub threshold_value: u64,
     pub comparison_operator: ComparisonOperator,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum ComparisonOperator {
     GreaterThan,
     LessThan,
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::InsurancePool;
+
+pub const REGISTRY_PAGE_SIZE: usize = 100;
+
+/// One page of the on-chain directory of pools, so clients can discover them without an
+/// indexer. Anyone may open the next page once the last one fills, so listing never
+/// blocks pool creation.
+#[account]
+#[derive(InitSpace)]
+pub struct PoolRegistry {
+    pub page: u32,
+    #[max_len(REGISTRY_PAGE_SIZE)]
+    pub pools: Vec<PoolEntry>,
+    pub bump: u8,
+}
+
+impl PoolRegistry {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    pub fn register(&mut self, entry: PoolEntry) -> Result<()> {
+        require!(
+            !self.pools.iter().any(|existing| existing.pool == entry.pool),
+            InsuranceError::PoolAlreadyRegistered
+        );
+        require!(self.pools.len() < REGISTRY_PAGE_SIZE, InsuranceError::RegistryFull);
+
+        self.pools.push(entry);
+        Ok(())
+    }
+
+    pub fn entry_mut(&mut self, pool: &Pubkey) -> Result<&mut PoolEntry> {
+        self.pools
+            .iter_mut()
+            .find(|entry| entry.pool == *pool)
+            .ok_or_else(|| InsuranceError::PoolNotRegistered.into())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct PoolEntry {
+    pub pool: Pubkey,
+    pub authority: Pubkey,
+    pub pool_id: u64,
+    pub coverage_types: u8, // bitmask of CoverageType::mask
+    pub status: PoolStatus,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub enum PoolStatus {
+    Active,
+    Paused,
+    WindingDown,
+    Redeeming,
+}
+
+impl InsurancePool {
+    /// Retirement supersedes a pause: once winding down, the pool never takes new risk again
+    pub fn status(&self) -> PoolStatus {
+        if self.wind_down.redemption_opened_at.is_some() {
+            PoolStatus::Redeeming
+        } else if self.wind_down.started_at.is_some() {
+            PoolStatus::WindingDown
+        } else if self.is_paused {
+            PoolStatus::Paused
+        } else {
+            PoolStatus::Active
+        }
+    }
+}
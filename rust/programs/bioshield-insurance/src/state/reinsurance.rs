@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, PoolStatus, BASIS_POINTS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub enum TreatyKind {
+    /// The reinsurer takes `cession_bps` of every policy's exposure and every loss
+    QuotaShare,
+    /// The reinsurer pays the part of each loss above `attachment_point`, up to `limit` in total
+    ExcessOfLoss,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct TreatyTerms {
+    pub kind: TreatyKind,
+    pub cession_bps: u16, // share of premiums ceded, and of losses under quota share
+    pub attachment_point: u64,
+    pub limit: u64,
+}
+
+/// Risk ceded by a pool to a backstop pool
+#[account]
+#[derive(InitSpace)]
+pub struct ReinsuranceTreaty {
+    pub cedent: Pubkey,
+    pub reinsurer: Pubkey,
+    pub terms: TreatyTerms,
+    pub ceded_coverage: u64,
+    pub ceded_premiums: u64,
+    pub recoveries: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl ReinsuranceTreaty {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    /// Exposure of a new policy carried by the reinsurer. An excess-of-loss limit is
+    /// aggregate, so policies only take up what the open ones have left of it.
+    pub fn ceded_coverage_for(&self, coverage_amount: u64) -> Result<u64> {
+        match self.terms.kind {
+            TreatyKind::QuotaShare => apply_bps(coverage_amount, self.terms.cession_bps),
+            TreatyKind::ExcessOfLoss => Ok(std::cmp::min(
+                coverage_amount.saturating_sub(self.terms.attachment_point),
+                self.terms.limit.saturating_sub(self.ceded_coverage),
+            )),
+        }
+    }
+
+    /// Hand back a policy's ceded exposure once it expires, is cancelled or is exhausted
+    pub fn release_ceded_coverage(&mut self, reinsurer: &mut InsurancePool, ceded_coverage: u64) {
+        self.ceded_coverage = self.ceded_coverage.saturating_sub(ceded_coverage);
+        reinsurer.total_coverage_amount = reinsurer.total_coverage_amount.saturating_sub(ceded_coverage);
+        reinsurer.assumed_coverage = reinsurer.assumed_coverage.saturating_sub(ceded_coverage);
+    }
+
+    /// Share of an earned premium owed to the reinsurer
+    pub fn ceded_premium_for(&self, premium: u64) -> Result<u64> {
+        apply_bps(premium, self.terms.cession_bps)
+    }
+
+    /// Part of a claim payout recovered from the reinsurer, capped by what is left of the limit
+    /// and of the exposure the policy ceded. A policy written before the treaty, or while the
+    /// reinsurer took no new risk, ceded nothing and recovers nothing.
+    pub fn recovery_for(&self, payout: u64, ceded_coverage: u64) -> Result<u64> {
+        let recovery = match self.terms.kind {
+            TreatyKind::QuotaShare => apply_bps(payout, self.terms.cession_bps)?,
+            TreatyKind::ExcessOfLoss => payout.saturating_sub(self.terms.attachment_point),
+        };
+        let remaining_limit = self.terms.limit.saturating_sub(self.recoveries);
+
+        Ok(recovery.min(remaining_limit).min(ceded_coverage))
+    }
+}
+
+pub(crate) fn apply_bps(amount: u64, bps: u16) -> Result<u64> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?
+        .checked_div(BASIS_POINTS as u128)
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+    u64::try_from(share).map_err(|_| InsuranceError::PremiumCalculationOverflow.into())
+}
+
+impl InsurancePool {
+    /// Whether new policies cede to `reinsurer`. A terminated treaty, or a reinsurer that
+    /// is paused or winding down, leaves the pool to retain the whole risk.
+    pub fn cedes_new_policies(&self, reinsurer: &InsurancePool) -> bool {
+        !self.treaty_terminated && reinsurer.status() == PoolStatus::Active
+    }
+
+    /// Unlink a terminated treaty once the last policy it covered has handed back its
+    /// exposure, so the pool can register another. Returns whether it was unlinked.
+    pub fn close_terminated_treaty(&mut self, treaty: &ReinsuranceTreaty) -> bool {
+        if !self.treaty_terminated || treaty.ceded_coverage > 0 {
+            return false;
+        }
+
+        self.reinsurance_treaty = None;
+        self.treaty_terminated = false;
+        true
+    }
+
+    /// Take on exposure ceded by another pool's treaty. Only an active pool writes new
+    /// risk, and the exposure counts against its capacity like a policy of its own.
+    pub fn assume_coverage(&mut self, ceded_coverage: u64) -> Result<()> {
+        require!(
+            self.status() == PoolStatus::Active,
+            InsuranceError::ReinsurerNotActive
+        );
+
+        self.total_coverage_amount = self.total_coverage_amount
+            .checked_add(ceded_coverage)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+        self.assumed_coverage = self.assumed_coverage
+            .checked_add(ceded_coverage)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+        require!(self.total_value_locked > 0, InsuranceError::InsufficientLiquidity);
+        let utilization_bps = crate::utils::calculations::calculate_utilization_rate(
+            self.total_coverage_amount,
+            self.total_value_locked,
+        )?;
+        require!(
+            utilization_bps <= self.max_utilization_bps as u64,
+            InsuranceError::InsufficientLiquidity
+        );
+
+        Ok(())
+    }
+}
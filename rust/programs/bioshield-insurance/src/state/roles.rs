@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, ParameterChange};
+
+pub const MAX_ROLE_MEMBERS: usize = 16;
+
+/// Keys holding privileged roles in one pool
+#[account]
+#[derive(InitSpace)]
+pub struct PoolRoles {
+    pub pool: Pubkey,
+    #[max_len(MAX_ROLE_MEMBERS)]
+    pub members: Vec<RoleMember>,
+    pub bump: u8,
+}
+
+impl PoolRoles {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    pub fn has_role(&self, key: &Pubkey, role: Role) -> bool {
+        self.members
+            .iter()
+            .any(|member| member.member == *key && member.roles & role.mask() != 0)
+    }
+
+    pub fn grant(&mut self, key: Pubkey, role: Role) -> Result<()> {
+        require!(key != Pubkey::default(), InsuranceError::InvalidRoleAssignment);
+
+        if let Some(member) = self.members.iter_mut().find(|member| member.member == key) {
+            member.roles |= role.mask();
+            return Ok(());
+        }
+
+        require!(
+            self.members.len() < MAX_ROLE_MEMBERS,
+            InsuranceError::RoleMembersFull
+        );
+        self.members.push(RoleMember { member: key, roles: role.mask() });
+
+        Ok(())
+    }
+
+    /// Remove `role` from `key`, dropping the member once it holds no roles.
+    /// The last admin can't be revoked, or the pool's roles could never change again.
+    pub fn revoke(&mut self, key: &Pubkey, role: Role) -> Result<()> {
+        require!(self.has_role(key, role), InsuranceError::InvalidRoleAssignment);
+        require!(
+            role != Role::Admin || self.admin_count() > 1,
+            InsuranceError::InvalidRoleAssignment
+        );
+
+        for member in self.members.iter_mut().filter(|member| member.member == *key) {
+            member.roles &= !role.mask();
+        }
+        self.members.retain(|member| member.roles != 0);
+
+        Ok(())
+    }
+
+    /// Apply a matured role change; other changes belong to the pool
+    pub fn apply_parameter_change(&mut self, change: &ParameterChange) -> Result<()> {
+        match change {
+            ParameterChange::GrantRole { member, role } => self.grant(*member, *role),
+            ParameterChange::RevokeRole { member, role } => self.revoke(member, *role),
+            _ => err!(InsuranceError::InvalidProposal),
+        }
+    }
+
+    fn admin_count(&self) -> usize {
+        self.members
+            .iter()
+            .filter(|member| member.roles & Role::Admin.mask() != 0)
+            .count()
+    }
+}
+
+impl InsurancePool {
+    /// Hand the pool to its nominated authority, moving the admin role with it; returns the old authority
+    pub fn transfer_authority(&mut self, roles: &mut PoolRoles, new_authority: Pubkey) -> Result<Pubkey> {
+        require!(
+            self.pending_authority == Some(new_authority),
+            InsuranceError::InvalidAuthority
+        );
+        let old_authority = self.authority;
+
+        roles.grant(new_authority, Role::Admin)?;
+        if roles.has_role(&old_authority, Role::Admin) {
+            roles.revoke(&old_authority, Role::Admin)?;
+        }
+
+        self.authority = new_authority;
+        self.pending_authority = None;
+
+        Ok(old_authority)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct RoleMember {
+    pub member: Pubkey,
+    pub roles: u8, // bitmask of Role::mask
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+pub enum Role {
+    /// Manages roles and queues timelocked parameter changes
+    Admin,
+    /// Pauses the pool and cancels queued parameter changes
+    Guardian,
+    /// Settles claims against oracle data
+    ClaimsProcessor,
+    /// Co-signs oracle-attested risk inputs when pricing coverage
+    OracleOperator,
+}
+
+impl Role {
+    pub fn mask(&self) -> u8 {
+        match self {
+            Role::Admin => 1 << 0,
+            Role::Guardian => 1 << 1,
+            Role::ClaimsProcessor => 1 << 2,
+            Role::OracleOperator => 1 << 3,
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{BASIS_POINTS, LIVES_DISCOUNT_WARMUP_SECONDS, MIN_STAKE_AGE_SECONDS};
+
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+pub const MAX_LOCK_BOOSTS: usize = 4;
+
+/// LIVES an insured has staked with the pool to qualify for premium discount tiers.
+/// Stake counts towards a tier in proportion to how long it has been held, so LIVES
+/// borrowed or bought just before a quote earns nothing.
+#[account]
+#[derive(InitSpace)]
+pub struct LivesStake {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub staked_since: i64, // deposit time, weighted by amount across top-ups
+    pub bump: u8,
+}
+
+impl LivesStake {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    /// Add LIVES, moving the stake's age towards `now` by the weight of the deposit.
+    /// Age past the warm-up is dropped first, so an old stake cannot lend it to a top-up.
+    pub fn deposit(&mut self, amount: u64, now: i64) -> Result<()> {
+        let total = self.amount
+            .checked_add(amount)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+        let matured_since = self.staked_since.max(now.saturating_sub(LIVES_DISCOUNT_WARMUP_SECONDS));
+        let weighted_since = (self.amount as i128 * matured_since as i128 +
+            amount as i128 * now as i128) / total as i128;
+
+        self.staked_since = weighted_since as i64;
+        self.amount = total;
+        Ok(())
+    }
+
+    /// Take LIVES out; what remains keeps its age
+    pub fn withdraw(&mut self, amount: u64) -> Result<()> {
+        self.amount = self.amount
+            .checked_sub(amount)
+            .ok_or(InsuranceError::InsufficientStake)?;
+        Ok(())
+    }
+
+    /// Stake counted towards a discount tier, ramping up over the warm-up period
+    pub fn discount_balance(&self, now: i64) -> u64 {
+        let age = now.saturating_sub(self.staked_since).clamp(0, LIVES_DISCOUNT_WARMUP_SECONDS);
+        (self.amount as u128 * age as u128 / LIVES_DISCOUNT_WARMUP_SECONDS as u128) as u64
+    }
+}
+
+/// SHIELD staked by one LP. Premiums accrue on the staked amount; LIVES emissions
+/// accrue on the staked amount boosted by the position's lock multiplier. Every stake
+/// holds the whole position for MIN_STAKE_AGE_SECONDS, so SHIELD staked just before a
+/// large premium accrual cannot take its share and leave straight away.
+#[account]
+#[derive(InitSpace)]
+pub struct StakePosition {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub staked_amount: u64,
+    pub reward_debt: u128,
+    pub pending_rewards: u64,
+    pub lock_end: i64,
+    pub last_staked_at: i64,
+    pub boost_bps: u32,
+    pub boosted_amount: u64,
+    pub lives_reward_debt: u128,
+    pub pending_lives_rewards: u64,
+    pub bump: u8,
+}
+
+impl StakePosition {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    /// Lock the whole position for at least `lock_seconds` from `now`. A new lock can extend
+    /// the current one but never shorten it, and topping up a position that is still locked
+    /// keeps the boost its lock earned even though less of the lock remains.
+    pub fn extend_lock(&mut self, config: &StakingConfig, lock_seconds: i64, now: i64) -> Result<()> {
+        let still_locked = now < self.lock_end;
+        self.lock_end = std::cmp::max(
+            self.lock_end,
+            now.checked_add(lock_seconds).ok_or(InsuranceError::InvalidStakingConfig)?,
+        );
+
+        let boost_bps = config.boost_bps_for(self.lock_end.saturating_sub(now));
+        self.boost_bps = if still_locked {
+            std::cmp::max(self.boost_bps, boost_bps)
+        } else {
+            boost_bps
+        };
+
+        Ok(())
+    }
+
+    /// Whether the latest stake is still inside the minimum staking period
+    pub fn is_warming_up(&self, now: i64) -> bool {
+        now < self.last_staked_at.saturating_add(MIN_STAKE_AGE_SECONDS)
+    }
+
+    /// Move premiums and LIVES accrued since the last checkpoint into the pending balances
+    pub fn settle(&mut self, rewards: &PremiumRewards, emissions: &LivesEmissions) -> Result<()> {
+        let accrued = accrued_rewards(self.staked_amount, rewards.acc_reward_per_share)?;
+        let owed = u64::try_from(accrued.saturating_sub(self.reward_debt))
+            .map_err(|_| InsuranceError::RewardCalculationOverflow)?;
+        self.pending_rewards = self.pending_rewards
+            .checked_add(owed)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+        self.reward_debt = accrued;
+
+        let accrued_lives = accrued_rewards(self.boosted_amount, emissions.acc_lives_per_share)?;
+        let owed_lives = u64::try_from(accrued_lives.saturating_sub(self.lives_reward_debt))
+            .map_err(|_| InsuranceError::RewardCalculationOverflow)?;
+        self.pending_lives_rewards = self.pending_lives_rewards
+            .checked_add(owed_lives)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+        self.lives_reward_debt = accrued_lives;
+
+        Ok(())
+    }
+
+    /// Reset both checkpoints after the staked or boosted amount changes
+    pub fn sync_reward_debt(&mut self, rewards: &PremiumRewards, emissions: &LivesEmissions) -> Result<()> {
+        self.reward_debt = accrued_rewards(self.staked_amount, rewards.acc_reward_per_share)?;
+        self.lives_reward_debt = accrued_rewards(self.boosted_amount, emissions.acc_lives_per_share)?;
+
+        Ok(())
+    }
+}
+
+fn accrued_rewards(amount: u64, acc_per_share: u128) -> Result<u128> {
+    (amount as u128)
+        .checked_mul(acc_per_share)
+        .ok_or(InsuranceError::RewardCalculationOverflow)?
+        .checked_div(REWARD_PRECISION)
+        .ok_or_else(|| InsuranceError::RewardCalculationOverflow.into())
+}
+
+/// Earned premiums streamed to staked SHIELD through a per-share accumulator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub struct PremiumRewards {
+    pub acc_reward_per_share: u128, // scaled by REWARD_PRECISION
+    pub total_staked: u64,
+    pub rewards_owed: u64,
+}
+
+impl PremiumRewards {
+    /// Credit stakers their part of `amount` of earned premium: the fraction of the SHIELD
+    /// supply they have staked. The rest stays in the vault as net assets, raising the share
+    /// price for every holder. Returns the amount credited, zero when nobody is staked.
+    pub fn distribute(&mut self, amount: u64, shield_supply: u64) -> Result<u64> {
+        if self.total_staked == 0 || amount == 0 {
+            return Ok(0);
+        }
+
+        let staker_share = (amount as u128)
+            .checked_mul(self.total_staked as u128)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?
+            .checked_div(std::cmp::max(shield_supply, self.total_staked) as u128)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+        let staker_share = staker_share as u64; // at most `amount`
+        if staker_share == 0 {
+            return Ok(0);
+        }
+
+        let increment = (staker_share as u128)
+            .checked_mul(REWARD_PRECISION)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?
+            .checked_div(self.total_staked as u128)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+        self.acc_reward_per_share = self.acc_reward_per_share
+            .checked_add(increment)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+        self.rewards_owed = self.rewards_owed
+            .checked_add(staker_share)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+
+        Ok(staker_share)
+    }
+}
+
+/// Governed staking parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub struct StakingConfig {
+    pub lives_emission_per_second: u64,
+    #[max_len(MAX_LOCK_BOOSTS)]
+    pub lock_boosts: Vec<LockBoost>,
+}
+
+impl StakingConfig {
+    /// Best multiplier a lock of `lock_seconds` qualifies for; unlocked stake earns 1x
+    pub fn boost_bps_for(&self, lock_seconds: i64) -> u32 {
+        self.lock_boosts
+            .iter()
+            .filter(|boost| lock_seconds >= boost.min_lock_seconds)
+            .map(|boost| boost.multiplier_bps)
+            .fold(BASIS_POINTS as u32, u32::max)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct LockBoost {
+    pub min_lock_seconds: i64,
+    pub multiplier_bps: u32,
+}
+
+/// LIVES emitted per second to boosted stake, paid from the pool's LIVES reward vault
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub struct LivesEmissions {
+    pub acc_lives_per_share: u128, // scaled by REWARD_PRECISION
+    pub total_boosted: u64,
+    pub funded_remaining: u64,
+    pub last_update: i64,
+}
+
+impl LivesEmissions {
+    /// Accrue emissions up to `now`, never emitting more than the reward vault was funded with
+    pub fn update(&mut self, emission_per_second: u64, now: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.last_update);
+        if elapsed <= 0 {
+            return Ok(());
+        }
+        self.last_update = now;
+
+        if self.total_boosted == 0 {
+            return Ok(());
+        }
+
+        let emitted = std::cmp::min(
+            (emission_per_second as u128)
+                .checked_mul(elapsed as u128)
+                .ok_or(InsuranceError::RewardCalculationOverflow)?,
+            self.funded_remaining as u128,
+        );
+        if emitted == 0 {
+            return Ok(());
+        }
+
+        let increment = emitted
+            .checked_mul(REWARD_PRECISION)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?
+            .checked_div(self.total_boosted as u128)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+        self.acc_lives_per_share = self.acc_lives_per_share
+            .checked_add(increment)
+            .ok_or(InsuranceError::RewardCalculationOverflow)?;
+        self.funded_remaining -= emitted as u64;
+
+        Ok(())
+    }
+}
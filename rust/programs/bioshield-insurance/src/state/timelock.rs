@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, ParameterChange, MAX_PROPOSAL_ACTIONS, TIMELOCK_GRACE_PERIOD};
+
+/// Delay imposed on admin parameter changes; guardians may veto them in the meantime
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct TimelockConfig {
+    pub delay: i64, // seconds between queueing and the earliest execution
+}
+
+/// Parameter changes queued by a pool admin, or passed by governance, executable by anyone
+/// once `eta` has passed. They only run while whoever queued them still holds the Admin role.
+#[account]
+#[derive(InitSpace)]
+pub struct TimelockOperation {
+    pub pool: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey, // an admin, or the pool's governance for a passed proposal
+    pub payer: Pubkey, // paid the rent and gets it back
+    #[max_len(MAX_PROPOSAL_ACTIONS)]
+    pub actions: Vec<ParameterChange>,
+    pub queued_at: i64,
+    pub eta: i64,
+    pub bump: u8,
+}
+
+impl TimelockOperation {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    /// Executable from its ETA until the grace period runs out; a stale change has to be queued again
+    pub fn check_executable(&self, now: i64) -> Result<()> {
+        require!(now >= self.eta, InsuranceError::TimelockNotReady);
+        require!(
+            now <= self.eta.saturating_add(TIMELOCK_GRACE_PERIOD),
+            InsuranceError::TimelockExpired
+        );
+
+        Ok(())
+    }
+}
+
+impl InsurancePool {
+    /// Take the next timelock operation ID and the ETA of an operation queued at `now`
+    pub fn next_timelock_operation(&mut self, now: i64) -> Result<(u64, i64)> {
+        let id = self.timelock_operation_count;
+        let eta = now
+            .checked_add(self.timelock.delay)
+            .ok_or(InsuranceError::InvalidTimelockConfig)?;
+
+        self.timelock_operation_count = self.timelock_operation_count
+            .checked_add(1)
+            .ok_or(InsuranceError::InvalidTimelockConfig)?;
+
+        Ok((id, eta))
+    }
+
+    /// LPs must be able to exit through the withdrawal cooldown before a queued change lands
+    pub fn check_timelock_covers(&self, change: &ParameterChange) -> Result<()> {
+        if let ParameterChange::WithdrawalConfig { config } = change {
+            require!(
+                config.cooldown_seconds <= self.timelock.delay,
+                InsuranceError::InvalidWithdrawalConfig
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, BASIS_POINTS};
+
+pub const MAX_TRANCHES: usize = 2;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub enum TrancheKind {
+    Senior,
+    Junior,
+}
+
+/// A slice of pool capital with its own share mint and premium cut
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct Tranche {
+    pub kind: TrancheKind,
+    pub share_mint: Pubkey,
+    pub assets: u64,
+    pub premium_share_bps: u16,
+}
+
+/// How a claim loss was split across the tranches
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LossAllocation {
+    pub junior_loss: u64,
+    pub senior_loss: u64,
+}
+
+impl InsurancePool {
+    pub fn has_tranches(&self) -> bool {
+        !self.tranches.is_empty()
+    }
+
+    pub fn tranche_by_mint_mut(&mut self, share_mint: &Pubkey) -> Result<&mut Tranche> {
+        self.tranches
+            .iter_mut()
+            .find(|tranche| tranche.share_mint == *share_mint)
+            .ok_or_else(|| InsuranceError::TrancheNotFound.into())
+    }
+
+    pub fn is_share_mint(&self, mint: &Pubkey) -> bool {
+        *mint == self.shield_token_mint ||
+            self.tranches.iter().any(|tranche| tranche.share_mint == *mint)
+    }
+
+    fn tranche_assets(&self, kind: TrancheKind) -> u64 {
+        self.tranches
+            .iter()
+            .find(|tranche| tranche.kind == kind)
+            .map(|tranche| tranche.assets)
+            .unwrap_or(0)
+    }
+
+    /// Tranche assets net of reserved claims, which hit the junior tranche first
+    pub fn tranche_net_assets(&self, share_mint: &Pubkey) -> Result<u64> {
+        let tranche = self.tranches
+            .iter()
+            .find(|tranche| tranche.share_mint == *share_mint)
+            .ok_or(InsuranceError::TrancheNotFound)?;
+
+        let junior_assets = self.tranche_assets(TrancheKind::Junior);
+        Ok(match tranche.kind {
+            TrancheKind::Junior => junior_assets.saturating_sub(self.reserved_claims),
+            TrancheKind::Senior => {
+                let senior_reserve = self.reserved_claims.saturating_sub(junior_assets);
+                tranche.assets.saturating_sub(senior_reserve)
+            }
+        })
+    }
+
+    /// Register a tranche; junior capital takes losses first, so it must take the larger premium cut
+    pub fn add_tranche(&mut self, tranche: Tranche) -> Result<()> {
+        require!(
+            self.tranches.len() < MAX_TRANCHES &&
+            !self.tranches.iter().any(|existing| existing.kind == tranche.kind),
+            InsuranceError::InvalidTrancheConfig
+        );
+
+        let total_share_bps = self.tranches
+            .iter()
+            .map(|existing| existing.premium_share_bps as u32)
+            .sum::<u32>()
+            .checked_add(tranche.premium_share_bps as u32)
+            .ok_or(InsuranceError::InvalidTrancheConfig)?;
+        require!(
+            total_share_bps <= BASIS_POINTS as u32,
+            InsuranceError::InvalidTrancheConfig
+        );
+
+        if let Some(other) = self.tranches.first() {
+            let (junior_bps, senior_bps) = match tranche.kind {
+                TrancheKind::Junior => (tranche.premium_share_bps, other.premium_share_bps),
+                TrancheKind::Senior => (other.premium_share_bps, tranche.premium_share_bps),
+            };
+            require!(junior_bps > senior_bps, InsuranceError::InvalidTrancheConfig);
+        }
+
+        self.tranches.push(tranche);
+        Ok(())
+    }
+
+    /// Split a settlement premium by each tranche's cut. Whatever the cuts leave, rounding dust
+    /// included, goes to the senior tranche, or to the junior one in a pool without a senior
+    /// tranche, so the tranches always account for the whole premium
+    pub fn allocate_premium(&mut self, premium: u64) -> Result<()> {
+        if self.tranches.is_empty() {
+            return Ok(());
+        }
+
+        let mut allocated: u64 = 0;
+        for tranche in self.tranches.iter_mut() {
+            let share = (premium as u128)
+                .checked_mul(tranche.premium_share_bps as u128)
+                .ok_or(InsuranceError::PremiumCalculationOverflow)?
+                .checked_div(BASIS_POINTS as u128)
+                .ok_or(InsuranceError::PremiumCalculationOverflow)? as u64;
+            tranche.assets = tranche.assets
+                .checked_add(share)
+                .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+            allocated = allocated
+                .checked_add(share)
+                .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+        }
+
+        let remainder = premium.saturating_sub(allocated);
+        if remainder > 0 {
+            let residual = self.tranches
+                .iter()
+                .position(|tranche| tranche.kind == TrancheKind::Senior)
+                .unwrap_or(0);
+            let tranche = &mut self.tranches[residual];
+            tranche.assets = tranche.assets
+                .checked_add(remainder)
+                .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+        }
+
+        Ok(())
+    }
+
+    /// Waterfall a claim loss: junior capital first, senior only once junior is wiped out
+    pub fn absorb_loss(&mut self, loss: u64) -> Result<LossAllocation> {
+        let mut allocation = LossAllocation::default();
+        let mut remaining = loss;
+
+        if let Some(junior) = self.tranches.iter_mut().find(|tranche| tranche.kind == TrancheKind::Junior) {
+            allocation.junior_loss = std::cmp::min(remaining, junior.assets);
+            junior.assets -= allocation.junior_loss;
+            remaining -= allocation.junior_loss;
+        }
+
+        if let Some(senior) = self.tranches.iter_mut().find(|tranche| tranche.kind == TrancheKind::Senior) {
+            allocation.senior_loss = std::cmp::min(remaining, senior.assets);
+            senior.assets -= allocation.senior_loss;
+            remaining -= allocation.senior_loss;
+        }
+
+        require!(remaining == 0, InsuranceError::InsufficientLiquidity);
+
+        Ok(allocation)
+    }
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::{InsurancePool, PoolStatus, MAX_COVERAGE_PERIOD};
+
+/// Progress of a pool being retired. Winding down stops new risk while existing policies
+/// run off and claims settle; redemption then pays LPs out of what is left in the vault.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub struct WindDown {
+    pub started_at: Option<i64>,
+    pub redemption_opened_at: Option<i64>,
+    pub open_coverages: Option<u64>, // None for pools migrated before policies were counted
+    pub coverage_ends_by: i64, // no policy written so far runs past this
+}
+
+/// Instructions whose availability depends on the pool's status; anything not listed
+/// here (premium accrual, staking, rewards, governance, expiry, withdrawal cancellation)
+/// runs in every status
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PoolOperation {
+    WriteCoverage,
+    TradeCoverage,
+    AddLiquidity,
+    RequestWithdrawal,
+    ProcessWithdrawal,
+    SubmitClaim,
+    ProcessClaim,
+    CancelCoverage,
+    RedeemShares,
+}
+
+impl PoolStatus {
+    pub fn allows(&self, operation: PoolOperation) -> bool {
+        use PoolOperation::*;
+
+        match self {
+            PoolStatus::Active => !matches!(operation, CancelCoverage | RedeemShares),
+            // Emergency stop: no new risk or capital movement, but claims still settle
+            PoolStatus::Paused => matches!(operation, SubmitClaim | ProcessClaim),
+            PoolStatus::WindingDown => matches!(operation, SubmitClaim | ProcessClaim | CancelCoverage),
+            // Queued withdrawals escrowed SHIELD before the wind-down, so they may still clear;
+            // providers can also cancel them and redeem instead
+            PoolStatus::Redeeming => matches!(operation, ProcessWithdrawal | RedeemShares),
+        }
+    }
+}
+
+impl InsurancePool {
+    pub fn require_allows(&self, operation: PoolOperation) -> Result<()> {
+        let status = self.status();
+        if status.allows(operation) {
+            return Ok(());
+        }
+
+        Err(match status {
+            PoolStatus::Paused => InsuranceError::PoolPaused,
+            _ => InsuranceError::OperationNotAllowedInPoolStatus,
+        }
+        .into())
+    }
+
+    /// Count a newly written policy against the run-off
+    pub fn track_coverage(&mut self, end_time: i64) -> Result<()> {
+        if let Some(open) = self.wind_down.open_coverages.as_mut() {
+            *open = open.checked_add(1).ok_or(InsuranceError::InvalidCoverageAmount)?;
+        }
+        self.wind_down.coverage_ends_by = self.wind_down.coverage_ends_by.max(end_time);
+
+        Ok(())
+    }
+
+    /// A policy expired, was cancelled or was paid out in full; its retained exposure
+    /// no longer counts towards utilization
+    pub fn release_coverage(&mut self, retained_coverage: u64) {
+        if let Some(open) = self.wind_down.open_coverages.as_mut() {
+            *open = open.saturating_sub(1);
+        }
+        self.total_coverage_amount = self.total_coverage_amount.saturating_sub(retained_coverage);
+    }
+
+    pub fn begin_wind_down(&mut self, now: i64) -> Result<()> {
+        require!(
+            self.wind_down.started_at.is_none(),
+            InsuranceError::PoolAlreadyWindingDown
+        );
+
+        self.wind_down.started_at = Some(now);
+        // Untracked policies were all written before now, so none outlives the longest period
+        if self.wind_down.open_coverages.is_none() {
+            self.wind_down.coverage_ends_by = self.wind_down.coverage_ends_by
+                .max(now.saturating_add(MAX_COVERAGE_PERIOD as i64));
+        }
+
+        Ok(())
+    }
+
+    /// Redemption may open once no policy can still be claimed against, every claim has
+    /// settled, every settlement premium has been earned through `accrue_premium` and no
+    /// treaty still cedes exposure to the pool
+    pub fn is_run_off_complete(&self, now: i64) -> bool {
+        let policies_closed = self.wind_down.open_coverages == Some(0) ||
+            now > self.wind_down.coverage_ends_by;
+
+        self.wind_down.started_at.is_some() &&
+            policies_closed &&
+            self.assumed_coverage == 0 &&
+            self.reserved_claims == 0 &&
+            self.unearned_premiums == 0
+    }
+
+    pub fn open_redemption(&mut self, now: i64) -> Result<()> {
+        require!(
+            self.status() == PoolStatus::WindingDown,
+            InsuranceError::OperationNotAllowedInPoolStatus
+        );
+        require!(self.is_run_off_complete(now), InsuranceError::WindDownIncomplete);
+
+        self.wind_down.redemption_opened_at = Some(now);
+
+        Ok(())
+    }
+
+    /// Vault balance backing one share mint once the pool is redeeming. Staker rewards stay
+    /// claimable, tranche shares are backed by their tranche and SHIELD takes the rest.
+    pub fn redeemable_assets(&self, share_mint: &Pubkey, vault_balance: u64) -> Result<u64> {
+        if *share_mint != self.shield_token_mint {
+            return self.tranche_net_assets(share_mint);
+        }
+
+        let tranche_assets = self.tranches
+            .iter()
+            .fold(0u64, |total, tranche| total.saturating_add(tranche.assets));
+        Ok(vault_balance
+            .saturating_sub(self.settlement_liabilities())
+            .saturating_sub(tranche_assets))
+    }
+
+    /// LIVES or wrapped SOL backing one share mint once the pool is redeeming. Those vaults
+    /// owe nothing, so SHIELD takes them whole; a tranched pool never raised SHIELD and
+    /// splits them between its tranches in proportion to their net assets instead.
+    pub fn redeemable_vault_assets(&self, share_mint: &Pubkey, vault_balance: u64) -> Result<u64> {
+        if !self.has_tranches() {
+            return Ok(if *share_mint == self.shield_token_mint { vault_balance } else { 0 });
+        }
+        if *share_mint == self.shield_token_mint {
+            return Ok(0);
+        }
+
+        let net_assets = self.tranche_net_assets(share_mint)?;
+        let mut total_net_assets = 0u64;
+        for tranche in &self.tranches {
+            total_net_assets = total_net_assets
+                .saturating_add(self.tranche_net_assets(&tranche.share_mint)?);
+        }
+        // Tranches wiped out by losses still share whatever the other vaults hold
+        if total_net_assets == 0 {
+            return Ok(vault_balance / self.tranches.len() as u64);
+        }
+
+        let share = (vault_balance as u128)
+            .checked_mul(net_assets as u128)
+            .ok_or(InsuranceError::InvalidLiquidityAmount)?
+            / total_net_assets as u128;
+        u64::try_from(share).map_err(|_| InsuranceError::InvalidLiquidityAmount.into())
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::errors::InsuranceError;
+use crate::state::BASIS_POINTS;
+
+/// A queued LP withdrawal; the SHIELD is held in escrow until it is processed. A request
+/// too large for one epoch's cap is filled across epochs, and a cancelled one keeps its
+/// place with nothing escrowed until the queue reaches it.
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawalRequest {
+    pub pool: Pubkey,
+    pub provider: Pubkey,
+    pub share_mint: Pubkey,
+    pub shield_amount: u64,
+    pub sequence: u64,
+    pub requested_at: i64,
+    pub bump: u8,
+}
+
+impl WithdrawalRequest {
+    pub const SIZE: usize = 8 + // discriminator
+        Self::INIT_SPACE; // fields, bounded by their max_len
+
+    /// The provider took their SHIELD back; processing only clears the request
+    pub fn is_cancelled(&self) -> bool {
+        self.shield_amount == 0
+    }
+
+    /// Cancelled requests clear at once; live ones wait out the cooldown
+    pub fn is_executable(&self, config: &WithdrawalConfig, now: i64) -> bool {
+        self.is_cancelled() ||
+            now >= self.requested_at.saturating_add(config.cooldown_seconds)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct WithdrawalConfig {
+    pub cooldown_seconds: i64,
+    pub epoch_duration: i64,
+    pub max_epoch_withdrawal_bps: u16,
+}
+
+/// FIFO cursor over withdrawal requests plus the current epoch's outflow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, InitSpace)]
+pub struct WithdrawalQueue {
+    pub head: u64,
+    pub tail: u64,
+    pub epoch_start: i64,
+    pub epoch_tvl_snapshot: u64,
+    pub epoch_withdrawn: u64,
+}
+
+impl WithdrawalQueue {
+    /// Start a new epoch once the current one is over, snapshotting TVL for its cap
+    pub fn roll_epoch(&mut self, config: &WithdrawalConfig, total_value_locked: u64, now: i64) {
+        if now >= self.epoch_start.saturating_add(config.epoch_duration) {
+            self.epoch_start = now;
+            self.epoch_tvl_snapshot = total_value_locked;
+            self.epoch_withdrawn = 0;
+        }
+    }
+
+    /// Settlement that may still leave the pool this epoch
+    pub fn remaining_capacity(&self, config: &WithdrawalConfig) -> Result<u64> {
+        let epoch_cap = (self.epoch_tvl_snapshot as u128)
+            .checked_mul(config.max_epoch_withdrawal_bps as u128)
+            .ok_or(InsuranceError::InvalidLiquidityAmount)?
+            .checked_div(BASIS_POINTS as u128)
+            .ok_or(InsuranceError::InvalidLiquidityAmount)? as u64;
+
+        Ok(epoch_cap.saturating_sub(self.epoch_withdrawn))
+    }
+}
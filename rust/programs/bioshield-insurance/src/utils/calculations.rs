@@ -1,56 +1,394 @@
 use anchor_lang::prelude::*;
 use crate::errors::InsuranceError;
-use crate::state::{RiskCategory, BASIS_POINTS, LIVES_DISCOUNT_PERCENTAGE};
+use crate::state::{
+    RiskCategory, PricingCurve, PricingInputs, PremiumQuote, InsurancePool,
+    BASIS_POINTS, MAX_RISK_MULTIPLIER_BPS, MAX_RISK_DATA_AGE,
+    QUOTE_VALIDITY_SECONDS, SECONDS_PER_DAY, SECONDS_PER_YEAR, VIRTUAL_SHIELD_SHARES,
+    VIRTUAL_SETTLEMENT_ASSETS, SHARE_PRICE_PRECISION, PRICING_VERSION_PER_SECOND,
+};
+use crate::state::coverage_account::CoverageType;
+use crate::oracles::RiskAssessment;
 
+/// Base annual rate in basis points (1 bp = 0.01%) for a risk category
+pub fn base_rate_basis_points(risk_category: &RiskCategory) -> u16 {
+    match risk_category {
+        RiskCategory::Low => 300,      // 3%
+        RiskCategory::Medium => 500,   // 5%
+        RiskCategory::High => 800,     // 8%
+        RiskCategory::VeryHigh => 1200, // 12%
+    }
+}
+
+/// Premium for `coverage_period` seconds of cover, pro-rata to the second
 pub fn calculate_premium(
     coverage_amount: u64,
     coverage_period: u32,
     risk_category: RiskCategory,
+    risk_multiplier_bps: u32,
+    utilization_multiplier_bps: u32,
 ) -> Result<u64> {
-    // Base rate in basis points (1 bp = 0.01%)
-    let base_rate = match risk_category {
-        RiskCategory::Low => 300,      // 3%
-        RiskCategory::Medium => 500,   // 5%
-        RiskCategory::High => 800,     // 8%
-        RiskCategory::VeryHigh => 1200, // 12%
-    };
+    let base_rate = base_rate_basis_points(&risk_category) as u128;
+
+    // Apply the oracle risk multiplier, capped to protect the insured
+    let risk_multiplier = std::cmp::min(risk_multiplier_bps, MAX_RISK_MULTIPLIER_BPS) as u128;
+
+    // amount * rate * risk * utilization * period / (BP^3 * year), kept in 128 bits until the end
+    let numerator = (coverage_amount as u128)
+        .checked_mul(base_rate)
+        .and_then(|v| v.checked_mul(risk_multiplier))
+        .and_then(|v| v.checked_mul(utilization_multiplier_bps as u128))
+        .and_then(|v| v.checked_mul(coverage_period as u128))
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+    let denominator = (BASIS_POINTS as u128).pow(3) * SECONDS_PER_YEAR as u128;
+
+    let premium = numerator
+        .checked_div(denominator)
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+    u64::try_from(premium).map_err(|_| InsuranceError::PremiumCalculationOverflow.into())
+}
 
-    // Calculate annual premium
-    let annual_premium = coverage_amount
-        .checked_mul(base_rate as u64)
+/// Resolve the pricing inputs for a policy. Without an oracle co-signed assessment the
+/// risk is unpriced, so it is loaded at the maximum multiplier.
+pub fn resolve_pricing_inputs(
+    risk_assessment: Option<&RiskAssessment>,
+    oracle_signed: bool,
+    coverage_type: &CoverageType,
+    risk_category: &RiskCategory,
+    now: i64,
+) -> Result<PricingInputs> {
+    let base_rate_bps = base_rate_basis_points(risk_category);
+
+    match risk_assessment {
+        Some(assessment) => {
+            require!(oracle_signed, InsuranceError::InvalidOracleSignature);
+            // A future-dated assessment would otherwise look fresh for as long as it likes
+            let age = now
+                .checked_sub(assessment.oracle_data.consensus_timestamp)
+                .filter(|age| *age >= 0)
+                .ok_or(InsuranceError::InvalidOracleData)?;
+            require!(age <= MAX_RISK_DATA_AGE, InsuranceError::StaleOracleData);
+            assessment.price(coverage_type, base_rate_bps, now)
+        }
+        None => Ok(PricingInputs {
+            base_rate_bps,
+            risk_multiplier_bps: MAX_RISK_MULTIPLIER_BPS,
+            ..Default::default()
+        }),
+    }
+}
+
+/// Utilization once `coverage_amount` more is written, enforcing the pool's capacity limit
+pub fn utilization_after(pool: &InsurancePool, coverage_amount: u64) -> Result<u64> {
+    let new_total_coverage = pool.total_coverage_amount
+        .checked_add(coverage_amount)
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+    require!(pool.total_value_locked > 0, InsuranceError::InsufficientLiquidity);
+    let utilization_bps = calculate_utilization_rate(
+        new_total_coverage,
+        pool.total_value_locked,
+    )?;
+    require!(
+        utilization_bps <= pool.max_utilization_bps as u64,
+        InsuranceError::InsufficientLiquidity
+    );
+
+    Ok(utilization_bps)
+}
+
+/// Full premium breakdown for a policy against the pool's current state.
+/// `lives_balance` is the insured's LIVES holding when paying with LIVES.
+pub fn quote_premium(
+    pool: &InsurancePool,
+    coverage_amount: u64,
+    coverage_period: u32,
+    risk_category: &RiskCategory,
+    pricing_inputs: &PricingInputs,
+    lives_balance: Option<u64>,
+    now: i64,
+) -> Result<PremiumQuote> {
+    require!(
+        coverage_amount >= pool.min_coverage_amount &&
+        coverage_amount <= pool.max_coverage_amount,
+        InsuranceError::InvalidCoverageAmount
+    );
+    crate::utils::validations::validate_coverage_period(coverage_period)?;
+
+    let utilization_bps = utilization_after(pool, coverage_amount)?;
+    let utilization_multiplier_bps = calculate_utilization_multiplier(
+        utilization_bps,
+        &pool.pricing_curve,
+        pool.max_utilization_bps,
+    )?;
+
+    let base_premium = calculate_premium(
+        coverage_amount,
+        coverage_period,
+        risk_category.clone(),
+        BASIS_POINTS as u32,
+        utilization_multiplier_bps,
+    )?;
+    let risk_adjusted_premium = calculate_premium(
+        coverage_amount,
+        coverage_period,
+        risk_category.clone(),
+        pricing_inputs.risk_multiplier_bps,
+        utilization_multiplier_bps,
+    )?
+    .max(pool.min_premium);
+
+    let fee = risk_adjusted_premium
+        .checked_mul(pool.pool_fee_basis_points as u64)
         .ok_or(InsuranceError::PremiumCalculationOverflow)?
         .checked_div(BASIS_POINTS as u64)
         .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+    let gross_premium = risk_adjusted_premium
+        .checked_add(fee)
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+    let lives_discount = if let Some(balance) = lives_balance {
+        gross_premium
+            .checked_mul(pool.lives_discount.discount_bps_for(balance) as u64)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?
+            .checked_div(BASIS_POINTS as u64)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?
+    } else {
+        0
+    };
+
+    let final_premium = gross_premium
+        .checked_sub(lives_discount)
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+    let expires_at = now
+        .checked_add(QUOTE_VALIDITY_SECONDS)
+        .ok_or(InsuranceError::QuoteExpired)?;
+
+    Ok(PremiumQuote {
+        base_premium,
+        risk_multiplier_bps: pricing_inputs.risk_multiplier_bps,
+        utilization_multiplier_bps,
+        risk_premium: risk_adjusted_premium,
+        fee,
+        lives_discount,
+        final_premium,
+        premium_mint: pool.settlement_mint,
+        amount_due: final_premium,
+        expires_at,
+    })
+}
+
+/// Convert a settlement amount into base units of another asset from (price, exponent) USD quotes
+pub fn convert_settlement_to_asset(
+    settlement_amount: u64,
+    settlement_decimals: u8,
+    settlement_price: (i64, i32),
+    asset_decimals: u8,
+    asset_price: (i64, i32),
+) -> Result<u64> {
+    let (settlement_value, settlement_expo) = settlement_price;
+    let (asset_value, asset_expo) = asset_price;
+    require!(
+        settlement_value > 0 && asset_value > 0,
+        InsuranceError::InvalidOracleData
+    );
 
-    // Adjust for coverage period (in seconds to days)
-    let period_in_days = coverage_period / (24 * 60 * 60);
-    let premium = annual_premium
-        .checked_mul(period_in_days as u64)
+    // amount * p_s * 10^e_s * 10^d_a / (p_a * 10^e_a * 10^d_s)
+    let mut numerator = (settlement_amount as u128)
+        .checked_mul(settlement_value as u128)
+        .and_then(|v| v.checked_mul(10u128.pow(asset_decimals as u32)))
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+    let mut denominator = (asset_value as u128)
+        .checked_mul(10u128.pow(settlement_decimals as u32))
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+    let expo_diff = settlement_expo - asset_expo;
+    if expo_diff >= 0 {
+        numerator = numerator
+            .checked_mul(10u128.pow(expo_diff as u32))
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+    } else {
+        denominator = denominator
+            .checked_mul(10u128.pow(expo_diff.unsigned_abs()))
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+    }
+
+    let asset_amount = numerator
+        .checked_div(denominator)
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+    u64::try_from(asset_amount).map_err(|_| InsuranceError::PremiumCalculationOverflow.into())
+}
+
+/// Settlement value of a claim shortfall met by paying `paid` of the `owed` units of
+/// another asset, when its vault cannot pay all of them
+pub fn settlement_value_covered(shortfall: u64, owed: u64, paid: u64) -> Result<u64> {
+    if paid >= owed {
+        return Ok(shortfall);
+    }
+
+    let covered = (shortfall as u128)
+        .checked_mul(paid as u128)
+        .ok_or(InsuranceError::PayoutCalculationError)?
+        .checked_div(owed as u128)
+        .ok_or(InsuranceError::PayoutCalculationError)?;
+
+    u64::try_from(covered).map_err(|_| InsuranceError::PayoutCalculationError.into())
+}
+
+/// Settlement assets backing SHIELD: vault balance less claim reserves, unearned premiums
+/// and rewards owed to stakers
+pub fn calculate_net_assets(
+    vault_balance: u64,
+    liabilities: u64,
+) -> u64 {
+    vault_balance.saturating_sub(liabilities)
+}
+
+/// Portion of a premium earned by `now`, amortized over the period the premium was priced for.
+/// Legacy policies were charged for whole days only, so they earn one day's share per elapsed day.
+pub fn calculate_earned_premium(
+    premium: u64,
+    start_time: i64,
+    end_time: i64,
+    now: i64,
+    pricing_version: u8,
+) -> Result<u64> {
+    if pricing_version >= PRICING_VERSION_PER_SECOND {
+        return amortize_linearly(premium, start_time, end_time, now);
+    }
+
+    // A policy shorter than a day was priced at nothing, so there is nothing to amortize
+    let priced_days = end_time.saturating_sub(start_time) / SECONDS_PER_DAY;
+    let elapsed_days = now.saturating_sub(start_time).max(0) / SECONDS_PER_DAY;
+    amortize_linearly(premium, 0, priced_days, elapsed_days)
+}
+
+fn amortize_linearly(premium: u64, start: i64, end: i64, now: i64) -> Result<u64> {
+    if now >= end || end <= start {
+        return Ok(premium);
+    }
+    if now <= start {
+        return Ok(0);
+    }
+
+    let earned = (premium as u128)
+        .checked_mul((now - start) as u128)
         .ok_or(InsuranceError::PremiumCalculationOverflow)?
-        .checked_div(365)
+        .checked_div((end - start) as u128)
         .ok_or(InsuranceError::PremiumCalculationOverflow)?;
 
-    Ok(premium)
+    u64::try_from(earned).map_err(|_| InsuranceError::PremiumCalculationOverflow.into())
+}
+
+/// Staked SHIELD weighted by a lock multiplier, used for LIVES emissions
+pub fn calculate_boosted_amount(staked_amount: u64, boost_bps: u32) -> Result<u64> {
+    let boosted = (staked_amount as u128)
+        .checked_mul(boost_bps as u128)
+        .ok_or(InsuranceError::RewardCalculationOverflow)?
+        .checked_div(BASIS_POINTS as u128)
+        .ok_or(InsuranceError::RewardCalculationOverflow)?;
+
+    u64::try_from(boosted).map_err(|_| InsuranceError::RewardCalculationOverflow.into())
+}
+
+/// Voting weight of escrowed SHIELD plus LIVES scaled by its relative weight
+pub fn calculate_vote_weight(
+    shield_amount: u64,
+    lives_amount: u64,
+    lives_vote_weight_bps: u16,
+) -> Result<u64> {
+    let lives_weight = (lives_amount as u128)
+        .checked_mul(lives_vote_weight_bps as u128)
+        .ok_or(InsuranceError::InsufficientVotingWeight)?
+        .checked_div(BASIS_POINTS as u128)
+        .ok_or(InsuranceError::InsufficientVotingWeight)?;
+    let weight = (shield_amount as u128)
+        .checked_add(lives_weight)
+        .ok_or(InsuranceError::InsufficientVotingWeight)?;
+
+    u64::try_from(weight).map_err(|_| InsuranceError::InsufficientVotingWeight.into())
 }
 
+/// SHIELD minted for a deposit. Virtual shares and assets make a donation to the vault
+/// cost the first depositor far more than it can skim from later LPs.
 pub fn calculate_shield_tokens(
     liquidity_amount: u64,
-    total_value_locked: u64,
+    net_assets: u64,
+    total_supply: u64,
+) -> Result<u64> {
+    let shield_amount = (liquidity_amount as u128)
+        .checked_mul(total_supply as u128 + VIRTUAL_SHIELD_SHARES as u128)
+        .ok_or(InsuranceError::InvalidLiquidityAmount)?
+        .checked_div(net_assets as u128 + VIRTUAL_SETTLEMENT_ASSETS as u128)
+        .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+
+    u64::try_from(shield_amount).map_err(|_| InsuranceError::InvalidLiquidityAmount.into())
+}
+
+/// Settlement tokens redeemed for `shield_amount` SHIELD at the current share price
+pub fn calculate_withdrawal_amount(
+    shield_amount: u64,
+    net_assets: u64,
     total_supply: u64,
 ) -> Result<u64> {
-    if total_value_locked == 0 || total_supply == 0 {
-        // First liquidity provider gets 1:1
-        return Ok(liquidity_amount);
+    let amount = (shield_amount as u128)
+        .checked_mul(net_assets as u128 + VIRTUAL_SETTLEMENT_ASSETS as u128)
+        .ok_or(InsuranceError::InvalidLiquidityAmount)?
+        .checked_div(total_supply as u128 + VIRTUAL_SHIELD_SHARES as u128)
+        .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+
+    u64::try_from(amount).map_err(|_| InsuranceError::InvalidLiquidityAmount.into())
+}
+
+/// SHIELD from a queued withdrawal worth `amount` that fits under the epoch's remaining
+/// outflow `capacity`; the rest stays queued for a later epoch
+pub fn fillable_shares(shield_amount: u64, amount: u64, capacity: u64) -> Result<u64> {
+    if amount <= capacity {
+        return Ok(shield_amount);
     }
 
-    // Calculate proportional SHIELD tokens
-    let shield_amount = liquidity_amount
-        .checked_mul(total_supply)
-        .unwrap()
-        .checked_div(total_value_locked)
-        .unwrap();
+    let shares = (shield_amount as u128)
+        .checked_mul(capacity as u128)
+        .ok_or(InsuranceError::InvalidLiquidityAmount)?
+        .checked_div(amount as u128)
+        .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+
+    u64::try_from(shares).map_err(|_| InsuranceError::InvalidLiquidityAmount.into())
+}
+
+/// Exact pro-rata slice of a redeeming pool's assets; no virtual shares, so the last
+/// holder takes whatever rounding left behind
+pub fn calculate_redemption_amount(
+    share_amount: u64,
+    redeemable_assets: u64,
+    total_supply: u64,
+) -> Result<u64> {
+    require!(
+        share_amount > 0 && share_amount <= total_supply,
+        InsuranceError::InvalidLiquidityAmount
+    );
+
+    let amount = (share_amount as u128)
+        .checked_mul(redeemable_assets as u128)
+        .ok_or(InsuranceError::InvalidLiquidityAmount)?
+        .checked_div(total_supply as u128)
+        .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+
+    u64::try_from(amount).map_err(|_| InsuranceError::InvalidLiquidityAmount.into())
+}
 
-    Ok(shield_amount)
+/// Settlement units per SHIELD base unit, scaled by `SHARE_PRICE_PRECISION`
+pub fn calculate_share_price(
+    net_assets: u64,
+    total_supply: u64,
+) -> Result<u64> {
+    let price = (SHARE_PRICE_PRECISION as u128)
+        .checked_mul(net_assets as u128 + VIRTUAL_SETTLEMENT_ASSETS as u128)
+        .ok_or(InsuranceError::InvalidLiquidityAmount)?
+        .checked_div(total_supply as u128 + VIRTUAL_SHIELD_SHARES as u128)
+        .ok_or(InsuranceError::InvalidLiquidityAmount)?;
+
+    u64::try_from(price).map_err(|_| InsuranceError::InvalidLiquidityAmount.into())
 }
 
 pub fn calculate_payout_amount(
@@ -93,10 +431,354 @@ pub fn calculate_utilization_rate(
     Ok(utilization)
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub enum RiskCategory {
-    Low,
-    Medium,
-    High,
-    VeryHigh,
+/// Premium multiplier (in basis points) for a utilization level on a kinked curve
+pub fn calculate_utilization_multiplier(
+    utilization_bps: u64,
+    curve: &PricingCurve,
+    max_utilization_bps: u32,
+) -> Result<u32> {
+    let kink = curve.kink_utilization_bps as u64;
+
+    let loading = if utilization_bps <= kink {
+        utilization_bps
+            .checked_mul(curve.slope_below_kink_bps as u64)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?
+            .checked_div(kink)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?
+    } else {
+        // Steeper slope once capacity gets scarce
+        let excess = utilization_bps - kink;
+        let excess_range = (max_utilization_bps as u64)
+            .checked_sub(kink)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+        let steep_loading = excess
+            .checked_mul(curve.slope_above_kink_bps as u64)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?
+            .checked_div(excess_range)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+        (curve.slope_below_kink_bps as u64)
+            .checked_add(steep_loading)
+            .ok_or(InsuranceError::PremiumCalculationOverflow)?
+    };
+
+    let multiplier = (BASIS_POINTS as u64)
+        .checked_add(loading)
+        .ok_or(InsuranceError::PremiumCalculationOverflow)?;
+
+    u32::try_from(multiplier).map_err(|_| InsuranceError::PremiumCalculationOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{
+        StakingConfig, LockBoost, LivesEmissions, VoterRecord, REWARD_PRECISION,
+        MAX_VOTE_CHECKPOINTS,
+    };
+
+    fn curve() -> PricingCurve {
+        PricingCurve {
+            kink_utilization_bps: 8_000,   // 80%
+            slope_below_kink_bps: 2_000,   // +20% at the kink
+            slope_above_kink_bps: 20_000,  // +200% more at max utilization
+        }
+    }
+
+    #[test]
+    fn test_utilization_multiplier_follows_kinked_curve() {
+        assert_eq!(calculate_utilization_multiplier(0, &curve(), 10_000).unwrap(), 10_000);
+        assert_eq!(calculate_utilization_multiplier(4_000, &curve(), 10_000).unwrap(), 11_000);
+        assert_eq!(calculate_utilization_multiplier(8_000, &curve(), 10_000).unwrap(), 12_000);
+        assert_eq!(calculate_utilization_multiplier(9_000, &curve(), 10_000).unwrap(), 22_000);
+        assert_eq!(calculate_utilization_multiplier(10_000, &curve(), 10_000).unwrap(), 32_000);
+    }
+
+    #[test]
+    fn test_premium_is_pro_rata_to_the_second() {
+        let amount = 1_000_000 * 1_000_000_000;
+        let annual = calculate_premium(amount, SECONDS_PER_YEAR as u32, RiskCategory::Medium, 10_000, 10_000).unwrap();
+        assert_eq!(annual, amount / 20); // 5% per year
+
+        // A policy under one day is no longer free
+        let hourly = calculate_premium(amount, 60 * 60, RiskCategory::Medium, 10_000, 10_000).unwrap();
+        assert!(hourly > 0);
+        assert_eq!(hourly, annual / (365 * 24));
+    }
+
+    #[test]
+    fn test_unassessed_risk_is_priced_at_the_maximum_multiplier() {
+        let inputs = resolve_pricing_inputs(
+            None,
+            false,
+            &CoverageType::ClinicalTrialFailure,
+            &RiskCategory::Medium,
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(inputs.risk_multiplier_bps, MAX_RISK_MULTIPLIER_BPS);
+        assert_eq!(inputs.base_rate_bps, base_rate_basis_points(&RiskCategory::Medium));
+    }
+
+    fn assessment(consensus_timestamp: i64) -> RiskAssessment {
+        RiskAssessment {
+            oracle_data: crate::oracles::MultiOracleData {
+                price_data: None,
+                bioscience_data: None,
+                switchboard_data: None,
+                consensus_timestamp,
+                data_sources_count: 0,
+            },
+            clinical_trial: None,
+            regulatory: None,
+            intellectual_property: None,
+        }
+    }
+
+    fn resolve_signed(assessment: &RiskAssessment, now: i64) -> Result<PricingInputs> {
+        resolve_pricing_inputs(
+            Some(assessment),
+            true,
+            &CoverageType::ResearchInfrastructure,
+            &RiskCategory::Medium,
+            now,
+        )
+    }
+
+    #[test]
+    fn test_assessment_without_oracle_signature_is_rejected() {
+        assert_eq!(
+            resolve_pricing_inputs(
+                Some(&assessment(0)),
+                false,
+                &CoverageType::ResearchInfrastructure,
+                &RiskCategory::Medium,
+                0,
+            )
+            .err(),
+            Some(InsuranceError::InvalidOracleSignature.into())
+        );
+    }
+
+    #[test]
+    fn test_assessment_age_is_checked_without_overflow() {
+        assert!(resolve_signed(&assessment(1_000), 1_000 + MAX_RISK_DATA_AGE).is_ok());
+        assert_eq!(
+            resolve_signed(&assessment(1_000), 1_001 + MAX_RISK_DATA_AGE).err(),
+            Some(InsuranceError::StaleOracleData.into())
+        );
+
+        // An assessment dated so far back that its age overflows is rejected, not a panic
+        assert_eq!(
+            resolve_signed(&assessment(i64::MIN), 1).err(),
+            Some(InsuranceError::InvalidOracleData.into())
+        );
+    }
+
+    #[test]
+    fn test_future_dated_assessment_is_rejected() {
+        assert_eq!(
+            resolve_signed(&assessment(1_001), 1_000).err(),
+            Some(InsuranceError::InvalidOracleData.into())
+        );
+    }
+
+    #[test]
+    fn test_lives_conversion_uses_oracle_prices() {
+        // 100 USDC (6 decimals) at $1.00, LIVES (9 decimals) at $0.25
+        let lives = convert_settlement_to_asset(
+            100_000_000, 6, (1, 0),
+            9, (25_000_000, -8),
+        ).unwrap();
+        assert_eq!(lives, 400 * 1_000_000_000);
+
+        // 1 wrapped SOL (9 decimals) at $150, LIVES at $0.50
+        let lives = convert_settlement_to_asset(
+            1_000_000_000, 9, (15_000_000_000, -8),
+            9, (50_000_000, -8),
+        ).unwrap();
+        assert_eq!(lives, 300 * 1_000_000_000);
+    }
+
+    #[test]
+    fn test_conversion_refuses_non_positive_prices() {
+        for price in [(0, -8), (-25_000_000, -8)] {
+            assert_eq!(
+                convert_settlement_to_asset(100_000_000, 6, (1, 0), 9, price).err(),
+                Some(InsuranceError::InvalidOracleData.into())
+            );
+            assert_eq!(
+                convert_settlement_to_asset(100_000_000, 6, price, 9, (25_000_000, -8)).err(),
+                Some(InsuranceError::InvalidOracleData.into())
+            );
+        }
+    }
+
+    #[test]
+    fn test_shortfall_is_covered_in_proportion_to_the_asset_paid() {
+        // A 100 USDC shortfall is owed as 400 LIVES
+        assert_eq!(settlement_value_covered(100_000_000, 400, 400).unwrap(), 100_000_000);
+        // A vault holding 100 LIVES covers a quarter of it
+        assert_eq!(settlement_value_covered(100_000_000, 400, 100).unwrap(), 25_000_000);
+        assert_eq!(settlement_value_covered(100_000_000, 400, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_withdrawal_over_the_epoch_cap_is_filled_in_part() {
+        // 1,000 SHIELD worth 500,000 against 100,000 of remaining capacity
+        assert_eq!(fillable_shares(1_000, 500_000, 100_000).unwrap(), 200);
+        assert_eq!(fillable_shares(1_000, 100_000, 100_000).unwrap(), 1_000);
+        assert_eq!(fillable_shares(1_000, 500_000, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_virtual_shares_block_first_depositor_inflation() {
+        // Attacker seeds the pool with 1 unit, then donates 1,000 tokens to the vault
+        let attacker_shares = calculate_shield_tokens(1, 0, 0).unwrap();
+        assert_eq!(attacker_shares, 1);
+        let mut net_assets = 1 + 1_000_000_000;
+        let mut supply = attacker_shares;
+
+        // Victim deposits 1,000 tokens and still receives shares
+        let victim_shares = calculate_shield_tokens(1_000_000_000, net_assets, supply).unwrap();
+        assert!(victim_shares > 0);
+        net_assets += 1_000_000_000;
+        supply += victim_shares;
+
+        // The donation is lost to the virtual shares, not captured from the victim
+        let victim_redeems = calculate_withdrawal_amount(victim_shares, net_assets, supply).unwrap();
+        let attacker_redeems = calculate_withdrawal_amount(attacker_shares, net_assets, supply).unwrap();
+        assert!(victim_redeems >= 999_000_000);
+        assert!(attacker_redeems < 1_000_000);
+    }
+
+    #[test]
+    fn test_redemption_drains_the_vault_pro_rata() {
+        let mut assets = 1_000_000_001;
+        let mut supply = 3_000;
+
+        // Each third of the supply takes a third of what is left, rounding down
+        let first = calculate_redemption_amount(1_000, assets, supply).unwrap();
+        assert_eq!(first, 333_333_333);
+        assets -= first;
+        supply -= 1_000;
+
+        let second = calculate_redemption_amount(1_000, assets, supply).unwrap();
+        assert_eq!(second, 333_333_334);
+        assets -= second;
+        supply -= 1_000;
+
+        // The last holder leaves nothing behind
+        assert_eq!(calculate_redemption_amount(1_000, assets, supply).unwrap(), assets);
+        assert!(calculate_redemption_amount(1_001, assets, supply).is_err());
+        assert!(calculate_redemption_amount(0, assets, supply).is_err());
+    }
+
+    #[test]
+    fn test_premium_is_earned_linearly_over_the_coverage_period() {
+        let premium = 365_000_000;
+        let start = 1_000;
+        let end = start + SECONDS_PER_YEAR;
+        let earned = |now| {
+            calculate_earned_premium(premium, start, end, now, PRICING_VERSION_PER_SECOND).unwrap()
+        };
+
+        assert_eq!(earned(start), 0);
+        assert_eq!(earned(start + SECONDS_PER_DAY), 1_000_000);
+        assert_eq!(earned(start + SECONDS_PER_YEAR / 2), premium / 2);
+        assert_eq!(earned(end + 1), premium);
+    }
+
+    #[test]
+    fn test_legacy_premium_is_earned_by_whole_days() {
+        // Ten days and a half, of which only the ten whole days were charged for
+        let premium = 10_000;
+        let start = 1_000;
+        let end = start + 10 * SECONDS_PER_DAY + SECONDS_PER_DAY / 2;
+        let earned = |now| calculate_earned_premium(premium, start, end, now, 0).unwrap();
+
+        assert_eq!(earned(start + SECONDS_PER_DAY - 1), 0);
+        assert_eq!(earned(start + SECONDS_PER_DAY), 1_000);
+        assert_eq!(earned(start + 3 * SECONDS_PER_DAY + SECONDS_PER_DAY / 2), 3_000);
+        // The uncharged half day earns nothing further
+        assert_eq!(earned(start + 10 * SECONDS_PER_DAY), premium);
+        assert_eq!(earned(end - 1), premium);
+
+        // The same policy priced per second earns through the last half day
+        let per_second = calculate_earned_premium(
+            premium,
+            start,
+            end,
+            start + 10 * SECONDS_PER_DAY,
+            PRICING_VERSION_PER_SECOND,
+        )
+        .unwrap();
+        assert!(per_second < premium);
+
+        // A legacy policy shorter than a day was free and has nothing to amortize
+        assert_eq!(calculate_earned_premium(0, start, start + 3_600, start + 60, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_locked_stake_earns_boosted_lives_emissions() {
+        let config = StakingConfig {
+            lives_emission_per_second: 1_000,
+            lock_boosts: vec![
+                LockBoost { min_lock_seconds: 30 * SECONDS_PER_DAY, multiplier_bps: 15_000 },
+                LockBoost { min_lock_seconds: SECONDS_PER_YEAR, multiplier_bps: 25_000 },
+            ],
+        };
+        assert_eq!(config.boost_bps_for(0), 10_000);
+        assert_eq!(config.boost_bps_for(90 * SECONDS_PER_DAY), 15_000);
+        assert_eq!(config.boost_bps_for(2 * SECONDS_PER_YEAR), 25_000);
+
+        // Equal stakes, one unlocked and one locked for a year
+        let unlocked = calculate_boosted_amount(1_000_000, config.boost_bps_for(0)).unwrap();
+        let locked = calculate_boosted_amount(1_000_000, config.boost_bps_for(SECONDS_PER_YEAR)).unwrap();
+        let mut emissions = LivesEmissions {
+            total_boosted: unlocked + locked,
+            funded_remaining: 70_000,
+            ..Default::default()
+        };
+        emissions.update(config.lives_emission_per_second, 35).unwrap();
+        assert_eq!(emissions.funded_remaining, 35_000);
+
+        let unlocked_share = (unlocked as u128 * emissions.acc_lives_per_share / REWARD_PRECISION) as u64;
+        let locked_share = (locked as u128 * emissions.acc_lives_per_share / REWARD_PRECISION) as u64;
+        assert_eq!(unlocked_share, 10_000);
+        assert_eq!(locked_share, 25_000);
+
+        // Emissions stop once the reward vault's funding runs out
+        emissions.update(config.lives_emission_per_second, 1_000).unwrap();
+        assert_eq!(emissions.funded_remaining, 0);
+    }
+
+    #[test]
+    fn test_vote_weight_is_snapshotted_before_the_proposal() {
+        assert_eq!(calculate_vote_weight(1_000, 4_000, 2_500).unwrap(), 2_000);
+
+        let mut voter = VoterRecord {
+            governance: Pubkey::default(),
+            owner: Pubkey::default(),
+            shield_deposited: 0,
+            lives_deposited: 0,
+            checkpoints: Vec::new(),
+            bump: 0,
+        };
+        voter.push_checkpoint(100, 1_000);
+        voter.push_checkpoint(200, 5_000);
+
+        // Tokens deposited at or after proposal creation do not count
+        assert_eq!(voter.weight_at(100), 0);
+        assert_eq!(voter.weight_at(150), 1_000);
+        assert_eq!(voter.weight_at(200), 1_000);
+        assert_eq!(voter.weight_at(201), 5_000);
+        assert_eq!(voter.current_weight(), 5_000);
+
+        // Once history rolls past a snapshot the weight there is treated as zero
+        for timestamp in 300..(300 + MAX_VOTE_CHECKPOINTS as i64) {
+            voter.push_checkpoint(timestamp, 9_000);
+        }
+        assert_eq!(voter.weight_at(250), 0);
+    }
 }
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::errors::InsuranceError;
+
+/// State accounts carrying a layout version and reserved space for future fields
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator {
+    const VERSION: u8;
+    /// Allocated size of the layout deployed before accounts carried a version
+    const BASELINE_SIZE: usize;
+
+    fn version(&self) -> u8;
+
+    /// Decode the pre-versioning layout (after the discriminator) into the current one,
+    /// with defaults for every field added since
+    fn from_baseline(data: &mut &[u8]) -> Result<Self>;
+
+    /// Fill in defaults for everything added since `from_version` and stamp the current version
+    fn upgrade(&mut self, from_version: u8);
+}
+
+/// Decode an account in whichever layout it was written, returning it with the version it
+/// was written at. `data` has already been grown to the current size; `original_len` is the
+/// size it was allocated with, which is what tells the baseline layout apart.
+pub fn decode_versioned<T: Versioned>(data: &[u8], original_len: usize) -> Result<(T, u8)> {
+    if original_len == T::BASELINE_SIZE {
+        require!(
+            data.len() >= 8 && data[..8] == T::DISCRIMINATOR[..],
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        let state = T::from_baseline(&mut &data[8..original_len])?;
+        return Ok((state, 0));
+    }
+
+    // Later layouts only carve fields out of the reserved bytes, which read as zero
+    let state = T::try_deserialize(&mut &data[..])?;
+    let version = state.version();
+    Ok((state, version))
+}
+
+/// Bring a program-owned account up to the current layout of `T`, returning the version it
+/// was migrated from. Accounts shorter than `size` are grown in place first, with the payer
+/// topping up rent.
+pub fn migrate_account<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    size: usize,
+) -> Result<u8> {
+    let original_len = account.data_len();
+    if original_len < size {
+        let required_lamports = Rent::get()?
+            .minimum_balance(size)
+            .saturating_sub(account.lamports());
+        if required_lamports > 0 {
+            let cpi_accounts = Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+            system_program::transfer(cpi_ctx, required_lamports)?;
+        }
+        account.realloc(size, true)?;
+    }
+
+    let (mut state, from_version) =
+        decode_versioned::<T>(&account.try_borrow_data()?, original_len)?;
+    require!(
+        from_version < T::VERSION,
+        InsuranceError::AccountAlreadyMigrated
+    );
+
+    state.upgrade(from_version);
+    state.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    Ok(from_version)
+}
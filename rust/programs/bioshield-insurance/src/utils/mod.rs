@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 
 pub mod calculations;
 pub mod validations;
+pub mod migration;
 
 pub use calculations::*;
 pub use validations::*;
+pub use migration::*;
@@ -1,6 +1,16 @@
 use anchor_lang::prelude::*;
 use crate::errors::InsuranceError;
 use crate::state::oracle_data::TriggerConditions;
+use crate::state::coverage_account::{CoverageType, ALL_COVERAGE_TYPES};
+use crate::state::{
+    PricingCurve, LivesDiscountSchedule, WithdrawalConfig, StakingConfig, InsurancePool,
+    ReinsuranceTreaty, TreatyTerms, TreatyKind, GovernanceConfig, ParameterChange, TimelockConfig,
+    MAX_PROPOSAL_ACTIONS, MIN_VOTING_PERIOD, MAX_VOTING_PERIOD, MIN_TIMELOCK_DELAY,
+    MAX_TIMELOCK_DELAY, MIN_COVERAGE_PERIOD, MAX_COVERAGE_PERIOD, MAX_DISCOUNT_TIERS,
+    MAX_LIVES_DISCOUNT_BPS, MAX_LOCK_BOOSTS, MAX_STAKE_LOCK_SECONDS, MAX_LOCK_BOOST_BPS,
+    MAX_METADATA_URI_LEN, MAX_COVERAGE_CATEGORY_LEN, MAX_CUSTOM_CONDITIONS, MAX_CONDITION_TYPE_LEN,
+    MAX_REJECTION_REASON_LEN, MAX_MARKETPLACE_FEE_BASIS_POINTS, BASIS_POINTS,
+};
 
 pub fn validate_coverage_amount(
     amount: u64,
@@ -14,10 +24,11 @@ pub fn validate_coverage_amount(
     Ok(())
 }
 
+/// Coverage periods are expressed in seconds
 pub fn validate_coverage_period(period: u32) -> Result<()> {
     require!(
-        period > 0 && period <= 365 * 5, // Max 5 years
-        InsuranceError::InvalidCoverageAmount
+        period >= MIN_COVERAGE_PERIOD && period <= MAX_COVERAGE_PERIOD,
+        InsuranceError::InvalidCoveragePeriod
     );
     Ok(())
 }
@@ -33,16 +44,41 @@ pub fn validate_trigger_conditions(conditions: &TriggerConditions) -> Result<()>
     );
 
     // Validate custom conditions
+    require!(
+        conditions.custom_conditions.len() <= MAX_CUSTOM_CONDITIONS,
+        InsuranceError::TooManyCustomConditions
+    );
     for condition in &conditions.custom_conditions {
         require!(
             !condition.condition_type.is_empty(),
             InsuranceError::InvalidTriggerConditions
         );
+        require!(
+            condition.condition_type.len() <= MAX_CONDITION_TYPE_LEN,
+            InsuranceError::ConditionTypeTooLong
+        );
     }
 
     Ok(())
 }
 
+/// String lengths are checked in bytes, the unit the account space is budgeted in
+pub fn validate_metadata_uri(metadata_uri: &str) -> Result<()> {
+    require!(
+        metadata_uri.len() <= MAX_METADATA_URI_LEN,
+        InsuranceError::MetadataUriTooLong
+    );
+    Ok(())
+}
+
+pub fn validate_rejection_reason(reason: &str) -> Result<()> {
+    require!(
+        reason.len() <= MAX_REJECTION_REASON_LEN,
+        InsuranceError::RejectionReasonTooLong
+    );
+    Ok(())
+}
+
 pub fn validate_claim_amount(
     claim_amount: u64,
     coverage_amount: u64,
@@ -98,3 +134,245 @@ pub fn validate_pool_parameters(
 
     Ok(())
 }
+
+pub fn validate_capacity_config(
+    curve: &PricingCurve,
+    max_utilization_bps: u32,
+) -> Result<()> {
+    require!(
+        curve.kink_utilization_bps > 0 && curve.kink_utilization_bps < max_utilization_bps,
+        InsuranceError::InvalidPricingCurve
+    );
+
+    require!(
+        curve.slope_below_kink_bps <= curve.slope_above_kink_bps,
+        InsuranceError::InvalidPricingCurve
+    );
+
+    Ok(())
+}
+
+pub fn validate_lives_discount_schedule(schedule: &LivesDiscountSchedule) -> Result<()> {
+    require!(
+        schedule.tiers.len() <= MAX_DISCOUNT_TIERS,
+        InsuranceError::InvalidDiscountSchedule
+    );
+
+    require!(
+        schedule.base_discount_bps <= MAX_LIVES_DISCOUNT_BPS &&
+        schedule.burn_share_bps <= BASIS_POINTS,
+        InsuranceError::InvalidDiscountSchedule
+    );
+
+    // Tiers must reward larger holdings with larger discounts
+    let mut previous_balance = 0;
+    let mut previous_discount = schedule.base_discount_bps;
+    for tier in &schedule.tiers {
+        require!(
+            tier.min_lives_balance > previous_balance &&
+            tier.discount_bps >= previous_discount &&
+            tier.discount_bps <= MAX_LIVES_DISCOUNT_BPS,
+            InsuranceError::InvalidDiscountSchedule
+        );
+        previous_balance = tier.min_lives_balance;
+        previous_discount = tier.discount_bps;
+    }
+
+    Ok(())
+}
+
+pub fn validate_withdrawal_config(config: &WithdrawalConfig) -> Result<()> {
+    require!(
+        config.cooldown_seconds >= 0 && config.epoch_duration > 0,
+        InsuranceError::InvalidWithdrawalConfig
+    );
+
+    require!(
+        config.max_epoch_withdrawal_bps > 0 &&
+        config.max_epoch_withdrawal_bps <= BASIS_POINTS,
+        InsuranceError::InvalidWithdrawalConfig
+    );
+
+    Ok(())
+}
+
+pub fn validate_staking_config(config: &StakingConfig) -> Result<()> {
+    require!(
+        config.lock_boosts.len() <= MAX_LOCK_BOOSTS,
+        InsuranceError::InvalidStakingConfig
+    );
+
+    // Longer locks must earn larger multipliers
+    let mut previous_lock = 0;
+    let mut previous_multiplier = BASIS_POINTS as u32;
+    for boost in &config.lock_boosts {
+        require!(
+            boost.min_lock_seconds > previous_lock &&
+            boost.min_lock_seconds <= MAX_STAKE_LOCK_SECONDS &&
+            boost.multiplier_bps >= previous_multiplier &&
+            boost.multiplier_bps <= MAX_LOCK_BOOST_BPS,
+            InsuranceError::InvalidStakingConfig
+        );
+        previous_lock = boost.min_lock_seconds;
+        previous_multiplier = boost.multiplier_bps;
+    }
+
+    Ok(())
+}
+
+pub fn validate_treaty_terms(terms: &TreatyTerms) -> Result<()> {
+    // Either kind pays for the risk it takes with a share of premiums
+    require!(
+        terms.cession_bps > 0 && terms.cession_bps <= BASIS_POINTS && terms.limit > 0,
+        InsuranceError::InvalidTreatyTerms
+    );
+
+    if terms.kind == TreatyKind::ExcessOfLoss {
+        require!(terms.attachment_point > 0, InsuranceError::InvalidTreatyTerms);
+    }
+
+    Ok(())
+}
+
+/// A pool with a treaty must be given that treaty and its reinsurer, and a pool without one neither
+pub fn validate_reinsurance_accounts(
+    pool: &InsurancePool,
+    treaty_key: Option<Pubkey>,
+    treaty: Option<&ReinsuranceTreaty>,
+    reinsurer_key: Option<Pubkey>,
+) -> Result<()> {
+    require!(
+        treaty_key == pool.reinsurance_treaty,
+        InsuranceError::ReinsuranceAccountsMismatch
+    );
+
+    match treaty {
+        Some(treaty) => require!(
+            reinsurer_key == Some(treaty.reinsurer),
+            InsuranceError::ReinsuranceAccountsMismatch
+        ),
+        None => require!(reinsurer_key.is_none(), InsuranceError::ReinsuranceAccountsMismatch),
+    }
+
+    Ok(())
+}
+
+pub fn validate_coverage_types(coverage_types: u8) -> Result<()> {
+    require!(
+        coverage_types != 0 && coverage_types & !ALL_COVERAGE_TYPES == 0,
+        InsuranceError::InvalidCoverageTypes
+    );
+
+    Ok(())
+}
+
+pub fn validate_coverage_type_offered(pool: &InsurancePool, coverage_type: &CoverageType) -> Result<()> {
+    require!(
+        pool.coverage_types & coverage_type.mask() != 0,
+        InsuranceError::CoverageTypeNotOffered
+    );
+
+    Ok(())
+}
+
+/// Custom categories are stored on the policy, so they must fit the space reserved for them
+pub fn validate_coverage_category(coverage_type: &CoverageType) -> Result<()> {
+    if let CoverageType::Custom { category } = coverage_type {
+        require!(
+            category.len() <= MAX_COVERAGE_CATEGORY_LEN,
+            InsuranceError::CoverageCategoryTooLong
+        );
+    }
+
+    Ok(())
+}
+
+pub fn validate_governance_config(config: &GovernanceConfig) -> Result<()> {
+    require!(
+        config.voting_period >= MIN_VOTING_PERIOD &&
+        config.voting_period <= MAX_VOTING_PERIOD,
+        InsuranceError::InvalidGovernanceConfig
+    );
+
+    require!(
+        config.quorum_votes > 0 &&
+        config.threshold_bps >= BASIS_POINTS / 2 &&
+        config.threshold_bps < BASIS_POINTS,
+        InsuranceError::InvalidGovernanceConfig
+    );
+
+    Ok(())
+}
+
+/// Proposed changes must pass the same checks as a queued timelock operation
+pub fn validate_proposal_actions(actions: &[ParameterChange]) -> Result<()> {
+    require!(
+        !actions.is_empty() && actions.len() <= MAX_PROPOSAL_ACTIONS,
+        InsuranceError::InvalidProposal
+    );
+
+    for action in actions {
+        validate_parameter_change(action)?;
+    }
+
+    Ok(())
+}
+
+pub fn validate_parameter_change(change: &ParameterChange) -> Result<()> {
+    match change {
+        ParameterChange::PoolFee { fee_basis_points } => require!(
+            *fee_basis_points <= 1000, // Max 10%
+            InsuranceError::InvalidFee
+        ),
+        ParameterChange::CoverageLimits { min_coverage_amount, max_coverage_amount } => require!(
+            *min_coverage_amount > 0 && max_coverage_amount > min_coverage_amount,
+            InsuranceError::InvalidCoverageAmount
+        ),
+        ParameterChange::Oracle { oracle_address } => require!(
+            *oracle_address != Pubkey::default(),
+            InsuranceError::InvalidProposal
+        ),
+        ParameterChange::LivesDiscount { schedule } => validate_lives_discount_schedule(schedule)?,
+        ParameterChange::Pause { .. } => {}
+        ParameterChange::CapacityConfig { pricing_curve, max_utilization_bps } => {
+            validate_capacity_config(pricing_curve, *max_utilization_bps)?
+        }
+        ParameterChange::WithdrawalConfig { config } => validate_withdrawal_config(config)?,
+        ParameterChange::StakingConfig { config } => validate_staking_config(config)?,
+        ParameterChange::Marketplace { treasury, marketplace_fee_basis_points } => require!(
+            *treasury != Pubkey::default() &&
+            *marketplace_fee_basis_points <= MAX_MARKETPLACE_FEE_BASIS_POINTS,
+            InsuranceError::InvalidFee
+        ),
+        ParameterChange::GrantRole { member, .. } => require!(
+            *member != Pubkey::default(),
+            InsuranceError::InvalidRoleAssignment
+        ),
+        ParameterChange::RevokeRole { .. } => {}
+        ParameterChange::Authority { new_authority } => require!(
+            *new_authority != Some(Pubkey::default()),
+            InsuranceError::InvalidAuthority
+        ),
+        ParameterChange::AcceptAuthority | ParameterChange::TerminateTreaty => {}
+    }
+
+    Ok(())
+}
+
+pub fn validate_timelock_config(
+    config: &TimelockConfig,
+    withdrawal_config: &WithdrawalConfig,
+) -> Result<()> {
+    require!(
+        config.delay >= MIN_TIMELOCK_DELAY && config.delay <= MAX_TIMELOCK_DELAY,
+        InsuranceError::InvalidTimelockConfig
+    );
+
+    // Otherwise an LP who sees a change queued can't get out before it lands
+    require!(
+        config.delay >= withdrawal_config.cooldown_seconds,
+        InsuranceError::InvalidTimelockConfig
+    );
+
+    Ok(())
+}